@@ -26,6 +26,9 @@ async fn main() -> octocrab::Result<()> {
             }
         }
         etag = response.etag;
-        tokio::time::sleep(tokio::time::Duration::from_millis(DELAY_MS)).await;
+        let delay = response
+            .poll_interval
+            .unwrap_or(tokio::time::Duration::from_millis(DELAY_MS));
+        tokio::time::sleep(delay).await;
     }
 }
@@ -9,10 +9,12 @@ pub mod events;
 pub mod gists;
 pub mod gitignore;
 pub mod hooks;
+mod interactions;
 pub mod issues;
 pub mod licenses;
 pub mod markdown;
 pub mod orgs;
+pub mod packages;
 pub mod projects;
 pub mod pulls;
 pub mod ratelimit;
@@ -21,3 +23,14 @@ pub mod search;
 pub mod teams;
 pub mod users;
 pub mod workflows;
+
+/// Serializes an optional list as the comma-separated string GitHub expects
+/// in query parameters such as `labels`.
+pub(crate) fn comma_separated<S, T>(values: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+    T: AsRef<[String]>,
+{
+    let values = values.as_ref().map_or(&[][..], AsRef::as_ref);
+    serializer.serialize_str(&values.join(","))
+}
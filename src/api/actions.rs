@@ -4,8 +4,10 @@ use http_body_util::combinators::BoxBody;
 use http_body_util::{BodyExt, Collected};
 use snafu::ResultExt;
 
+pub mod runner_groups;
 pub mod self_hosted_runners;
 
+use self::runner_groups::RunnerGroupsHandler;
 use self::self_hosted_runners::{CreateJitRunnerConfigBuilder, ListSelfHostedRunnersBuilder};
 use crate::error::HttpSnafu;
 use crate::etag::{EntityTag, Etagged};
@@ -76,17 +78,24 @@ impl<'octo> ListWorkflowRunArtifacts<'octo> {
 
         let request = self
             .crab
+            .operation("actions.list_workflow_run_artifacts")
             .build_request(Builder::new().method(Method::GET).uri(uri), None::<&()>)?;
         let response = self.crab.execute(request).await?;
         let etag = EntityTag::extract_from_response(&response);
+        let poll_interval = crate::etag::extract_poll_interval(&response);
         if response.status() == StatusCode::NOT_MODIFIED {
-            Ok(Etagged { etag, value: None })
+            Ok(Etagged {
+                etag,
+                value: None,
+                poll_interval,
+            })
         } else {
             <Page<WorkflowListArtifact>>::from_response(crate::map_github_error(response).await?)
                 .await
                 .map(|page| Etagged {
                     etag,
                     value: Some(page),
+                    poll_interval,
                 })
         }
     }
@@ -143,7 +152,11 @@ impl<'octo> WorkflowDispatchBuilder<'octo> {
             .build()
             .context(HttpSnafu)?;
 
-        let response = self.crab._post(uri, Some(&self.data)).await?;
+        let response = self
+            .crab
+            .operation("actions.create_workflow_dispatch")
+            ._post(uri, Some(&self.data))
+            .await?;
         if !response.status().is_success() {
             return Err(crate::map_github_error(response).await.unwrap_err());
         }
@@ -194,9 +207,14 @@ impl<'octo> ActionsHandler<'octo> {
             .path_and_query(route)
             .build()
             .context(HttpSnafu)?;
-        crate::map_github_error(self.crab._put(uri, None::<&()>).await?)
-            .await
-            .map(drop)
+        crate::map_github_error(
+            self.crab
+                .operation("actions.add_selected_repo_to_org_secret")
+                ._put(uri, None::<&()>)
+                .await?,
+        )
+        .await
+        .map(drop)
     }
 
     /// Removes a repository from an organization secret when the visibility for
@@ -230,9 +248,45 @@ impl<'octo> ActionsHandler<'octo> {
             .path_and_query(route)
             .build()
             .context(HttpSnafu)?;
-        crate::map_github_error(self.crab._delete(uri, None::<&()>).await?)
+        crate::map_github_error(
+            self.crab
+                .operation("actions.remove_selected_repo_from_org_secret")
+                ._delete(uri, None::<&()>)
+                .await?,
+        )
+        .await
+        .map(drop)
+    }
+
+    /// Lists the repositories that have been selected to access an
+    /// organization secret whose visibility is set to selected. You must
+    /// authenticate using an access token with the admin:org scope to use this
+    /// endpoint. GitHub Apps must have the secrets organization permission to
+    /// use this endpoint.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let repos = octocrab::instance()
+    ///     .actions()
+    ///     .list_selected_repos_for_org_secret("org", "secret_name")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_selected_repos_for_org_secret(
+        &self,
+        org: impl AsRef<str>,
+        secret_name: impl AsRef<str>,
+    ) -> crate::Result<Page<crate::models::Repository>> {
+        let route = format!(
+            "/orgs/{org}/actions/secrets/{secret_name}/repositories",
+            org = org.as_ref(),
+            secret_name = secret_name.as_ref(),
+        );
+
+        self.crab
+            .operation("actions.list_selected_repos_for_org_secret")
+            .get(route, None::<&()>)
             .await
-            .map(drop)
     }
 
     /// Cancels a workflow run using its id. You must authenticate using an
@@ -263,9 +317,14 @@ impl<'octo> ActionsHandler<'octo> {
             .path_and_query(route)
             .build()
             .context(HttpSnafu)?;
-        crate::map_github_error(self.crab._post(uri, None::<&()>).await?)
-            .await
-            .map(drop)
+        crate::map_github_error(
+            self.crab
+                .operation("actions.cancel_workflow_run")
+                ._post(uri, None::<&()>)
+                .await?,
+        )
+        .await
+        .map(drop)
     }
 
     async fn follow_location_to_data(
@@ -308,8 +367,13 @@ impl<'octo> ActionsHandler<'octo> {
             .build()
             .context(HttpSnafu)?;
 
-        self.follow_location_to_data(self.crab._get(uri).await?)
-            .await
+        self.follow_location_to_data(
+            self.crab
+                .operation("actions.download_workflow_run_logs")
+                ._get(uri)
+                .await?,
+        )
+        .await
     }
 
     /// Downloads and returns the raw data representing an artifact from a
@@ -332,6 +396,40 @@ impl<'octo> ActionsHandler<'octo> {
         artifact_id: ArtifactId,
         archive_format: params::actions::ArchiveFormat,
     ) -> crate::Result<bytes::Bytes> {
+        self.stream_artifact(owner, repo, artifact_id, archive_format)
+            .await?
+            .collect()
+            .await
+            .map(Collected::to_bytes)
+    }
+
+    /// Returns the body of an artifact from a repository without buffering
+    /// it, so that large archives can be consumed chunk by chunk.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use http_body_util::BodyExt;
+    /// use octocrab::params::actions::ArchiveFormat;
+    ///
+    /// let mut body = octocrab::instance()
+    ///     .actions()
+    ///     .stream_artifact("owner", "repo", 1234u64.into(), ArchiveFormat::Zip)
+    ///     .await?;
+    ///
+    /// while let Some(frame) = body.frame().await {
+    ///     if let Ok(chunk) = frame?.into_data() {
+    ///         println!("received {} bytes", chunk.len());
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stream_artifact(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        artifact_id: ArtifactId,
+        archive_format: params::actions::ArchiveFormat,
+    ) -> crate::Result<BoxBody<Bytes, crate::Error>> {
         let route = format!(
             "/repos/{owner}/{repo}/actions/artifacts/{artifact_id}/{archive_format}",
             owner = owner.as_ref(),
@@ -345,8 +443,69 @@ impl<'octo> ActionsHandler<'octo> {
             .build()
             .context(HttpSnafu)?;
 
-        self.follow_location_to_data(self.crab._get(uri).await?)
-            .await
+        let response = self
+            .crab
+            .operation("actions.stream_artifact")
+            ._get(uri)
+            .await?;
+        let data_response = self.crab.follow_location_to_data(response).await?;
+
+        Ok(data_response.into_body())
+    }
+
+    /// Downloads an artifact from a repository and writes it to `path` as the
+    /// chunks arrive, returning the number of bytes written.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::actions::ArchiveFormat;
+    ///
+    /// let written = octocrab::instance()
+    ///     .actions()
+    ///     .download_artifact_to(
+    ///         "owner",
+    ///         "repo",
+    ///         1234u64.into(),
+    ///         ArchiveFormat::Zip,
+    ///         "artifact.zip",
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "tokio")]
+    pub async fn download_artifact_to(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        artifact_id: ArtifactId,
+        archive_format: params::actions::ArchiveFormat,
+        path: impl AsRef<std::path::Path>,
+    ) -> crate::Result<u64> {
+        use tokio::io::AsyncWriteExt;
+
+        let mut body = self
+            .stream_artifact(owner, repo, artifact_id, archive_format)
+            .await?;
+
+        let io_error = |source: std::io::Error| crate::Error::Other {
+            source: Box::new(source),
+            backtrace: snafu::Backtrace::capture(),
+        };
+
+        let file = tokio::fs::File::create(path).await.map_err(io_error)?;
+        let mut writer = tokio::io::BufWriter::new(file);
+        let mut written = 0u64;
+
+        while let Some(frame) = body.frame().await {
+            if let Ok(chunk) = frame?.into_data() {
+                writer.write_all(&chunk).await.map_err(io_error)?;
+                written += chunk.len() as u64;
+            }
+        }
+
+        writer.flush().await.map_err(io_error)?;
+
+        Ok(written)
     }
 
     /// Deletes all logs for a workflow run. You must authenticate using an
@@ -378,9 +537,14 @@ impl<'octo> ActionsHandler<'octo> {
             .path_and_query(route)
             .build()
             .context(HttpSnafu)?;
-        crate::map_github_error(self.crab._delete(uri, None::<&()>).await?)
-            .await
-            .map(drop)
+        crate::map_github_error(
+            self.crab
+                .operation("actions.delete_workflow_run_logs")
+                ._delete(uri, None::<&()>)
+                .await?,
+        )
+        .await
+        .map(drop)
     }
 
     /// Get an organization's public key, which you need to encrypt secrets.
@@ -402,7 +566,10 @@ impl<'octo> ActionsHandler<'octo> {
     ) -> crate::Result<crate::models::PublicKey> {
         let route = format!("/orgs/{org}/actions/secrets/public-key", org = org.as_ref());
 
-        self.crab.get(route, None::<&()>).await
+        self.crab
+            .operation("actions.get_org_public_key")
+            .get(route, None::<&()>)
+            .await
     }
 
     /// Lists artifacts for a workflow run. Anyone with read access to the
@@ -450,6 +617,23 @@ impl<'octo> ActionsHandler<'octo> {
         )
     }
 
+    /// Creates a new handler for the self-hosted runner groups of `org`.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let groups = octocrab.actions()
+    ///    .runner_groups("org")
+    ///    .list()
+    ///    .send()
+    ///    .await?;
+    /// # return Ok(());
+    /// # }
+    /// ```
+    pub fn runner_groups(&self, org: impl Into<String>) -> RunnerGroupsHandler<'octo> {
+        RunnerGroupsHandler::new(self.crab, org.into())
+    }
+
     /// List all self-hosted runners configured in an organization.
     ///
     /// You must authenticate using an access token with the `admin:org` scope
@@ -548,7 +732,10 @@ impl<'octo> ActionsHandler<'octo> {
             org = org.as_ref()
         );
 
-        self.crab.post(route, None::<&()>).await
+        self.crab
+            .operation("actions.create_org_runner_registration_token")
+            .post(route, None::<&()>)
+            .await
     }
 
     /// Returns a token that you can pass to the self-hosted runner config
@@ -578,7 +765,10 @@ impl<'octo> ActionsHandler<'octo> {
             org = org.as_ref()
         );
 
-        self.crab.post(route, None::<&()>).await
+        self.crab
+            .operation("actions.create_org_runner_remove_token")
+            .post(route, None::<&()>)
+            .await
     }
 
     /// Gets a specific self-hosted runner configured in an organization.
@@ -606,7 +796,10 @@ impl<'octo> ActionsHandler<'octo> {
             org = org.as_ref()
         );
 
-        self.crab.get(route, None::<&()>).await
+        self.crab
+            .operation("actions.get_org_runner")
+            .get(route, None::<&()>)
+            .await
     }
 
     /// Forces the removal of a self-hosted runner from an organization. You
@@ -636,7 +829,11 @@ impl<'octo> ActionsHandler<'octo> {
             org = org.as_ref()
         );
 
-        let response = self.crab._delete(route, None::<&()>).await?;
+        let response = self
+            .crab
+            .operation("actions.delete_org_runner")
+            ._delete(route, None::<&()>)
+            .await?;
         crate::map_github_error(response).await.map(drop)
     }
 
@@ -742,7 +939,10 @@ impl<'octo> ActionsHandler<'octo> {
             repo = repo.as_ref()
         );
 
-        self.crab.post(route, None::<&()>).await
+        self.crab
+            .operation("actions.create_repo_runner_registration_token")
+            .post(route, None::<&()>)
+            .await
     }
 
     /// Returns a token that you can pass to the self-hosted runner config
@@ -774,7 +974,10 @@ impl<'octo> ActionsHandler<'octo> {
             repo = repo.as_ref()
         );
 
-        self.crab.post(route, None::<&()>).await
+        self.crab
+            .operation("actions.create_repo_runner_remove_token")
+            .post(route, None::<&()>)
+            .await
     }
 
     /// Gets a specific self-hosted runner configured in an organization.
@@ -804,7 +1007,10 @@ impl<'octo> ActionsHandler<'octo> {
             repo = repo.as_ref()
         );
 
-        self.crab.get(route, None::<&()>).await
+        self.crab
+            .operation("actions.get_repo_runner")
+            .get(route, None::<&()>)
+            .await
     }
 
     /// Forces the removal of a self-hosted runner from an organization. You
@@ -836,7 +1042,11 @@ impl<'octo> ActionsHandler<'octo> {
             repo = repo.as_ref()
         );
 
-        let response = self.crab._delete(route, None::<&()>).await?;
+        let response = self
+            .crab
+            .operation("actions.delete_repo_runner")
+            ._delete(route, None::<&()>)
+            .await?;
         crate::map_github_error(response).await.map(drop)
     }
 }
@@ -0,0 +1,363 @@
+use crate::models::actions::{RunnerGroup, RunnerGroupVisibility, SelfHostedRunner};
+use crate::models::{RunnerGroupId, RunnerId};
+use crate::{Octocrab, Page};
+use serde::Serialize;
+
+/// A client to GitHub's self-hosted runner groups API for an organization.
+///
+/// Created with [`ActionsHandler::runner_groups`].
+///
+/// [`ActionsHandler::runner_groups`]: ../struct.ActionsHandler.html#method.runner_groups
+pub struct RunnerGroupsHandler<'octo> {
+    crab: &'octo Octocrab,
+    org: String,
+}
+
+impl<'octo> RunnerGroupsHandler<'octo> {
+    pub(crate) fn new(crab: &'octo Octocrab, org: String) -> Self {
+        Self { crab, org }
+    }
+
+    /// Lists all self-hosted runner groups configured in the organization.
+    ///
+    /// You must authenticate using an access token with the `admin:org` scope
+    /// to use this endpoint.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let groups = octocrab.actions()
+    ///    .runner_groups("org")
+    ///    .list()
+    ///    // optional
+    ///    .per_page(15)
+    ///    .page(2u32)
+    ///    .send()
+    ///    .await?;
+    /// # return Ok(());
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListRunnerGroupsBuilder<'_, '_> {
+        ListRunnerGroupsBuilder::new(self)
+    }
+
+    /// Gets a specific self-hosted runner group.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let group = octocrab.actions()
+    ///    .runner_groups("org")
+    ///    .get(39.into())
+    ///    .await?;
+    /// # return Ok(());
+    /// # }
+    /// ```
+    pub async fn get(&self, group_id: RunnerGroupId) -> crate::Result<RunnerGroup> {
+        let route = format!(
+            "/orgs/{org}/actions/runner-groups/{group_id}",
+            org = self.org
+        );
+
+        self.crab
+            .operation("actions.runner_groups.get")
+            .get(route, None::<&()>)
+            .await
+    }
+
+    /// Creates a new self-hosted runner group containing the given runners.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// use octocrab::models::actions::RunnerGroupVisibility;
+    ///
+    /// let group = octocrab.actions()
+    ///    .runner_groups("org")
+    ///    .create("Expensive hardware runners", RunnerGroupVisibility::All, vec![27.into()])
+    ///    .await?;
+    /// # return Ok(());
+    /// # }
+    /// ```
+    pub async fn create(
+        &self,
+        name: impl Into<String>,
+        visibility: RunnerGroupVisibility,
+        runner_ids: Vec<RunnerId>,
+    ) -> crate::Result<RunnerGroup> {
+        let route = format!("/orgs/{org}/actions/runner-groups", org = self.org);
+
+        self.crab
+            .operation("actions.runner_groups.create")
+            .post(
+                route,
+                Some(&serde_json::json!({
+                    "name": name.into(),
+                    "visibility": visibility,
+                    "runners": runner_ids,
+                })),
+            )
+            .await
+    }
+
+    /// Updates the name and settings of a self-hosted runner group.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// use octocrab::models::actions::RunnerGroupVisibility;
+    ///
+    /// let group = octocrab.actions()
+    ///    .runner_groups("org")
+    ///    .update(39.into())
+    ///    .name("Cheap hardware runners")
+    ///    .visibility(RunnerGroupVisibility::Private)
+    ///    .send()
+    ///    .await?;
+    /// # return Ok(());
+    /// # }
+    /// ```
+    pub fn update(&self, group_id: RunnerGroupId) -> UpdateRunnerGroupBuilder<'_, '_> {
+        UpdateRunnerGroupBuilder::new(self, group_id)
+    }
+
+    /// Deletes a self-hosted runner group. Runners in the group are moved to
+    /// the default group.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// octocrab.actions()
+    ///    .runner_groups("org")
+    ///    .delete(39.into())
+    ///    .await?;
+    /// # return Ok(());
+    /// # }
+    /// ```
+    pub async fn delete(&self, group_id: RunnerGroupId) -> crate::Result<()> {
+        let route = format!(
+            "/orgs/{org}/actions/runner-groups/{group_id}",
+            org = self.org
+        );
+
+        let response = self
+            .crab
+            .operation("actions.runner_groups.delete")
+            ._delete(route, None::<&()>)
+            .await?;
+        crate::map_github_error(response).await.map(drop)
+    }
+
+    /// Lists the self-hosted runners that are in a runner group.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let runners = octocrab.actions()
+    ///    .runner_groups("org")
+    ///    .list_runners_in_group(39.into())
+    ///    // optional
+    ///    .per_page(15)
+    ///    .send()
+    ///    .await?;
+    /// # return Ok(());
+    /// # }
+    /// ```
+    pub fn list_runners_in_group(
+        &self,
+        group_id: RunnerGroupId,
+    ) -> ListRunnersInGroupBuilder<'_, '_> {
+        ListRunnersInGroupBuilder::new(self, group_id)
+    }
+}
+
+/// A builder pattern struct for listing self-hosted runner groups.
+///
+/// Created by [`RunnerGroupsHandler::list`].
+#[derive(Serialize)]
+pub struct ListRunnerGroupsBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RunnerGroupsHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible_to_repository: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListRunnerGroupsBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RunnerGroupsHandler<'octo>) -> Self {
+        Self {
+            handler,
+            visible_to_repository: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Only return runner groups that are allowed to be used by this repository.
+    pub fn visible_to_repository(mut self, repository: impl Into<String>) -> Self {
+        self.visible_to_repository = Some(repository.into());
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Page<RunnerGroup>> {
+        let route = format!("/orgs/{org}/actions/runner-groups", org = self.handler.org);
+
+        self.handler
+            .crab
+            .operation("actions.runner_groups.list")
+            .get(route, Some(&self))
+            .await
+    }
+}
+
+/// A builder pattern struct for updating a self-hosted runner group.
+///
+/// Created by [`RunnerGroupsHandler::update`].
+#[derive(Serialize)]
+pub struct UpdateRunnerGroupBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RunnerGroupsHandler<'octo>,
+    #[serde(skip)]
+    group_id: RunnerGroupId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visibility: Option<RunnerGroupVisibility>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allows_public_repositories: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    restricted_to_workflows: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    selected_workflows: Option<Vec<String>>,
+}
+
+impl<'octo, 'r> UpdateRunnerGroupBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RunnerGroupsHandler<'octo>, group_id: RunnerGroupId) -> Self {
+        Self {
+            handler,
+            group_id,
+            name: None,
+            visibility: None,
+            allows_public_repositories: None,
+            restricted_to_workflows: None,
+            selected_workflows: None,
+        }
+    }
+
+    /// The new name of the runner group.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Which repositories can use the runner group.
+    pub fn visibility(mut self, visibility: RunnerGroupVisibility) -> Self {
+        self.visibility = Some(visibility);
+        self
+    }
+
+    /// Whether the runner group can be used by public repositories.
+    pub fn allows_public_repositories(mut self, allows_public_repositories: bool) -> Self {
+        self.allows_public_repositories = Some(allows_public_repositories);
+        self
+    }
+
+    /// Whether the runner group is restricted to running only the
+    /// [`selected_workflows`](Self::selected_workflows).
+    pub fn restricted_to_workflows(mut self, restricted_to_workflows: bool) -> Self {
+        self.restricted_to_workflows = Some(restricted_to_workflows);
+        self
+    }
+
+    /// The workflows that can use the runner group, e.g.
+    /// `octo-org/octo-repo/.github/workflows/deploy.yaml@main`.
+    pub fn selected_workflows(mut self, selected_workflows: Vec<String>) -> Self {
+        self.selected_workflows = Some(selected_workflows);
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<RunnerGroup> {
+        let route = format!(
+            "/orgs/{org}/actions/runner-groups/{group_id}",
+            org = self.handler.org,
+            group_id = self.group_id,
+        );
+
+        self.handler
+            .crab
+            .operation("actions.runner_groups.update")
+            .patch(route, Some(&self))
+            .await
+    }
+}
+
+/// A builder pattern struct for listing the runners in a self-hosted runner
+/// group.
+///
+/// Created by [`RunnerGroupsHandler::list_runners_in_group`].
+#[derive(Serialize)]
+pub struct ListRunnersInGroupBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RunnerGroupsHandler<'octo>,
+    #[serde(skip)]
+    group_id: RunnerGroupId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListRunnersInGroupBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RunnerGroupsHandler<'octo>, group_id: RunnerGroupId) -> Self {
+        Self {
+            handler,
+            group_id,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Page<SelfHostedRunner>> {
+        let route = format!(
+            "/orgs/{org}/actions/runner-groups/{group_id}/runners",
+            org = self.handler.org,
+            group_id = self.group_id,
+        );
+
+        self.handler
+            .crab
+            .operation("actions.runner_groups.list_runners_in_group")
+            .get(route, Some(&self))
+            .await
+    }
+}
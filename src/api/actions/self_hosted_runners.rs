@@ -79,7 +79,11 @@ impl<'octo, 'r> ListSelfHostedRunnersBuilder<'octo, 'r> {
             RunnerScope::Repo { owner, repo } => format!("/repos/{owner}/{repo}/actions/runners"),
         };
 
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("actions.list_org_self_hosted_runners")
+            .get(route, Some(&self))
+            .await
     }
 }
 
@@ -154,6 +158,10 @@ impl<'octo, 'r> CreateJitRunnerConfigBuilder<'octo, 'r> {
             }
         };
 
-        self.handler.crab.post(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("actions.create_org_jit_runner_config")
+            .post(route, Some(&self))
+            .await
     }
 }
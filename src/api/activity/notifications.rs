@@ -41,7 +41,10 @@ impl<'octo> NotificationsHandler<'octo> {
     /// ```
     pub async fn get(&self, id: NotificationId) -> crate::Result<Notification> {
         let route = format!("/notifications/threads/{id}");
-        self.crab.get(route, None::<&()>).await
+        self.crab
+            .operation("activity.notifications.get")
+            .get(route, None::<&()>)
+            .await
     }
 
     /// Marks a single thread as read.
@@ -63,7 +66,11 @@ impl<'octo> NotificationsHandler<'octo> {
             .build()
             .context(HttpSnafu)?;
 
-        let response = self.crab._patch(uri, None::<&()>).await?;
+        let response = self
+            .crab
+            .operation("activity.notifications.mark_as_read")
+            ._patch(uri, None::<&()>)
+            .await?;
         crate::map_github_error(response).await.map(drop)
     }
 
@@ -100,7 +107,11 @@ impl<'octo> NotificationsHandler<'octo> {
             .build()
             .context(HttpSnafu)?;
 
-        let response = self.crab._put(uri, body.as_ref()).await?;
+        let response = self
+            .crab
+            .operation("activity.notifications.mark_repo_as_read")
+            ._put(uri, body.as_ref())
+            .await?;
         crate::map_github_error(response).await.map(drop)
     }
 
@@ -136,7 +147,11 @@ impl<'octo> NotificationsHandler<'octo> {
             .build()
             .context(HttpSnafu)?;
 
-        let response = self.crab._put(uri, body.as_ref()).await?;
+        let response = self
+            .crab
+            .operation("activity.notifications.mark_all_as_read")
+            ._put(uri, body.as_ref())
+            .await?;
         crate::map_github_error(response).await.map(drop)
     }
 
@@ -158,7 +173,10 @@ impl<'octo> NotificationsHandler<'octo> {
     ) -> crate::Result<ThreadSubscription> {
         let route = format!("/notifications/threads/{thread}/subscription");
 
-        self.crab.get(route, None::<&()>).await
+        self.crab
+            .operation("activity.notifications.get_thread_subscription")
+            .get(route, None::<&()>)
+            .await
     }
 
     /// Ignore or unignore a thread subscription, that is enabled by watching a repository.
@@ -186,7 +204,10 @@ impl<'octo> NotificationsHandler<'octo> {
         let route = format!("/notifications/threads/{thread}/subscription");
         let body = Inner { ignored };
 
-        self.crab.put(route, Some(&body)).await
+        self.crab
+            .operation("activity.notifications.set_thread_subscription")
+            .put(route, Some(&body))
+            .await
     }
 
     /// Mutes the whole thread conversation until you comment or get mentioned.
@@ -207,7 +228,11 @@ impl<'octo> NotificationsHandler<'octo> {
             .path_and_query(route)
             .build()
             .context(HttpSnafu)?;
-        let response = self.crab._delete(uri, None::<&()>).await?;
+        let response = self
+            .crab
+            .operation("activity.notifications.delete_thread_subscription")
+            ._delete(uri, None::<&()>)
+            .await?;
         crate::map_github_error(response).await.map(drop)
     }
 
@@ -330,6 +355,9 @@ impl<'octo> ListNotificationsBuilder<'octo> {
 
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<Page<Notification>> {
-        self.crab.get(&self.url, Some(&self)).await
+        self.crab
+            .operation("activity.notifications.list_for_repo")
+            .get(&self.url, Some(&self))
+            .await
     }
 }
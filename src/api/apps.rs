@@ -2,8 +2,11 @@ use crate::{models::InstallationId, Octocrab};
 use http::request::Builder;
 use http::Method;
 
+mod installation_repos;
 mod installations;
 
+pub use installation_repos::InstallationReposRequestBuilder;
+
 /// A client to [GitHub's apps API][apps-api].
 ///
 /// Created with [`Octocrab::apps`].
@@ -38,7 +41,10 @@ impl<'octo> AppsRequestHandler<'octo> {
     ) -> crate::Result<crate::models::Installation> {
         let route = format!("/app/installations/{installation_id}",);
 
-        self.crab.get(&route, None::<&()>).await
+        self.crab
+            .operation("apps.installation")
+            .get(&route, None::<&()>)
+            .await
     }
 
     /// Creates a new `InstallationsBuilder` that can be configured to filter
@@ -66,8 +72,71 @@ impl<'octo> AppsRequestHandler<'octo> {
         installations::InstallationsRequestBuilder::new(self)
     }
 
+    /// Creates a new `InstallationReposRequestBuilder` listing the
+    /// repositories that the authenticated installation can access.
+    ///
+    /// This requires an installation-scoped client, e.g. one created with
+    /// [`Octocrab::installation`].
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// use octocrab::models::InstallationId;
+    ///
+    /// let installation = octocrab.installation(InstallationId(1))?;
+    /// let page = installation
+    ///     .apps()
+    ///     .installation_repos()
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// let repos = installation.all_pages(page).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [See the GitHub API documentation](https://docs.github.com/en/rest/apps/installations?apiVersion=2022-11-28#list-repositories-accessible-to-the-app-installation)
+    pub fn installation_repos(
+        &self,
+    ) -> installation_repos::InstallationReposRequestBuilder<'octo, '_> {
+        installation_repos::InstallationReposRequestBuilder::new(self)
+    }
+
+    /// Lists all repositories accessible to `installation_id`, fetching a
+    /// fresh installation token with this app-authenticated client. Every
+    /// page is retrieved with that token, since the installation client isn't
+    /// returned. Use [`AppsRequestHandler::installation_repos`] instead if the
+    /// client already authenticates as the installation.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// use octocrab::models::InstallationId;
+    ///
+    /// let repos = octocrab
+    ///     .apps()
+    ///     .list_repos_accessible_to_installation(InstallationId(1))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_repos_accessible_to_installation(
+        &self,
+        installation_id: InstallationId,
+    ) -> crate::Result<Vec<crate::models::Repository>> {
+        let installation = self.crab.installation(installation_id)?;
+        let page = installation
+            .apps()
+            .installation_repos()
+            .per_page(100)
+            .send()
+            .await?;
+        installation.all_pages(page).await
+    }
+
     pub(crate) async fn http_get<R, A, P>(
         &self,
+        operation: &'static str,
         route: A,
         parameters: Option<&P>,
     ) -> crate::Result<R>
@@ -79,7 +148,10 @@ impl<'octo> AppsRequestHandler<'octo> {
         let request = Builder::new()
             .method(Method::GET)
             .uri(self.crab.parameterized_uri(route, parameters)?);
-        let request = self.crab.build_request(request, None::<&()>)?;
+        let request = self
+            .crab
+            .operation(operation)
+            .build_request(request, None::<&()>)?;
         R::from_response(crate::map_github_error(self.crab.execute(request).await?).await?).await
     }
 
@@ -95,7 +167,10 @@ impl<'octo> AppsRequestHandler<'octo> {
             repo = repo.as_ref(),
         );
 
-        self.crab.get(&route, None::<&()>).await
+        self.crab
+            .operation("apps.get_repository_installation")
+            .get(&route, None::<&()>)
+            .await
     }
 
     /// Get an organization installation for the authenticated app.
@@ -105,6 +180,9 @@ impl<'octo> AppsRequestHandler<'octo> {
     ) -> crate::Result<crate::models::Installation> {
         let route = format!("/orgs/{owner}/installation", owner = owner.as_ref(),);
 
-        self.crab.get(&route, None::<&()>).await
+        self.crab
+            .operation("apps.get_org_installation")
+            .get(&route, None::<&()>)
+            .await
     }
 }
@@ -0,0 +1,70 @@
+use super::*;
+use crate::Page;
+
+/// A builder pattern struct for listing the repositories accessible to the
+/// authenticated installation.
+///
+/// created by [`AppsRequestHandler::installation_repos`]
+///
+/// [`AppsRequestHandler::installation_repos`]: ./struct.AppsRequestHandler.html#method.installation_repos
+#[derive(serde::Serialize)]
+pub struct InstallationReposRequestBuilder<'octo, 'b> {
+    #[serde(skip)]
+    handler: &'b AppsRequestHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'b> InstallationReposRequestBuilder<'octo, 'b> {
+    pub(crate) fn new(handler: &'b AppsRequestHandler<'octo>) -> Self {
+        Self {
+            handler,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Page<crate::models::Repository>> {
+        let route = "/installation/repositories";
+        self.handler
+            .crab
+            .operation("apps.installation_repos")
+            .get(route, Some(&self))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[tokio::test]
+    async fn serialize() {
+        let octocrab = crate::Octocrab::default();
+
+        let handler = octocrab.apps();
+        let list = handler.installation_repos().per_page(100).page(2u8);
+
+        assert_eq!(
+            serde_json::to_value(list).unwrap(),
+            serde_json::json!({
+                "per_page": 100,
+                "page": 2
+            })
+        )
+    }
+}
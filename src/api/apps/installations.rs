@@ -49,7 +49,9 @@ impl<'octo, 'b> InstallationsRequestBuilder<'octo, 'b> {
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<Page<crate::models::Installation>> {
         let route = "/app/installations";
-        self.handler.http_get(route, Some(&self)).await
+        self.handler
+            .http_get("apps.installations", route, Some(&self))
+            .await
     }
 }
 
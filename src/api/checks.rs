@@ -40,7 +40,11 @@ impl<'octo, 'r> GetCheckRunBuilder<'octo, 'r> {
             check_run_id = self.check_run_id
         );
 
-        self.handler.crab.get(route, None::<&()>).await
+        self.handler
+            .crab
+            .operation("checks.get_check_run")
+            .get(route, None::<&()>)
+            .await
     }
 }
 
@@ -127,7 +131,11 @@ impl<'octo, 'r> CreateCheckRunBuilder<'octo, 'r> {
             repo = self.handler.repo
         );
 
-        self.handler.crab.post(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("checks.create_check_run")
+            .post(route, Some(&self))
+            .await
     }
 }
 
@@ -230,7 +238,11 @@ impl<'octo, 'r> UpdateCheckRunBuilder<'octo, 'r> {
             repo = self.handler.repo,
             check_run_id = self.check_run_id
         );
-        self.handler.crab.patch(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("checks.update_check_run")
+            .patch(route, Some(&self))
+            .await
     }
 }
 
@@ -294,7 +306,11 @@ impl<'octo, 'r> ListCheckRunsInCheckSuiteBuilder<'octo, 'r> {
             check_suite_id = self.check_suite_id,
         );
 
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("checks.list_check_runs_in_a_check_suite")
+            .get(route, Some(&self))
+            .await
     }
 }
 
@@ -338,10 +354,31 @@ impl<'octo, 'r> ListCheckRunsForGitRefBuilder<'octo, 'r> {
             "/repos/{owner}/{repo}/commits/{ref}/check-runs",
             owner = self.handler.owner,
             repo = self.handler.repo,
-            ref = self.git_ref,
+            ref = self.git_ref.ref_url_path(),
         );
 
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("checks.list_check_runs_for_git_ref")
+            .get(route, Some(&self))
+            .await
+    }
+
+    /// Like [`Self::send`], but returns the check runs as a [`crate::Page`]
+    /// so the rest can be fetched with [`Octocrab::all_pages`].
+    pub(crate) async fn send_page(self) -> Result<crate::Page<models::checks::CheckRun>> {
+        let route = format!(
+            "/repos/{owner}/{repo}/commits/{ref}/check-runs",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+            ref = self.git_ref.ref_url_path(),
+        );
+
+        self.handler
+            .crab
+            .operation("checks.list_check_runs_for_git_ref.send_page")
+            .get(route, Some(&self))
+            .await
     }
 }
 
@@ -378,10 +415,14 @@ impl<'octo, 'r> crate::checks::ListCheckSuitesForGitRefBuilder<'octo, 'r> {
             "/repos/{owner}/{repo}/commits/{ref}/check-suites",
             owner = self.handler.owner,
             repo = self.handler.repo,
-            ref = self.git_ref,
+            ref = self.git_ref.ref_url_path(),
         );
 
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("checks.list_check_suites_for_git_ref")
+            .get(route, Some(&self))
+            .await
     }
 
     /// Results per page (max 100).
@@ -613,7 +654,8 @@ impl<'octo> ChecksHandler<'octo> {
     ///```no_run
     /// use octocrab::models::CheckRunId;
     /// use octocrab::params::checks::CheckRunAnnotation;
-    ///  async fn run() -> octocrab::Result<Vec<CheckRunAnnotation>> {
+    /// use octocrab::Page;
+    ///  async fn run() -> octocrab::Result<Page<CheckRunAnnotation>> {
     ///   let check_run_annotations_result = octocrab::instance()
     ///    .checks("owner", "repo")
     ///    .list_annotations(CheckRunId(42))
@@ -663,7 +705,11 @@ impl<'octo, 'r> CreateCheckSuiteBuilder<'octo, 'r> {
             owner = self.handler.owner,
             repo = self.handler.repo
         );
-        self.handler.crab.post(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("checks.create_check_suite")
+            .post(route, Some(&self))
+            .await
     }
 }
 
@@ -695,7 +741,11 @@ impl<'octo, 'r> CheckSuitePreferencesBuilder<'octo, 'r> {
             owner = self.handler.owner,
             repo = self.handler.repo
         );
-        self.handler.crab.patch(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("checks.update_preferences")
+            .patch(route, Some(&self))
+            .await
     }
 }
 
@@ -725,7 +775,11 @@ impl<'octo, 'r> GetCheckSuiteBuilder<'octo, 'r> {
             repo = self.handler.repo,
             check_suite_id = self.check_suite_id
         );
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("checks.get_check_suite")
+            .get(route, Some(&self))
+            .await
     }
 }
 
@@ -755,7 +809,12 @@ impl<'octo, 'r> crate::checks::RerequestCheckSuiteBuilder<'octo, 'r> {
             repo = self.handler.repo,
             check_suite_id = self.check_suite_id
         );
-        let response = self.handler.crab._post(route, Some(&self)).await?;
+        let response = self
+            .handler
+            .crab
+            .operation("checks.rerequest_check_suite")
+            ._post(route, Some(&self))
+            .await?;
         if !response.status().is_success() {
             return Err(crate::map_github_error(response).await.unwrap_err());
         }
@@ -790,7 +849,12 @@ impl<'octo, 'r> crate::checks::RerequestCheckRunBuilder<'octo, 'r> {
             repo = self.handler.repo,
             check_run_id = self.check_run_id
         );
-        let response = self.handler.crab._post(route, Some(&self)).await?;
+        let response = self
+            .handler
+            .crab
+            .operation("checks.rerequest_check_run")
+            ._post(route, Some(&self))
+            .await?;
         if !response.status().is_success() {
             return Err(crate::map_github_error(response).await.unwrap_err());
         }
@@ -803,6 +867,7 @@ impl<'octo, 'r> crate::checks::RerequestCheckRunBuilder<'octo, 'r> {
 pub struct CheckRunAnnotationsBuilder<'octo, 'r> {
     #[serde(skip)]
     handler: &'r ChecksHandler<'octo>,
+    #[serde(skip)]
     check_run_id: CheckRunId,
     per_page: Option<u8>,
     page: Option<u32>,
@@ -822,14 +887,18 @@ impl<'octo, 'r> crate::checks::CheckRunAnnotationsBuilder<'octo, 'r> {
     /// see https://docs.github.com/en/rest/checks/runs?apiVersion=2022-11-28#list-check-run-annotations
     ///
     /// [`ChecksHandler.list_annotations()`]: ChecksHandler#method.list_annotations()
-    pub async fn send(self) -> Result<Vec<CheckRunAnnotation>> {
+    pub async fn send(self) -> Result<crate::Page<CheckRunAnnotation>> {
         let route = format!(
             "/repos/{owner}/{repo}/check-runs/{check_run_id}/annotations",
             owner = self.handler.owner,
             repo = self.handler.repo,
             check_run_id = self.check_run_id
         );
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("checks.list_annotations")
+            .get(route, Some(&self))
+            .await
     }
 
     /// Results per page (max 100).
@@ -1,6 +1,7 @@
 //! The code scanning API.
 use crate::{models, params, Octocrab, Result};
 
+mod analyses;
 mod list;
 mod update;
 
@@ -34,7 +35,10 @@ impl<'octo> CodeScanningHandler<'octo> {
             number = number,
         );
 
-        self.crab.get(route, None::<&()>).await
+        self.crab
+            .operation("code_scanning.get")
+            .get(route, None::<&()>)
+            .await
     }
 
     /// List code scannings in the repository.
@@ -42,6 +46,23 @@ impl<'octo> CodeScanningHandler<'octo> {
         list::ListCodeScanningsBuilder::new(self)
     }
 
+    /// List the code scanning analyses of the repository, most recent first.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let analyses = octocrab.code_scannings("owner", "repo")
+    ///     .list_analyses()
+    ///     .tool_name("CodeQL")
+    ///     .reference("refs/heads/main")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_analyses(&self) -> analyses::ListCodeScanningAnalysesBuilder<'_, '_> {
+        analyses::ListCodeScanningAnalysesBuilder::new(self)
+    }
+
     /// Update a code scanning alert
     /// ```no_run
     /// # use octocrab::params;
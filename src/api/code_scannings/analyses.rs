@@ -0,0 +1,97 @@
+use super::*;
+use crate::params::Direction;
+
+#[derive(serde::Serialize)]
+pub struct ListCodeScanningAnalysesBuilder<'octo, 'b> {
+    #[serde(skip)]
+    handler: &'b CodeScanningHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_guid: Option<String>,
+    #[serde(rename = "ref", skip_serializing_if = "Option::is_none")]
+    reference: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sarif_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    direction: Option<Direction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'b> ListCodeScanningAnalysesBuilder<'octo, 'b> {
+    pub(crate) fn new(handler: &'b CodeScanningHandler<'octo>) -> Self {
+        Self {
+            handler,
+            tool_name: None,
+            tool_guid: None,
+            reference: None,
+            sarif_id: None,
+            direction: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Only list analyses made by the tool with this name.
+    pub fn tool_name(mut self, tool_name: impl Into<String>) -> Self {
+        self.tool_name = Some(tool_name.into());
+        self
+    }
+
+    /// Only list analyses made by the tool with this GUID.
+    pub fn tool_guid(mut self, tool_guid: impl Into<String>) -> Self {
+        self.tool_guid = Some(tool_guid.into());
+        self
+    }
+
+    /// Only list analyses of this Git reference, formatted as
+    /// `refs/heads/<branch>` or `refs/pull/<number>/merge`.
+    pub fn reference(mut self, reference: impl Into<String>) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    /// Only list analyses created from this SARIF upload.
+    pub fn sarif_id(mut self, sarif_id: impl Into<String>) -> Self {
+        self.sarif_id = Some(sarif_id.into());
+        self
+    }
+
+    /// The direction of the sort by creation date. Default: descending.
+    pub fn direction(mut self, direction: impl Into<Direction>) -> Self {
+        self.direction = Some(direction.into());
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(
+        self,
+    ) -> crate::Result<crate::Page<models::code_scannings::CodeScanningAnalysis>> {
+        let route = format!(
+            "/repos/{owner}/{repo}/code-scanning/analyses",
+            owner = self.handler.owner,
+            repo = self.handler.repo.as_ref().expect("Repository is required"),
+        );
+
+        self.handler
+            .crab
+            .operation("code_scanning.list_analyses")
+            .get(route, Some(&self))
+            .await
+    }
+}
@@ -13,8 +13,8 @@ pub struct ListCodeScanningsBuilder<'octo, 'b> {
     per_page: Option<u8>,
     #[serde(skip_serializing_if = "Option::is_none")]
     page: Option<u32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    reference: Option<params::code_scannings::Reference>,
+    #[serde(rename = "ref", skip_serializing_if = "Option::is_none")]
+    reference: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     direction: Option<Direction>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -47,6 +47,31 @@ impl<'octo, 'b, 'c, 'd> ListCodeScanningsBuilder<'octo, 'b> {
         self
     }
 
+    /// Only list alerts found by the tool with this name.
+    pub fn tool_name(mut self, tool_name: impl Into<String>) -> Self {
+        self.tool_name = Some(tool_name.into());
+        self
+    }
+
+    /// Only list alerts found by the tool with this GUID.
+    pub fn tool_guid(mut self, tool_guid: impl Into<String>) -> Self {
+        self.tool_guid = Some(tool_guid.into());
+        self
+    }
+
+    /// Only list alerts for this Git reference, formatted as
+    /// `refs/heads/<branch>` or `refs/pull/<number>/merge`.
+    pub fn reference(mut self, reference: impl Into<String>) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    /// Only list alerts of this severity.
+    pub fn severity(mut self, severity: impl Into<params::code_scannings::Severity>) -> Self {
+        self.severity = Some(severity.into());
+        self
+    }
+
     /// What to sort results by. Can be either `created`, `updated`,
     /// `popularity` (comment count) or `long-running` (age, filtering by pulls
     /// updated in the last month).
@@ -95,6 +120,10 @@ impl<'octo, 'b, 'c, 'd> ListCodeScanningsBuilder<'octo, 'b> {
                 owner = self.handler.owner,
             ));
 
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("code_scanning.list")
+            .get(route, Some(&self))
+            .await
     }
 }
@@ -49,6 +49,10 @@ impl<'octo, 'a, 'b, 'c> UpdateCodeScanningBuilder<'octo, 'a> {
             code_scanning = self.number,
         );
 
-        self.handler.crab.patch(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("code_scanning.update")
+            .patch(route, Some(&self))
+            .await
     }
 }
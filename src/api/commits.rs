@@ -3,10 +3,15 @@ mod associated_check_runs;
 mod associated_pull_requests;
 mod compare_commit;
 mod create_comment;
+mod list_comments;
 
 pub use associated_pull_requests::PullRequestTarget;
 
+pub use self::associated_check_runs::AssociatedCheckRunsBuilder;
+pub use self::associated_pull_requests::AssociatedPullRequestsBuilder;
 pub use self::create_comment::CreateCommentBuilder;
+pub use self::list_comments::ListCommitCommentsBuilder;
+use crate::api::repos::{ListStatusesBuilder, RepoHandler, RepoRef};
 use crate::params::repos::Reference;
 use crate::{models, Octocrab, Result};
 
@@ -14,11 +19,20 @@ pub struct CommitHandler<'octo> {
     crab: &'octo Octocrab,
     owner: String,
     repo: String,
+    /// The same repository, for the commit endpoints living on [`RepoHandler`].
+    repo_handler: RepoHandler<'octo>,
 }
 
 impl<'octo> CommitHandler<'octo> {
     pub(crate) fn new(crab: &'octo Octocrab, owner: String, repo: String) -> Self {
-        Self { crab, owner, repo }
+        let repo_handler =
+            RepoHandler::new(crab, RepoRef::ByOwnerAndName(owner.clone(), repo.clone()));
+        Self {
+            crab,
+            owner,
+            repo,
+            repo_handler,
+        }
     }
 
     // pub fn create(&self, title: impl Into<String>) -> create::CreateIssueBuilder<'_, '_> {
@@ -36,8 +50,8 @@ impl<'octo> CommitHandler<'octo> {
     pub fn associated_check_runs(
         &self,
         reference: impl Into<Reference>,
-    ) -> associated_check_runs::AssociatedCheckRunsBuilder<'_, '_> {
-        associated_check_runs::AssociatedCheckRunsBuilder::new(self, reference)
+    ) -> AssociatedCheckRunsBuilder<'_, '_> {
+        AssociatedCheckRunsBuilder::new(self, reference)
     }
 
     pub fn associated_pull_requests(
@@ -55,6 +69,74 @@ impl<'octo> CommitHandler<'octo> {
         create_comment::CreateCommentBuilder::new(self, sha.into(), body.into())
     }
 
+    /// Lists the comments on the commit with the given `sha`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let comments = octocrab::instance()
+    ///     .commits("owner", "repo")
+    ///     .list_comments("6dcb09b5b57875f334f61aebed695e2e4193db5e")
+    ///     .per_page(50)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_comments(&self, sha: impl Into<String>) -> ListCommitCommentsBuilder<'_, '_> {
+        ListCommitCommentsBuilder::new(self, sha.into())
+    }
+
+    /// Lists the check runs of the commit with the given `sha`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let check_runs = octocrab::instance()
+    ///     .commits("owner", "repo")
+    ///     .list_check_runs("6dcb09b5b57875f334f61aebed695e2e4193db5e")
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_check_runs(&self, sha: impl Into<String>) -> AssociatedCheckRunsBuilder<'_, '_> {
+        AssociatedCheckRunsBuilder::for_sha(self, sha.into())
+    }
+
+    /// Lists the statuses of the commit with the given `sha`, most recent
+    /// first.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let statuses = octocrab::instance()
+    ///     .commits("owner", "repo")
+    ///     .list_statuses("6dcb09b5b57875f334f61aebed695e2e4193db5e")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_statuses(&self, sha: impl Into<String>) -> ListStatusesBuilder<'_, '_> {
+        self.repo_handler.list_statuses(sha.into())
+    }
+
+    /// Lists the pull requests that introduced the commit with the given
+    /// `sha`. Merged pull requests are only returned if the commit is on the
+    /// repository's default branch.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let pulls = octocrab::instance()
+    ///     .commits("owner", "repo")
+    ///     .list_pull_requests("6dcb09b5b57875f334f61aebed695e2e4193db5e")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_pull_requests(
+        &self,
+        sha: impl Into<String>,
+    ) -> AssociatedPullRequestsBuilder<'_, '_> {
+        self.associated_pull_requests(PullRequestTarget::Sha(sha.into()))
+    }
+
     pub async fn get(&self, reference: impl Into<String>) -> Result<models::repos::RepoCommit> {
         let route = format!(
             "/repos/{owner}/{repo}/commits/{reference}",
@@ -62,6 +144,9 @@ impl<'octo> CommitHandler<'octo> {
             repo = self.repo,
             reference = reference.into(),
         );
-        self.crab.get(route, None::<&()>).await
+        self.crab
+            .operation("commits.get")
+            .get(route, None::<&()>)
+            .await
     }
 }
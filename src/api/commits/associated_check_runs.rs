@@ -3,12 +3,19 @@ use crate::models::checks::ListCheckRuns;
 use crate::params::repos::Reference;
 use crate::Result;
 
+/// A builder pattern struct for listing the check runs of a commit.
+///
+/// Created by [`CommitHandler::associated_check_runs`] and
+/// [`CommitHandler::list_check_runs`].
 #[derive(serde::Serialize)]
 pub struct AssociatedCheckRunsBuilder<'octo, 'r> {
     #[serde(skip)]
     handler: &'r CommitHandler<'octo>,
+    /// The full reference or SHA of the commit.
     #[serde(skip)]
-    reference: Reference,
+    reference: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    check_name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     per_page: Option<u8>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -17,14 +24,25 @@ pub struct AssociatedCheckRunsBuilder<'octo, 'r> {
 
 impl<'octo, 'r> AssociatedCheckRunsBuilder<'octo, 'r> {
     pub(crate) fn new(handler: &'r CommitHandler<'octo>, reference: impl Into<Reference>) -> Self {
+        Self::for_sha(handler, reference.into().full_ref())
+    }
+
+    pub(crate) fn for_sha(handler: &'r CommitHandler<'octo>, sha: String) -> Self {
         Self {
             handler,
-            reference: reference.into(),
+            reference: sha,
+            check_name: None,
             per_page: None,
             page: None,
         }
     }
 
+    /// Only return check runs with the given name.
+    pub fn check_name(mut self, check_name: impl Into<String>) -> Self {
+        self.check_name = Some(check_name.into());
+        self
+    }
+
     /// Results per page (max 100).
     pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
         self.per_page = Some(per_page.into());
@@ -43,9 +61,13 @@ impl<'octo, 'r> AssociatedCheckRunsBuilder<'octo, 'r> {
             "/repos/{owner}/{repo}/commits/{reference}/check-runs",
             owner = self.handler.owner,
             repo = self.handler.repo,
-            reference = self.reference.full_ref_url()
+            reference = crate::params::repos::encode_ref_path(&self.reference)
         );
 
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("commits.associated_check_runs")
+            .get(route, Some(&self))
+            .await
     }
 }
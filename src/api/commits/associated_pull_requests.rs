@@ -64,7 +64,11 @@ impl<'octo, 'r> AssociatedPullRequestsBuilder<'octo, 'r> {
             target = self.target,
         );
 
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("commits.associated_pull_requests")
+            .get(route, Some(&self))
+            .await
     }
 }
 
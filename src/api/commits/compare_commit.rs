@@ -49,7 +49,11 @@ impl<'octo, 'r> CompareCommitsBuilder<'octo, 'r> {
             head = self.head,
         );
 
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("commits.compare")
+            .get(route, Some(&self))
+            .await
     }
 }
 
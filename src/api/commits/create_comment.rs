@@ -35,7 +35,11 @@ impl<'octo, 'r> CreateCommentBuilder<'octo, 'r> {
             commit_sha = self.sha,
         );
 
-        self.handler.crab.post(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("commits.create_comment")
+            .post(route, Some(&self))
+            .await
     }
 
     /// Relative path of the file to comment on.
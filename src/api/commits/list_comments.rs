@@ -0,0 +1,55 @@
+use super::*;
+
+/// A builder pattern struct for listing the comments on a commit.
+///
+/// Created by [`CommitHandler::list_comments`].
+#[derive(serde::Serialize)]
+pub struct ListCommitCommentsBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r CommitHandler<'octo>,
+    #[serde(skip)]
+    sha: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListCommitCommentsBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r CommitHandler<'octo>, sha: String) -> Self {
+        Self {
+            handler,
+            sha,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<crate::Page<models::commits::Comment>> {
+        let route = format!(
+            "/repos/{owner}/{repo}/commits/{commit_sha}/comments",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+            commit_sha = self.sha,
+        );
+
+        self.handler
+            .crab
+            .operation("commits.list_comments")
+            .get(route, Some(&self))
+            .await
+    }
+}
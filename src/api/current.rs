@@ -2,7 +2,8 @@
 
 use crate::{
     models::{self, gists::Gist, orgs::MembershipInvitation, Installation, Repository},
-    Octocrab, Page, Result,
+    params::users::emails::EmailVisibilityState,
+    FromResponse, Octocrab, Page, Result,
 };
 use chrono::{DateTime, Utc};
 
@@ -22,7 +23,10 @@ impl<'octo> CurrentAuthHandler<'octo> {
 
     /// Fetches information about the current user.
     pub async fn user(&self) -> Result<models::Author> {
-        self.crab.get("/user", None::<&()>).await
+        self.crab
+            .operation("current.user")
+            .get("/user", None::<&()>)
+            .await
     }
 
     /// Fetches information about the currently authenticated app.
@@ -41,7 +45,10 @@ impl<'octo> CurrentAuthHandler<'octo> {
     /// # }
     /// ```
     pub async fn app(&self) -> Result<models::App> {
-        self.crab.get("/app", None::<&()>).await
+        self.crab
+            .operation("current.app")
+            .get("/app", None::<&()>)
+            .await
     }
 
     /// List repositories starred by current authenticated user.
@@ -163,6 +170,336 @@ impl<'octo> CurrentAuthHandler<'octo> {
     ) -> ListOrgMembershipsForAuthenticatedUserBuilder<'octo> {
         ListOrgMembershipsForAuthenticatedUserBuilder::new(self.crab)
     }
+
+    /// Lists the organizations the authenticated user is a member of,
+    /// including private memberships.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .current()
+    ///     .list_orgs()
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [See the GitHub API documentation](https://docs.github.com/en/rest/orgs/orgs#list-organizations-for-the-authenticated-user)
+    pub fn list_orgs(&self) -> ListOrgsForAuthenticatedUserBuilder<'octo> {
+        ListOrgsForAuthenticatedUserBuilder::new(self.crab)
+    }
+
+    /// Lists the users blocked by the authenticated user.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let blocked = octocrab::instance()
+    ///     .current()
+    ///     .list_blocked_users()
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [See the GitHub API documentation](https://docs.github.com/en/rest/users/blocking#list-users-blocked-by-the-authenticated-user)
+    pub fn list_blocked_users(&self) -> ListBlockedUsersBuilder<'octo> {
+        ListBlockedUsersBuilder::new(self.crab)
+    }
+
+    /// Checks whether the authenticated user has blocked `username`.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let blocking = octocrab::instance()
+    ///     .current()
+    ///     .is_blocking("spammer")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn is_blocking(&self, username: impl AsRef<str>) -> Result<bool> {
+        let route = format!("/user/blocks/{}", username.as_ref());
+        let response = self
+            .crab
+            .operation("current.is_blocking")
+            ._get(route)
+            .await?;
+
+        match response.status() {
+            http::StatusCode::NO_CONTENT => Ok(true),
+            http::StatusCode::NOT_FOUND => Ok(false),
+            _ => crate::map_github_error(response).await.map(|_| false),
+        }
+    }
+
+    /// Blocks `username` for the authenticated user.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .current()
+    ///     .block_user("spammer")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn block_user(&self, username: impl AsRef<str>) -> Result<()> {
+        let route = format!("/user/blocks/{}", username.as_ref());
+        crate::map_github_error(
+            self.crab
+                .operation("current.block_user")
+                ._put(route, None::<&()>)
+                .await?,
+        )
+        .await
+        .map(drop)
+    }
+
+    /// Unblocks `username` for the authenticated user.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .current()
+    ///     .unblock_user("spammer")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn unblock_user(&self, username: impl AsRef<str>) -> Result<()> {
+        let route = format!("/user/blocks/{}", username.as_ref());
+        crate::map_github_error(
+            self.crab
+                .operation("current.unblock_user")
+                ._delete(route, None::<&()>)
+                .await?,
+        )
+        .await
+        .map(drop)
+    }
+
+    /// Checks whether the authenticated user follows `username`.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let following = octocrab::instance()
+    ///     .current()
+    ///     .is_following("octocat")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn is_following(&self, username: impl AsRef<str>) -> Result<bool> {
+        let route = format!("/user/following/{}", username.as_ref());
+        let response = self
+            .crab
+            .operation("current.is_following")
+            ._get(route)
+            .await?;
+
+        match response.status() {
+            http::StatusCode::NO_CONTENT => Ok(true),
+            http::StatusCode::NOT_FOUND => Ok(false),
+            _ => crate::map_github_error(response).await.map(|_| false),
+        }
+    }
+
+    /// Follows `username` as the authenticated user.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .current()
+    ///     .follow("octocat")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn follow(&self, username: impl AsRef<str>) -> Result<()> {
+        let route = format!("/user/following/{}", username.as_ref());
+        crate::map_github_error(
+            self.crab
+                .operation("current.follow")
+                ._put(route, None::<&()>)
+                .await?,
+        )
+        .await
+        .map(drop)
+    }
+
+    /// Unfollows `username` as the authenticated user.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .current()
+    ///     .unfollow("octocat")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn unfollow(&self, username: impl AsRef<str>) -> Result<()> {
+        let route = format!("/user/following/{}", username.as_ref());
+        crate::map_github_error(
+            self.crab
+                .operation("current.unfollow")
+                ._delete(route, None::<&()>)
+                .await?,
+        )
+        .await
+        .map(drop)
+    }
+
+    /// Fetches the interaction limit in place for the authenticated user's
+    /// public repositories, if any.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let limit = octocrab::instance()
+    ///     .current()
+    ///     .get_interaction_limits()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_interaction_limits(
+        &self,
+    ) -> crate::Result<Option<crate::models::interactions::InteractionLimit>> {
+        crate::api::interactions::get(self.crab, "/user/interaction-limits".to_string()).await
+    }
+
+    /// Limits who can interact with the authenticated user's public
+    /// repositories, replacing any limit already in place.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::interactions::{
+    ///     InteractionExpiry, InteractionGroup, SetInteractionLimit,
+    /// };
+    ///
+    /// let mut limit = SetInteractionLimit::new(InteractionGroup::CollaboratorsOnly);
+    /// limit.expiry = Some(InteractionExpiry::OneWeek);
+    /// octocrab::instance()
+    ///     .current()
+    ///     .set_interaction_limits(&limit)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_interaction_limits(
+        &self,
+        limit: &crate::models::interactions::SetInteractionLimit,
+    ) -> crate::Result<crate::models::interactions::InteractionLimit> {
+        crate::api::interactions::set(self.crab, "/user/interaction-limits".to_string(), limit)
+            .await
+    }
+
+    /// Removes the interaction limit in place for the authenticated user's
+    /// public repositories.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .current()
+    ///     .remove_interaction_limits()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_interaction_limits(&self) -> crate::Result<()> {
+        crate::api::interactions::remove(self.crab, "/user/interaction-limits".to_string()).await
+    }
+
+    /// Lists the email addresses of the authenticated user.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let emails = octocrab::instance()
+    ///     .current()
+    ///     .list_emails()
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [See the GitHub API documentation](https://docs.github.com/en/rest/users/emails#list-email-addresses-for-the-authenticated-user)
+    pub fn list_emails(&self) -> ListEmailsBuilder<'octo> {
+        ListEmailsBuilder::new(self.crab)
+    }
+
+    /// Adds `emails` to the authenticated user's account, returning the
+    /// newly added addresses.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let added = octocrab::instance()
+    ///     .current()
+    ///     .add_emails(&["octocat@example.com"])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_emails(&self, emails: &[&str]) -> Result<Vec<models::UserEmailInfo>> {
+        let body = serde_json::json!({ "emails": emails });
+        let response = self
+            .crab
+            .operation("current.add_emails")
+            ._post("/user/emails", Some(&body))
+            .await?;
+        let response = crate::map_github_error(response).await?;
+        <Vec<models::UserEmailInfo>>::from_response(response).await
+    }
+
+    /// Removes `emails` from the authenticated user's account.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .current()
+    ///     .delete_emails(&["octocat@example.com"])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_emails(&self, emails: &[&str]) -> Result<()> {
+        let body = serde_json::json!({ "emails": emails });
+        let response = self
+            .crab
+            .operation("current.delete_emails")
+            ._delete("/user/emails", Some(&body))
+            .await?;
+        crate::map_github_error(response).await?;
+        Ok(())
+    }
+
+    /// Sets whether the authenticated user's primary email address is
+    /// publicly visible.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::users::emails::EmailVisibilityState;
+    ///
+    /// let emails = octocrab::instance()
+    ///     .current()
+    ///     .set_primary_email_visibility(EmailVisibilityState::Private)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_primary_email_visibility(
+        &self,
+        visibility: EmailVisibilityState,
+    ) -> Result<Vec<models::UserEmailInfo>> {
+        self.crab
+            .operation("current.set_primary_email_visibility")
+            .patch(
+                "/user/email/visibility",
+                Some(&serde_json::json!({ "visibility": visibility })),
+            )
+            .await
+    }
 }
 
 /// A builder pattern struct for listing starred repositories.
@@ -233,7 +570,10 @@ impl<'octo> ListStarredReposBuilder<'octo> {
 
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<Page<Repository>> {
-        self.crab.get("/user/starred", Some(&self)).await
+        self.crab
+            .operation("current.list_repos_starred_by_authenticated_user")
+            .get("/user/starred", Some(&self))
+            .await
     }
 }
 
@@ -370,7 +710,10 @@ impl<'octo> ListReposForAuthenticatedUserBuilder<'octo> {
 
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<Page<Repository>> {
-        self.crab.get("/user/repos", (&self).into()).await
+        self.crab
+            .operation("current.list_repos_for_authenticated_user")
+            .get("/user/repos", (&self).into())
+            .await
     }
 }
 
@@ -433,7 +776,10 @@ impl<'octo> ListGistsForAuthenticatedUserBuilder<'octo> {
 
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<Page<Gist>> {
-        self.crab.get("/gists", Some(&self)).await
+        self.crab
+            .operation("current.list_gists_for_authenticated_user")
+            .get("/gists", Some(&self))
+            .await
     }
 }
 
@@ -486,7 +832,10 @@ impl<'octo> ListStarredGistsBuilder<'octo> {
 
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<Page<Gist>> {
-        self.crab.get("/gists/starred", Some(&self)).await
+        self.crab
+            .operation("current.list_gists_starred_by_authenticated_user")
+            .get("/gists/starred", Some(&self))
+            .await
     }
 }
 
@@ -535,11 +884,148 @@ impl<'octo> ListOrgMembershipsForAuthenticatedUserBuilder<'octo> {
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<Page<MembershipInvitation>> {
         self.crab
+            .operation("current.list_org_memberships_for_authenticated_user")
             .get("/user/memberships/orgs", (&self).into())
             .await
     }
 }
 
+/// A builder pattern struct for listing the users blocked by the authenticated user.
+///
+/// Created by [`CurrentAuthHandler::list_blocked_users`].
+#[derive(serde::Serialize)]
+pub struct ListBlockedUsersBuilder<'octo> {
+    #[serde(skip)]
+    crab: &'octo Octocrab,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo> ListBlockedUsersBuilder<'octo> {
+    fn new(crab: &'octo Octocrab) -> Self {
+        Self {
+            crab,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Page<models::SimpleUser>> {
+        self.crab
+            .operation("current.list_blocked_users")
+            .get("/user/blocks", Some(&self))
+            .await
+    }
+}
+
+/// A builder pattern struct for listing the email addresses of the
+/// authenticated user.
+///
+/// Created by [`CurrentAuthHandler::list_emails`].
+#[derive(serde::Serialize)]
+pub struct ListEmailsBuilder<'octo> {
+    #[serde(skip)]
+    crab: &'octo Octocrab,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo> ListEmailsBuilder<'octo> {
+    fn new(crab: &'octo Octocrab) -> Self {
+        Self {
+            crab,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Page<models::UserEmailInfo>> {
+        self.crab
+            .operation("current.list_emails")
+            .get("/user/emails", Some(&self))
+            .await
+    }
+}
+
+/// A builder pattern struct for listing the organizations of the authenticated user.
+///
+/// Created by [`CurrentAuthHandler::list_orgs`].
+#[derive(serde::Serialize)]
+pub struct ListOrgsForAuthenticatedUserBuilder<'octo> {
+    #[serde(skip)]
+    crab: &'octo Octocrab,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo> ListOrgsForAuthenticatedUserBuilder<'octo> {
+    fn new(crab: &'octo Octocrab) -> Self {
+        Self {
+            crab,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Page<models::orgs::Organization>> {
+        self.crab
+            .operation("current.list_orgs")
+            .get("/user/orgs", Some(&self))
+            .await
+    }
+}
+
 /// A builder pattern struct for listing the installations accessible to a user access token.
 ///
 /// Created by [`CurrentAuthHandler::list_app_installations_accessible_to_user`].
@@ -584,6 +1070,9 @@ impl<'octo> ListAppInstallationsAccessibleToUserBuilder<'octo> {
 
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<Page<Installation>> {
-        self.crab.get("/user/installations", (&self).into()).await
+        self.crab
+            .operation("current.list_app_installations_accessible_to_user")
+            .get("/user/installations", (&self).into())
+            .await
     }
 }
@@ -1,6 +1,7 @@
 //! GitHub Events
 
 use crate::{
+    api::users::UserRef,
     etag::{EntityTag, Etagged},
     models::events,
     FromResponse, Octocrab, Page,
@@ -10,6 +11,7 @@ use http::{header::HeaderMap, Method, StatusCode};
 
 pub struct EventsBuilder<'octo> {
     crab: &'octo Octocrab,
+    route: String,
     headers: Headers,
     params: Params,
 }
@@ -30,6 +32,7 @@ impl<'octo> EventsBuilder<'octo> {
     pub(crate) fn new(crab: &'octo Octocrab) -> Self {
         Self {
             crab,
+            route: "/events".to_string(),
             headers: Headers { etag: None },
             params: Params {
                 per_page: None,
@@ -38,6 +41,53 @@ impl<'octo> EventsBuilder<'octo> {
         }
     }
 
+    /// Lists the events of a repository instead of all public events.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let events = octocrab::instance()
+    ///     .events()
+    ///     .for_repo("owner", "repo")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn for_repo(mut self, owner: impl AsRef<str>, repo: impl AsRef<str>) -> Self {
+        self.route = format!("/repos/{}/{}/events", owner.as_ref(), repo.as_ref());
+        self
+    }
+
+    /// Lists the public events of an organization instead of all public
+    /// events.
+    pub fn for_org(mut self, org: impl AsRef<str>) -> Self {
+        self.route = format!("/orgs/{}/events", org.as_ref());
+        self
+    }
+
+    /// Lists the events performed by a user instead of all public events.
+    /// Private events are included when authenticated as that user. Wait
+    /// [`Etagged::poll_interval`] between polls.
+    pub fn for_user(self, login: impl AsRef<str>) -> Self {
+        self.for_user_ref(&UserRef::ByString(login.as_ref().to_string()))
+    }
+
+    /// Lists the events received by a user, i.e. the activity of the users
+    /// they follow and the repositories they watch, instead of all public
+    /// events.
+    pub fn received_by_user(self, login: impl AsRef<str>) -> Self {
+        self.received_by_user_ref(&UserRef::ByString(login.as_ref().to_string()))
+    }
+
+    pub(crate) fn for_user_ref(mut self, user: &UserRef) -> Self {
+        self.route = format!("/{}/events", user);
+        self
+    }
+
+    pub(crate) fn received_by_user_ref(mut self, user: &UserRef) -> Self {
+        self.route = format!("/{}/received_events", user);
+        self
+    }
+
     /// Etag for this request.
     pub fn etag(mut self, etag: Option<EntityTag>) -> Self {
         self.headers.etag = etag;
@@ -58,8 +108,9 @@ impl<'octo> EventsBuilder<'octo> {
 
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<Etagged<Page<events::Event>>> {
-        let route = "/events".to_string();
-        let uri = self.crab.parameterized_uri(route, Some(&self.params))?;
+        let uri = self
+            .crab
+            .parameterized_uri(&self.route, Some(&self.params))?;
 
         let mut headers = HeaderMap::new();
         if let Some(etag) = self.headers.etag {
@@ -69,18 +120,27 @@ impl<'octo> EventsBuilder<'octo> {
         for (key, value) in headers.iter() {
             builder = builder.header(key, value);
         }
-        let request = self.crab.build_request(builder, None::<&()>)?;
+        let request = self
+            .crab
+            .operation("users.events")
+            .build_request(builder, None::<&()>)?;
 
         let response = self.crab.execute(request).await?;
         let etag = EntityTag::extract_from_response(&response);
+        let poll_interval = crate::etag::extract_poll_interval(&response);
         if response.status() == StatusCode::NOT_MODIFIED {
-            Ok(Etagged { etag, value: None })
+            Ok(Etagged {
+                etag,
+                value: None,
+                poll_interval,
+            })
         } else {
             <Page<events::Event>>::from_response(crate::map_github_error(response).await?)
                 .await
                 .map(|page| Etagged {
                     etag,
                     value: Some(page),
+                    poll_interval,
                 })
         }
     }
@@ -5,6 +5,7 @@
 //! [Official documentation][docs]
 //!
 //! [docs]: https://docs.github.com/en/rest/gists/gists?apiVersion=2022-11-28
+mod list_comments;
 mod list_commits;
 mod list_forks;
 mod list_gists;
@@ -13,11 +14,16 @@ use http::StatusCode;
 use serde::Serialize;
 use std::collections::BTreeMap;
 
+pub use self::list_comments::ListGistCommentsBuilder;
 pub use self::list_commits::ListCommitsBuilder;
 pub use self::list_gists::{ListAllGistsBuilder, ListPublicGistsBuilder, ListUserGistsBuilder};
 
 use crate::{
-    models::gists::{Gist, GistRevision},
+    current::ListStarredGistsBuilder,
+    models::{
+        gists::{Gist, GistComment, GistRevision},
+        CommentId,
+    },
     Octocrab, Result,
 };
 
@@ -180,7 +186,10 @@ impl<'octo> GistsHandler<'octo> {
     /// ```
     pub async fn get(&self, id: impl AsRef<str>) -> Result<Gist> {
         let id = id.as_ref();
-        self.crab.get(format!("/gists/{id}"), None::<&()>).await
+        self.crab
+            .operation("gists.get")
+            .get(format!("/gists/{id}"), None::<&()>)
+            .await
     }
 
     /// Delete a single gist.
@@ -195,6 +204,7 @@ impl<'octo> GistsHandler<'octo> {
         let gist_id = gist_id.as_ref();
         let response = self
             .crab
+            .operation("gists.delete")
             ._delete(format!("/gists/{gist_id}"), None::<&()>)
             .await?;
 
@@ -223,6 +233,7 @@ impl<'octo> GistsHandler<'octo> {
         let id = id.as_ref();
         let sha1 = sha1.as_ref();
         self.crab
+            .operation("gists.get_revision")
             .get(format!("/gists/{id}/{sha1}"), None::<&()>)
             .await
     }
@@ -250,6 +261,27 @@ impl<'octo> GistsHandler<'octo> {
         list_commits::ListCommitsBuilder::new(self, gist_id.into())
     }
 
+    /// List the gists starred by the authenticated user. See
+    /// [GitHub API Documentation][docs] for more information about request
+    /// parameters.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let starred = octocrab::instance()
+    ///     .gists()
+    ///     .list_starred()
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [docs]: https://docs.github.com/en/rest/gists/gists?apiVersion=2022-11-28#list-starred-gists
+    pub fn list_starred(&self) -> ListStarredGistsBuilder<'octo> {
+        ListStarredGistsBuilder::new(self.crab)
+    }
+
     /// Check if the given is gist is already starred by the authenticated user.
     /// See [GitHub API Documentation][docs] more information about response
     /// data.
@@ -267,7 +299,11 @@ impl<'octo> GistsHandler<'octo> {
     /// [docs]: https://docs.github.com/en/rest/gists/gists?apiVersion=2022-11-28#check-if-a-gist-is-starred
     pub async fn is_starred(&self, gist_id: impl AsRef<str>) -> Result<bool> {
         let gist_id = gist_id.as_ref();
-        let response = self.crab._get(format!("/gists/{gist_id}/star")).await?;
+        let response = self
+            .crab
+            .operation("gists.is_starred")
+            ._get(format!("/gists/{gist_id}/star"))
+            .await?;
         // Gist API returns 204 (NO CONTENT) if a gist is starred
 
         match response.status() {
@@ -297,6 +333,7 @@ impl<'octo> GistsHandler<'octo> {
         // sense to deserialize it as JSON.
         let response = self
             .crab
+            .operation("gists.star")
             ._put(format!("/gists/{gist_id}/star"), None::<&()>)
             .await?;
 
@@ -327,6 +364,7 @@ impl<'octo> GistsHandler<'octo> {
         // sense to deserialize it as JSON.
         let response = self
             .crab
+            .operation("gists.unstar")
             ._delete(format!("/gists/{gist_id}/star"), None::<&()>)
             .await?;
 
@@ -364,7 +402,119 @@ impl<'octo> GistsHandler<'octo> {
     /// [docs]: https://docs.github.com/en/rest/gists/gists?apiVersion=2022-11-28#fork-a-gist
     pub async fn fork(&self, gist_id: impl AsRef<str>) -> Result<Gist> {
         let route = format!("/gists/{gist_id}/forks", gist_id = gist_id.as_ref());
-        self.crab.post(route, None::<&()>).await
+        self.crab
+            .operation("gists.fork")
+            .post(route, None::<&()>)
+            .await
+    }
+
+    /// List the comments on the given `gist_id`. See [GitHub API Docs][docs]
+    /// for more information about request parameters and response schema.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let comments = octocrab::instance()
+    ///     .gists()
+    ///     .list_comments("00000000000000000000000000000000")
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [docs]: https://docs.github.com/en/rest/gists/comments?apiVersion=2022-11-28#list-gist-comments
+    pub fn list_comments(&self, gist_id: impl Into<String>) -> ListGistCommentsBuilder<'octo, '_> {
+        ListGistCommentsBuilder::new(self, gist_id.into())
+    }
+
+    /// Comment on the given `gist_id`. See [GitHub API Docs][docs] for more
+    /// information about response schema.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let comment = octocrab::instance()
+    ///     .gists()
+    ///     .create_comment("00000000000000000000000000000000", "Nice gist!")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [docs]: https://docs.github.com/en/rest/gists/comments?apiVersion=2022-11-28#create-a-gist-comment
+    pub async fn create_comment(
+        &self,
+        gist_id: impl AsRef<str>,
+        body: impl AsRef<str>,
+    ) -> Result<GistComment> {
+        let route = format!("/gists/{gist_id}/comments", gist_id = gist_id.as_ref());
+        self.crab
+            .operation("gists.create_comment")
+            .post(route, Some(&serde_json::json!({ "body": body.as_ref() })))
+            .await
+    }
+
+    /// Replace the body of `comment_id` on the given `gist_id`. See
+    /// [GitHub API Docs][docs] for more information about response schema.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let comment = octocrab::instance()
+    ///     .gists()
+    ///     .update_comment("00000000000000000000000000000000", 1u64.into(), "Nicer gist!")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [docs]: https://docs.github.com/en/rest/gists/comments?apiVersion=2022-11-28#update-a-gist-comment
+    pub async fn update_comment(
+        &self,
+        gist_id: impl AsRef<str>,
+        comment_id: CommentId,
+        body: impl AsRef<str>,
+    ) -> Result<GistComment> {
+        let route = format!(
+            "/gists/{gist_id}/comments/{comment_id}",
+            gist_id = gist_id.as_ref()
+        );
+        self.crab
+            .operation("gists.update_comment")
+            .patch(route, Some(&serde_json::json!({ "body": body.as_ref() })))
+            .await
+    }
+
+    /// Delete `comment_id` from the given `gist_id`. See
+    /// [GitHub API Docs][docs] for more information.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .gists()
+    ///     .delete_comment("00000000000000000000000000000000", 1u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [docs]: https://docs.github.com/en/rest/gists/comments?apiVersion=2022-11-28#delete-a-gist-comment
+    pub async fn delete_comment(
+        &self,
+        gist_id: impl AsRef<str>,
+        comment_id: CommentId,
+    ) -> Result<()> {
+        let route = format!(
+            "/gists/{gist_id}/comments/{comment_id}",
+            gist_id = gist_id.as_ref()
+        );
+        crate::map_github_error(
+            self.crab
+                .operation("gists.delete_comment")
+                ._delete(route, None::<&()>)
+                .await?,
+        )
+        .await
+        .map(drop)
     }
 }
 
@@ -406,7 +556,10 @@ impl<'octo> CreateGistBuilder<'octo> {
 
     /// Send the `CreateGist` request to Github for execution.
     pub async fn send(self) -> Result<Gist> {
-        self.crab.post("/gists", Some(&self.data)).await
+        self.crab
+            .operation("gists.create")
+            .post("/gists", Some(&self.data))
+            .await
     }
 }
 
@@ -455,9 +608,46 @@ impl<'octo> UpdateGistBuilder<'octo> {
         UpdateGistFileBuilder::new(self, filename)
     }
 
+    /// Apply several file edits at once. A `None` edit deletes the file,
+    /// `Some` updates its content and/or renames it. Files that aren't
+    /// mentioned are kept unchanged.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use std::collections::HashMap;
+    /// use octocrab::gists::UpdateGistFile;
+    ///
+    /// let mut files = HashMap::new();
+    /// files.insert("config.toml", Some(UpdateGistFile::default().content("debug = true")));
+    /// files.insert("old.toml", Some(UpdateGistFile::default().filename("new.toml")));
+    /// files.insert("unused.toml", None);
+    ///
+    /// let gist = octocrab::instance()
+    ///     .gists()
+    ///     .update("aa5a315d61ae9438b18d")
+    ///     .files(files)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn files<K: Into<String>>(
+        mut self,
+        files: impl IntoIterator<Item = (K, Option<UpdateGistFile>)>,
+    ) -> Self {
+        self.data
+            .files
+            .get_or_insert_with(BTreeMap::new)
+            .extend(files.into_iter().map(|(name, file)| (name.into(), file)));
+        self
+    }
+
     /// Send the `UpdateGist` command to Github for execution.
     pub async fn send(self) -> Result<Gist> {
-        self.crab.patch(self.gist_path, Some(&self.data)).await
+        self.crab
+            .operation("gists.update")
+            .patch(self.gist_path, Some(&self.data))
+            .await
     }
 }
 
@@ -477,6 +667,20 @@ pub struct UpdateGistFile {
     content: Option<String>,
 }
 
+impl UpdateGistFile {
+    /// Rename the file to `filename`.
+    pub fn filename(mut self, filename: impl Into<String>) -> Self {
+        self.filename = Some(filename.into());
+        self
+    }
+
+    /// Overwrite the content of the file with `content`.
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+}
+
 pub struct UpdateGistFileBuilder<'octo> {
     builder: UpdateGistBuilder<'octo>,
     filename: String,
@@ -37,6 +37,10 @@ impl<'octo, 'b> ListCommitsBuilder<'octo, 'b> {
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<crate::Page<crate::models::gists::GistCommit>> {
         let route = format!("/gists/{gist_id}/commits", gist_id = self.gist_id);
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("gists.list_commits")
+            .get(route, Some(&self))
+            .await
     }
 }
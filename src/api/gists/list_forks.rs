@@ -45,6 +45,10 @@ impl<'octo, 'b> ListGistForksBuilder<'octo, 'b> {
     /// Sends the actual request to GitHub's API
     pub async fn send(self) -> Result<Page<Gist>> {
         let route = format!("/gists/{gist_id}/forks", gist_id = self.gist_id);
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("gists.list_forks")
+            .get(route, Some(&self))
+            .await
     }
 }
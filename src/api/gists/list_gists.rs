@@ -69,7 +69,10 @@ impl<'octo, T: EndpointSelector> ListGistsBuilder<'octo, T> {
 
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<crate::Page<crate::models::gists::Gist>> {
-        self.crab.get(T::ENDPOINT, Some(&self)).await
+        self.crab
+            .operation("gists.list_all_gists")
+            .get(T::ENDPOINT, Some(&self))
+            .await
     }
 }
 
@@ -132,6 +135,7 @@ impl<'octo> ListUserGistsBuilder<'octo> {
 
     pub async fn send(self) -> crate::Result<crate::Page<Gist>> {
         self.crab
+            .operation("gists.list_user_gists")
             .get(
                 format!("/users/{username}/gists", username = self.username),
                 Some(&self),
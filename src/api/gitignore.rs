@@ -27,7 +27,10 @@ impl<'octo> GitignoreHandler<'octo> {
     /// # }
     /// ```
     pub async fn list(&self) -> crate::Result<Vec<String>> {
-        self.crab.get("/gitignore/templates", None::<&()>).await
+        self.crab
+            .operation("gitignore.list")
+            .get("/gitignore/templates", None::<&()>)
+            .await
     }
 
     /// Get the source of a single template.
@@ -46,7 +49,10 @@ impl<'octo> GitignoreHandler<'octo> {
         let mut request = request::Builder::new().method("GET").uri(uri);
         request = request.header(http::header::ACCEPT, crate::format_media_type("raw"));
 
-        let request = self.crab.build_request(request, None::<&()>)?;
+        let request = self
+            .crab
+            .operation("gitignore.get")
+            .build_request(request, None::<&()>)?;
 
         let response = self.crab.execute(request).await?;
         self.crab.body_to_string(response).await
@@ -2,10 +2,19 @@
 use crate::models::{HookDeliveryId, HookId};
 use crate::Octocrab;
 
+mod create_hook;
 mod list_deliveries;
 mod retry_delivery;
+mod update_hook;
+mod webhooks;
 
-pub use self::{list_deliveries::ListHooksDeliveriesBuilder, retry_delivery::RetryDeliveryBuilder};
+pub use self::{
+    create_hook::CreateHookBuilder,
+    list_deliveries::ListHooksDeliveriesBuilder,
+    retry_delivery::RetryDeliveryBuilder,
+    update_hook::UpdateHookBuilder,
+    webhooks::{ListHooksBuilder, WebhooksHandler},
+};
 
 /// A client to GitHub's webhooks API.
 ///
@@ -0,0 +1,81 @@
+use super::*;
+use crate::models::hooks::{Config, ContentType, Hook};
+use crate::models::webhook_events::WebhookEventType;
+
+/// A builder pattern struct for creating a webhook.
+///
+/// Created by [`WebhooksHandler::create`](crate::hooks::WebhooksHandler::create).
+#[derive(serde::Serialize)]
+pub struct CreateHookBuilder<'octo> {
+    #[serde(skip)]
+    crab: &'octo Octocrab,
+    #[serde(skip)]
+    route: String,
+    name: &'static str,
+    config: Config,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    events: Option<Vec<WebhookEventType>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    active: Option<bool>,
+}
+
+impl<'octo> CreateHookBuilder<'octo> {
+    pub(crate) fn new(crab: &'octo Octocrab, route: String, url: String) -> Self {
+        Self {
+            crab,
+            route,
+            name: "web",
+            config: Config {
+                url,
+                ..Config::default()
+            },
+            events: None,
+            active: None,
+        }
+    }
+
+    /// The URL to which the payloads will be delivered.
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.config.url = url.into();
+        self
+    }
+
+    /// The media type used to serialize the payloads.
+    pub fn content_type(mut self, content_type: impl Into<ContentType>) -> Self {
+        self.config.content_type = Some(content_type.into());
+        self
+    }
+
+    /// The secret used to sign the payloads with the `X-Hub-Signature-256`
+    /// header.
+    pub fn secret(mut self, secret: impl Into<String>) -> Self {
+        self.config.secret = Some(secret.into());
+        self
+    }
+
+    /// Whether to skip verifying the SSL certificate of the URL.
+    pub fn insecure_ssl(mut self, insecure_ssl: bool) -> Self {
+        self.config.insecure_ssl = Some(if insecure_ssl { "1" } else { "0" }.to_string());
+        self
+    }
+
+    /// The events the hook is triggered for. Defaults to `push`.
+    pub fn events(mut self, events: impl Into<Vec<WebhookEventType>>) -> Self {
+        self.events = Some(events.into());
+        self
+    }
+
+    /// Whether notifications are sent when the hook is triggered.
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = Some(active);
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Hook> {
+        self.crab
+            .operation("webhooks.create")
+            .post(&self.route, Some(&self))
+            .await
+    }
+}
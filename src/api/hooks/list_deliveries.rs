@@ -50,6 +50,10 @@ impl<'octo, 'r> ListHooksDeliveriesBuilder<'octo, 'r> {
                 self.handler.owner, self.hook_id
             ),
         };
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("hooks.list_deliveries")
+            .get(route, Some(&self))
+            .await
     }
 }
@@ -47,8 +47,14 @@ impl<'octo, 'r> RetryDeliveryBuilder<'octo, 'r> {
             .path_and_query(route)
             .build()
             .context(HttpSnafu)?;
-        crate::map_github_error(self.handler.crab._post(uri, None::<&()>).await?)
-            .await
-            .map(drop)
+        crate::map_github_error(
+            self.handler
+                .crab
+                .operation("hooks.retry_delivery")
+                ._post(uri, None::<&()>)
+                .await?,
+        )
+        .await
+        .map(drop)
     }
 }
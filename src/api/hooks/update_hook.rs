@@ -0,0 +1,172 @@
+use super::*;
+use crate::models::hooks::{ContentType, Hook};
+use crate::models::webhook_events::WebhookEventType;
+
+/// A builder pattern struct for updating a webhook. Only the settings that
+/// are set on the builder are sent. Changes to the URL, content type, secret
+/// or SSL verification are sent as the webhook's `config` in the same
+/// request.
+///
+/// Created by [`WebhooksHandler::update`](crate::hooks::WebhooksHandler::update).
+#[derive(serde::Serialize)]
+pub struct UpdateHookBuilder<'octo> {
+    #[serde(skip)]
+    crab: &'octo Octocrab,
+    #[serde(skip)]
+    route: String,
+    #[serde(skip)]
+    org: bool,
+    #[serde(skip_serializing_if = "UpdateHookConfig::is_empty")]
+    config: UpdateHookConfig,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    events: Option<Vec<WebhookEventType>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    add_events: Option<Vec<WebhookEventType>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    remove_events: Option<Vec<WebhookEventType>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    active: Option<bool>,
+}
+
+#[derive(Default, serde::Serialize)]
+struct UpdateHookConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_type: Option<ContentType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    secret: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    insecure_ssl: Option<String>,
+}
+
+impl UpdateHookConfig {
+    fn is_empty(&self) -> bool {
+        self.url.is_none()
+            && self.content_type.is_none()
+            && self.secret.is_none()
+            && self.insecure_ssl.is_none()
+    }
+}
+
+impl<'octo> UpdateHookBuilder<'octo> {
+    pub(crate) fn new(crab: &'octo Octocrab, route: String, org: bool) -> Self {
+        Self {
+            crab,
+            route,
+            org,
+            config: UpdateHookConfig::default(),
+            events: None,
+            add_events: None,
+            remove_events: None,
+            active: None,
+        }
+    }
+
+    /// The URL to which the payloads will be delivered.
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.config.url = Some(url.into());
+        self
+    }
+
+    /// The media type used to serialize the payloads.
+    pub fn content_type(mut self, content_type: impl Into<ContentType>) -> Self {
+        self.config.content_type = Some(content_type.into());
+        self
+    }
+
+    /// The secret used to sign the payloads with the `X-Hub-Signature-256`
+    /// header.
+    pub fn secret(mut self, secret: impl Into<String>) -> Self {
+        self.config.secret = Some(secret.into());
+        self
+    }
+
+    /// Whether to skip verifying the SSL certificate of the URL.
+    pub fn insecure_ssl(mut self, insecure_ssl: bool) -> Self {
+        self.config.insecure_ssl = Some(if insecure_ssl { "1" } else { "0" }.to_string());
+        self
+    }
+
+    /// Replaces the events the hook is triggered for.
+    pub fn events(mut self, events: impl Into<Vec<WebhookEventType>>) -> Self {
+        self.events = Some(events.into());
+        self
+    }
+
+    /// Events to add to the hook's events. Only supported by repository
+    /// webhooks.
+    pub fn add_events(mut self, events: impl Into<Vec<WebhookEventType>>) -> Self {
+        self.add_events = Some(events.into());
+        self
+    }
+
+    /// Events to remove from the hook's events. Only supported by repository
+    /// webhooks.
+    pub fn remove_events(mut self, events: impl Into<Vec<WebhookEventType>>) -> Self {
+        self.remove_events = Some(events.into());
+        self
+    }
+
+    /// Whether notifications are sent when the hook is triggered.
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = Some(active);
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Hook> {
+        if self.org && (self.add_events.is_some() || self.remove_events.is_some()) {
+            return Err(crate::Error::Other {
+                source:
+                    "`add_events` and `remove_events` are only supported by repository webhooks"
+                        .into(),
+                backtrace: snafu::Backtrace::capture(),
+            });
+        }
+
+        self.crab
+            .operation("webhooks.update")
+            .patch(&self.route, Some(&self))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::webhook_events::WebhookEventType;
+
+    #[tokio::test]
+    async fn serialize() {
+        let octocrab = crate::Octocrab::default();
+        let repo = octocrab.repos("owner", "repo");
+        let hooks = repo.hooks();
+        let update = hooks
+            .update(12u64)
+            .secret("shh")
+            .add_events(vec![WebhookEventType::PullRequest])
+            .active(false);
+
+        assert_eq!(
+            serde_json::to_value(update).unwrap(),
+            serde_json::json!({
+                "config": { "secret": "shh" },
+                "add_events": ["pull_request"],
+                "active": false,
+            })
+        )
+    }
+
+    #[tokio::test]
+    async fn serialize_without_config() {
+        let octocrab = crate::Octocrab::default();
+        let repo = octocrab.repos("owner", "repo");
+        let hooks = repo.hooks();
+        let update = hooks.update(12u64).active(true);
+
+        assert_eq!(
+            serde_json::to_value(update).unwrap(),
+            serde_json::json!({ "active": true })
+        )
+    }
+}
@@ -0,0 +1,201 @@
+use super::*;
+use crate::models::hooks::Hook;
+use crate::Page;
+
+/// A client to the webhooks of a repository or an organization.
+///
+/// Created with [`RepoHandler::hooks`](crate::repos::RepoHandler::hooks) or
+/// [`OrgHandler::hooks`](crate::orgs::OrgHandler::hooks).
+pub struct WebhooksHandler<'octo> {
+    crab: &'octo Octocrab,
+    /// The route of the hooks collection, e.g. `/repos/{owner}/{repo}/hooks`.
+    route: String,
+    org: bool,
+}
+
+impl<'octo> WebhooksHandler<'octo> {
+    pub(crate) fn for_repo(crab: &'octo Octocrab, route: String) -> Self {
+        Self {
+            crab,
+            route,
+            org: false,
+        }
+    }
+
+    pub(crate) fn for_org(crab: &'octo Octocrab, route: String) -> Self {
+        Self {
+            crab,
+            route,
+            org: true,
+        }
+    }
+
+    /// Lists the webhooks.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let hooks = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .hooks()
+    ///     .list()
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListHooksBuilder<'octo, '_> {
+        ListHooksBuilder::new(self)
+    }
+
+    /// Fetches a single webhook.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let hook = octocrab::instance()
+    ///     .orgs("org")
+    ///     .hooks()
+    ///     .get(12u64)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, hook_id: impl Into<HookId>) -> crate::Result<Hook> {
+        let route = format!("{}/{}", self.route, hook_id.into());
+        self.crab
+            .operation("webhooks.get")
+            .get(route, None::<&()>)
+            .await
+    }
+
+    /// Creates a webhook delivering payloads to `url`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::hooks::ContentType;
+    /// use octocrab::models::webhook_events::WebhookEventType;
+    ///
+    /// let hook = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .hooks()
+    ///     .create("https://example.com/webhook")
+    ///     .content_type(ContentType::Json)
+    ///     .secret("my-secret")
+    ///     .events(vec![WebhookEventType::Push, WebhookEventType::PullRequest])
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create(&self, url: impl Into<String>) -> CreateHookBuilder<'octo> {
+        CreateHookBuilder::new(self.crab, self.route.clone(), url.into())
+    }
+
+    /// Updates a webhook.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let hook = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .hooks()
+    ///     .update(12u64)
+    ///     .active(false)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self, hook_id: impl Into<HookId>) -> UpdateHookBuilder<'octo> {
+        UpdateHookBuilder::new(
+            self.crab,
+            format!("{}/{}", self.route, hook_id.into()),
+            self.org,
+        )
+    }
+
+    /// Deletes a webhook.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .orgs("org")
+    ///     .hooks()
+    ///     .delete(12u64)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(&self, hook_id: impl Into<HookId>) -> crate::Result<()> {
+        let route = format!("{}/{}", self.route, hook_id.into());
+        crate::map_github_error(
+            self.crab
+                .operation("webhooks.delete")
+                ._delete(route, None::<&()>)
+                .await?,
+        )
+        .await
+        .map(drop)
+    }
+
+    /// Triggers a `ping` event to be sent to the webhook.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .hooks()
+    ///     .ping(12u64)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn ping(&self, hook_id: impl Into<HookId>) -> crate::Result<()> {
+        let route = format!("{}/{}/pings", self.route, hook_id.into());
+        crate::map_github_error(
+            self.crab
+                .operation("webhooks.ping")
+                ._post(route, None::<&()>)
+                .await?,
+        )
+        .await
+        .map(drop)
+    }
+}
+
+/// A builder pattern struct for listing webhooks.
+///
+/// Created by [`WebhooksHandler::list`].
+#[derive(serde::Serialize)]
+pub struct ListHooksBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r WebhooksHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListHooksBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r WebhooksHandler<'octo>) -> Self {
+        Self {
+            handler,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Page<Hook>> {
+        self.handler
+            .crab
+            .operation("webhooks.list")
+            .get(&self.handler.route, Some(&self))
+            .await
+    }
+}
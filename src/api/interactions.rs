@@ -0,0 +1,42 @@
+//! Interaction limits, which are shared by users, organizations and
+//! repositories.
+
+use snafu::ResultExt;
+
+use crate::error::SerdeSnafu;
+use crate::models::interactions::{InteractionLimit, SetInteractionLimit};
+use crate::{Octocrab, Result};
+
+/// Fetches the interaction limit at `route`. GitHub answers with an empty
+/// object, or an empty body, when no limit is in place.
+pub(crate) async fn get(crab: &Octocrab, route: String) -> Result<Option<InteractionLimit>> {
+    let limit: Option<serde_json::Value> = crab
+        .operation("interactions.get")
+        .get(route, None::<&()>)
+        .await?;
+    match limit {
+        Some(serde_json::Value::Object(fields)) if fields.is_empty() => Ok(None),
+        Some(limit) => serde_json::from_value(limit).map(Some).context(SerdeSnafu),
+        None => Ok(None),
+    }
+}
+
+pub(crate) async fn set(
+    crab: &Octocrab,
+    route: String,
+    limit: &SetInteractionLimit,
+) -> Result<InteractionLimit> {
+    crab.operation("interactions.set")
+        .put(route, Some(limit))
+        .await
+}
+
+pub(crate) async fn remove(crab: &Octocrab, route: String) -> Result<()> {
+    crate::map_github_error(
+        crab.operation("interactions.remove")
+            ._delete(route, None::<&()>)
+            .await?,
+    )
+    .await
+    .map(drop)
+}
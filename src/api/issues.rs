@@ -50,7 +50,10 @@ impl<'octo> IssueHandler<'octo> {
     pub async fn get(&self, number: u64) -> Result<models::issues::Issue> {
         let route = format!("/{}/issues/{number}", self.repo, number = number,);
 
-        self.crab.get(route, None::<&()>).await
+        self.crab
+            .operation("issues.get")
+            .get(route, None::<&()>)
+            .await
     }
 
     /// Create an issue in the repository.
@@ -152,6 +155,7 @@ impl<'octo> IssueHandler<'octo> {
 
         let response = self
             .crab
+            .operation("issues.lock")
             ._put(
                 uri,
                 reason
@@ -185,7 +189,11 @@ impl<'octo> IssueHandler<'octo> {
             .build()
             .context(HttpSnafu)?;
 
-        let response = self.crab._delete(uri, None::<&()>).await?;
+        let response = self
+            .crab
+            .operation("issues.unlock")
+            ._delete(uri, None::<&()>)
+            .await?;
 
         Ok(response.status() == 204)
     }
@@ -210,6 +218,7 @@ impl<'octo> IssueHandler<'octo> {
         let route = format!("/{}/issues/{issue}/assignees", self.repo, issue = number);
 
         self.crab
+            .operation("issues.add_assignees")
             .post(route, Some(&serde_json::json!({ "assignees": assignees })))
             .await
     }
@@ -230,6 +239,7 @@ impl<'octo> IssueHandler<'octo> {
         let route = format!("/{}/issues/{issue}/assignees", self.repo, issue = number);
 
         self.crab
+            .operation("issues.remove_assignees")
             .delete(route, Some(&serde_json::json!({ "assignees": assignees })))
             .await
     }
@@ -255,7 +265,11 @@ impl<'octo> IssueHandler<'octo> {
             .build()
             .context(HttpSnafu)?;
 
-        let response = self.crab._get(uri).await?;
+        let response = self
+            .crab
+            .operation("issues.check_assignee")
+            ._get(uri)
+            .await?;
         let status = response.status();
 
         if status == 204 {
@@ -321,7 +335,11 @@ impl<'octo, 'r> ListAssigneesBuilder<'octo, 'r> {
     pub async fn send(self) -> Result<crate::Page<models::Author>> {
         let route = format!("/{}/assignees", self.handler.repo);
 
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("issues.list_assignees")
+            .get(route, Some(&self))
+            .await
     }
 }
 
@@ -341,6 +359,7 @@ impl<'octo> IssueHandler<'octo> {
         let route = format!("/{}/issues/{issue}/labels", self.repo, issue = number);
 
         self.crab
+            .operation("issues.add_labels")
             .post(route, Some(&serde_json::json!({ "labels": labels })))
             .await
     }
@@ -367,7 +386,10 @@ impl<'octo> IssueHandler<'octo> {
             name = utf8_percent_encode(label.as_ref(), NON_ALPHANUMERIC),
         );
 
-        self.crab.delete(route, None::<&()>).await
+        self.crab
+            .operation("issues.remove_label")
+            .delete(route, None::<&()>)
+            .await
     }
 
     /// Replaces all labels for an issue.
@@ -388,6 +410,7 @@ impl<'octo> IssueHandler<'octo> {
         let route = format!("/{}/issues/{issue}/labels", self.repo, issue = number);
 
         self.crab
+            .operation("issues.replace_all_labels")
             .put(route, Some(&serde_json::json!({ "labels": labels })))
             .await
     }
@@ -411,6 +434,7 @@ impl<'octo> IssueHandler<'octo> {
         let route = format!("/{}/labels", self.repo);
 
         self.crab
+            .operation("issues.create_label")
             .post(
                 route,
                 Some(&serde_json::json!({
@@ -435,7 +459,10 @@ impl<'octo> IssueHandler<'octo> {
     pub async fn get_label(&self, name: impl AsRef<str>) -> Result<models::Label> {
         let route = format!("/{}/labels/{name}", self.repo, name = name.as_ref(),);
 
-        self.crab.get(route, None::<&()>).await
+        self.crab
+            .operation("issues.get_label")
+            .get(route, None::<&()>)
+            .await
     }
 
     /// Deletes a label in the repository.
@@ -451,7 +478,10 @@ impl<'octo> IssueHandler<'octo> {
     pub async fn delete_label(&self, name: impl AsRef<str>) -> Result<()> {
         let route = format!("/{}/labels/{name}", self.repo, name = name.as_ref(),);
 
-        self.crab._delete(route, None::<&()>).await?;
+        self.crab
+            .operation("issues.delete_label")
+            ._delete(route, None::<&()>)
+            .await?;
         Ok(())
     }
 
@@ -512,6 +542,7 @@ impl<'octo> IssueHandler<'octo> {
         let route = format!("/{}/issues/{issue}/comments", self.repo, issue = number);
 
         self.crab
+            .operation("issues.create_comment")
             .post(route, Some(&serde_json::json!({ "body": body.as_ref() })))
             .await
     }
@@ -529,7 +560,10 @@ impl<'octo> IssueHandler<'octo> {
     pub async fn get_comment(&self, comment_id: CommentId) -> Result<models::issues::Comment> {
         let route = format!("/{}/issues/comments/{comment_id}", self.repo,);
 
-        self.crab.get(route, None::<&()>).await
+        self.crab
+            .operation("issues.get_comment")
+            .get(route, None::<&()>)
+            .await
     }
 
     /// Updates a comment in the issue.
@@ -550,6 +584,7 @@ impl<'octo> IssueHandler<'octo> {
         let route = format!("/{}/issues/comments/{comment_id}", self.repo,);
 
         self.crab
+            .operation("issues.update_comment")
             .post(route, Some(&serde_json::json!({ "body": body.as_ref() })))
             .await
     }
@@ -569,7 +604,11 @@ impl<'octo> IssueHandler<'octo> {
             .build()
             .context(HttpSnafu)?;
 
-        let response = self.crab._delete(uri, None::<&()>).await?;
+        let response = self
+            .crab
+            .operation("issues.delete_comment")
+            ._delete(uri, None::<&()>)
+            .await?;
 
         if response.status() == 204 {
             Ok(())
@@ -612,6 +651,63 @@ impl<'octo> IssueHandler<'octo> {
     pub fn list_issue_comments(&self) -> ListIssueCommentsBuilder<'_, '_> {
         ListIssueCommentsBuilder::new(self)
     }
+
+    /// Fetches and parses the issue templates and issue forms in the
+    /// repository's `.github/ISSUE_TEMPLATE` directory. The `config.yml`
+    /// chooser configuration and templates that fail to parse are skipped,
+    /// and a repository without templates returns an empty list.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let templates = octocrab::instance()
+    ///     .issues("owner", "repo")
+    ///     .list_issue_templates()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "issue-templates")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "issue-templates")))]
+    pub async fn list_issue_templates(&self) -> Result<Vec<models::issues::IssueTemplate>> {
+        const TEMPLATE_DIR: &str = ".github/ISSUE_TEMPLATE";
+
+        let repo = crate::repos::RepoHandler::new(self.crab, self.repo.clone());
+        let files = match repo.get_content().path(TEMPLATE_DIR).send().await {
+            Ok(mut files) => files.take_items(),
+            Err(crate::Error::GitHub { source, .. })
+                if source.status_code == http::StatusCode::NOT_FOUND =>
+            {
+                return Ok(Vec::new())
+            }
+            Err(e) => return Err(e),
+        };
+
+        let mut templates = Vec::new();
+        for file in files {
+            let is_template = [".md", ".yml", ".yaml"]
+                .iter()
+                .any(|extension| file.name.ends_with(extension));
+            if file.r#type != "file" || !is_template || file.name.starts_with("config.") {
+                continue;
+            }
+
+            let mut content = repo.get_content().path(&file.path).send().await?;
+            let contents = content
+                .take_items()
+                .into_iter()
+                .next()
+                .and_then(|content| content.decoded_content())
+                .unwrap_or_default();
+            match models::issues::IssueTemplate::parse(file.path, &contents) {
+                Ok(template) => templates.push(template),
+                Err(_error) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!("skipping malformed issue template: {}", _error);
+                }
+            }
+        }
+
+        Ok(templates)
+    }
 }
 
 #[derive(serde::Serialize)]
@@ -665,7 +761,11 @@ impl<'octo, 'r> ListCommentsBuilder<'octo, 'r> {
             issue = self.issue_number,
         );
 
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("issues.list_comments")
+            .get(route, Some(&self))
+            .await
     }
 }
 
@@ -709,7 +809,7 @@ impl<'octo, 'r> ListIssueCommentsBuilder<'octo, 'r> {
         self
     }
 
-    /// Specify sort results by. Can be either `created`, `updated`,
+    /// Specify sort results by. Can be either `created` or `updated`.
     pub fn sort(mut self, sort: impl Into<params::issues::Sort>) -> Self {
         self.sort = Some(sort.into());
         self
@@ -731,7 +831,11 @@ impl<'octo, 'r> ListIssueCommentsBuilder<'octo, 'r> {
     pub async fn send(self) -> Result<crate::Page<models::issues::Comment>> {
         let route = format!("/{}/issues/comments", self.handler.repo);
 
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("issues.list_issue_comments")
+            .get(route, Some(&self))
+            .await
     }
 }
 
@@ -776,7 +880,11 @@ impl<'octo, 'r> ListTimelineEventsBuilder<'octo, 'r> {
             issue = self.issue_number,
         );
 
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("issues.list_timeline_events")
+            .get(route, Some(&self))
+            .await
     }
 }
 
@@ -880,7 +988,11 @@ impl<'octo, 'r> ListReactionsBuilder<'octo, 'r> {
             issue = self.issue_number,
         );
 
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("issues.list_reactions")
+            .get(route, Some(&self))
+            .await
     }
 }
 
@@ -925,7 +1037,11 @@ impl<'octo, 'r> ListCommentReactionsBuilder<'octo, 'r> {
             comment = self.comment_id,
         );
 
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("issues.list_comment_reactions")
+            .get(route, Some(&self))
+            .await
     }
 }
 
@@ -948,6 +1064,7 @@ impl<'octo> IssueHandler<'octo> {
         let route = format!("/{}/issues/{issue_number}/reactions", self.repo,);
 
         self.crab
+            .operation("issues.create_reaction")
             .post(route, Some(&serde_json::json!({ "content": content })))
             .await
     }
@@ -974,6 +1091,7 @@ impl<'octo> IssueHandler<'octo> {
         );
 
         self.crab
+            .operation("issues.create_comment_reaction")
             .post(route, Some(&serde_json::json!({ "content": content })))
             .await
     }
@@ -1001,7 +1119,10 @@ impl<'octo> IssueHandler<'octo> {
             reaction_id = reaction_id.into(),
         );
 
-        self.crab._delete(route, None::<&()>).await?;
+        self.crab
+            .operation("issues.delete_reaction")
+            ._delete(route, None::<&()>)
+            .await?;
 
         Ok(())
     }
@@ -1028,7 +1149,13 @@ impl<'octo> IssueHandler<'octo> {
             reaction_id = reaction_id.into(),
         );
 
-        crate::map_github_error(self.crab._delete(route, None::<&()>).await?).await?;
+        crate::map_github_error(
+            self.crab
+                .operation("issues.delete_comment_reaction")
+                ._delete(route, None::<&()>)
+                .await?,
+        )
+        .await?;
 
         Ok(())
     }
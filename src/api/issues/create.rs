@@ -13,6 +13,8 @@ pub struct CreateIssueBuilder<'octo, 'r> {
     labels: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     assignees: Option<Vec<String>>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    issue_type: Option<String>,
 }
 
 impl<'octo, 'r> CreateIssueBuilder<'octo, 'r> {
@@ -24,6 +26,7 @@ impl<'octo, 'r> CreateIssueBuilder<'octo, 'r> {
             milestone: None,
             labels: None,
             assignees: None,
+            issue_type: None,
         }
     }
 
@@ -31,7 +34,11 @@ impl<'octo, 'r> CreateIssueBuilder<'octo, 'r> {
     pub async fn send(self) -> crate::Result<models::issues::Issue> {
         let route = format!("/{}/issues", self.handler.repo);
 
-        self.handler.crab.post(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("issues.create")
+            .post(route, Some(&self))
+            .await
     }
 
     /// The contents of the issue.
@@ -62,6 +69,14 @@ impl<'octo, 'r> CreateIssueBuilder<'octo, 'r> {
         self.assignees = assignees.into();
         self
     }
+
+    /// The name of the organization issue type to give this issue. *NOTE:
+    /// Only users with push access can set the type for new issues. The type
+    /// is silently dropped otherwise.*
+    pub fn issue_type<A: Into<String>>(mut self, issue_type: impl Into<Option<A>>) -> Self {
+        self.issue_type = issue_type.into().map(A::into);
+        self
+    }
 }
 
 #[cfg(test)]
@@ -76,7 +91,8 @@ mod tests {
             .body(String::from("testing..."))
             .milestone(3456)
             .labels(vec![String::from("help-wanted")])
-            .assignees(vec![String::from("octocrab"), String::from("ferris")]);
+            .assignees(vec![String::from("octocrab"), String::from("ferris")])
+            .issue_type("Bug");
 
         assert_eq!(
             serde_json::to_value(list).unwrap(),
@@ -86,6 +102,7 @@ mod tests {
                 "milestone": 3456,
                 "labels": ["help-wanted"],
                 "assignees": ["octocrab", "ferris"],
+                "type": "Bug",
             })
         )
     }
@@ -15,7 +15,7 @@ pub struct ListIssuesBuilder<'octo, 'b, 'c, 'd> {
     #[serde(skip_serializing_if = "Option::is_none")]
     mentioned: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(serialize_with = "comma_separated")]
+    #[serde(serialize_with = "crate::api::comma_separated")]
     labels: Option<&'d [String]>,
     #[serde(skip_serializing_if = "Option::is_none")]
     sort: Option<params::issues::Sort>,
@@ -125,17 +125,14 @@ impl<'octo, 'b, 'c, 'd> ListIssuesBuilder<'octo, 'b, 'c, 'd> {
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<crate::Page<models::issues::Issue>> {
         let route = format!("/{}/issues", self.handler.repo);
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("issues.list")
+            .get(route, Some(&self))
+            .await
     }
 }
 
-fn comma_separated<S: serde::Serializer>(
-    labels: &Option<&[String]>,
-    serializer: S,
-) -> Result<S::Ok, S::Error> {
-    serializer.serialize_str(&labels.unwrap().join(","))
-}
-
 #[cfg(test)]
 mod tests {
     #[tokio::test]
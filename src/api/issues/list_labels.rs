@@ -42,7 +42,11 @@ impl<'octo, 'r> ListLabelsForIssueBuilder<'octo, 'r> {
             number = self.number,
         );
 
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("issues.list_labels_for_issue")
+            .get(route, Some(&self))
+            .await
     }
 }
 
@@ -81,6 +85,10 @@ impl<'octo, 'r> ListLabelsForRepoBuilder<'octo, 'r> {
     pub async fn send(self) -> Result<crate::Page<models::Label>> {
         let route = format!("/{}/labels", self.handler.repo);
 
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("issues.list_labels_for_repo")
+            .get(route, Some(&self))
+            .await
     }
 }
@@ -20,6 +20,8 @@ pub struct UpdateIssueBuilder<'octo, 'a, 'b, 'c, 'd, 'e> {
     milestone: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     labels: Option<&'e [String]>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    issue_type: Option<Option<String>>,
 }
 
 impl<'octo, 'a, 'b, 'c, 'd, 'e> UpdateIssueBuilder<'octo, 'a, 'b, 'c, 'd, 'e> {
@@ -34,6 +36,7 @@ impl<'octo, 'a, 'b, 'c, 'd, 'e> UpdateIssueBuilder<'octo, 'a, 'b, 'c, 'd, 'e> {
             state_reason: None,
             milestone: None,
             labels: None,
+            issue_type: None,
         }
     }
 
@@ -82,6 +85,13 @@ impl<'octo, 'a, 'b, 'c, 'd, 'e> UpdateIssueBuilder<'octo, 'a, 'b, 'c, 'd, 'e> {
         self
     }
 
+    /// The name of the organization issue type of the issue. Pass `None` to
+    /// remove the issue's type.
+    pub fn issue_type<A: Into<String>>(mut self, issue_type: impl Into<Option<A>>) -> Self {
+        self.issue_type = Some(issue_type.into().map(A::into));
+        self
+    }
+
     /// Send the actual request.
     pub async fn send(self) -> Result<models::issues::Issue> {
         let route = format!(
@@ -90,7 +100,11 @@ impl<'octo, 'a, 'b, 'c, 'd, 'e> UpdateIssueBuilder<'octo, 'a, 'b, 'c, 'd, 'e> {
             issue = self.number,
         );
 
-        self.handler.crab.patch(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("issues.update")
+            .patch(route, Some(&self))
+            .await
     }
 }
 
@@ -113,7 +127,8 @@ mod tests {
             .state_reason(crate::models::issues::IssueStateReason::Completed)
             .milestone(1234u64)
             .assignees(assignees)
-            .labels(labels);
+            .labels(labels)
+            .issue_type("Feature");
 
         assert_eq!(
             serde_json::to_value(update).unwrap(),
@@ -125,6 +140,21 @@ mod tests {
                 "milestone": 1234,
                 "assignees": ["ferris"],
                 "labels": ["help wanted", "good first issue"],
+                "type": "Feature",
+            })
+        )
+    }
+
+    #[tokio::test]
+    async fn serialize_cleared_issue_type() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.issues("rust-lang", "rust");
+        let update = handler.update(1234).issue_type(None::<String>);
+
+        assert_eq!(
+            serde_json::to_value(update).unwrap(),
+            serde_json::json!({
+                "type": null,
             })
         )
     }
@@ -60,7 +60,10 @@ impl<'octo> MarkdownHandler<'octo> {
         let mut request = Builder::new().uri(uri).method(Method::POST);
         request = request.header(http::header::CONTENT_TYPE, "text/x-markdown");
 
-        let request = self.crab.build_request(request, Some(&text.into()))?;
+        let request = self
+            .crab
+            .operation("markdown.render_raw")
+            .build_request(request, Some(&text.into()))?;
 
         self.crab
             .body_to_string(self.crab.execute(request).await?)
@@ -110,7 +113,13 @@ impl<'octo, 'r, 'text> RenderMarkdownBuilder<'octo, 'r, 'text> {
             .context(HttpSnafu)?;
         self.handler
             .crab
-            .body_to_string(self.handler.crab._post(uri, Some(&self)).await?)
+            .body_to_string(
+                self.handler
+                    .crab
+                    .operation("markdown.render")
+                    ._post(uri, Some(&self))
+                    .await?,
+            )
             .await
     }
 }
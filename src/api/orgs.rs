@@ -1,8 +1,13 @@
 //! The Organization API.
 
+mod billing;
 mod events;
+mod list_blocked_users;
+mod list_issues;
 mod list_members;
 mod list_repos;
+mod pat_requests;
+mod rulesets;
 mod secrets;
 
 use crate::error::HttpSnafu;
@@ -10,9 +15,16 @@ use crate::Octocrab;
 use http::{StatusCode, Uri};
 use snafu::ResultExt;
 
+pub use self::billing::OrgBillingHandler;
 pub use self::events::ListOrgEventsBuilder;
+pub use self::list_blocked_users::ListOrgBlockedUsersBuilder;
+pub use self::list_issues::ListOrgIssuesBuilder;
 pub use self::list_members::ListOrgMembersBuilder;
 pub use self::list_repos::ListReposBuilder;
+pub use self::pat_requests::{
+    ListPatRequestReposBuilder, ListPatRequestsBuilder, OrgPatRequestsHandler,
+};
+pub use self::rulesets::{ListRulesetsBuilder, OrgRulesetsHandler};
 pub use self::secrets::OrgSecretsHandler;
 
 /// A client to GitHub's organization API.
@@ -49,6 +61,7 @@ impl<'octo> OrgHandler<'octo> {
     /// # Ok(())
     /// # }
     /// ```
+    #[deprecated(note = "use `update_membership`, which returns an `OrgMembership`")]
     pub async fn add_or_update_membership(
         &self,
         username: impl AsRef<str>,
@@ -62,7 +75,77 @@ impl<'octo> OrgHandler<'octo> {
 
         let body = role.map(|role| serde_json::json!({ "role": role }));
 
-        self.crab.post(route, body.as_ref()).await
+        self.crab
+            .operation("orgs.add_or_update_membership")
+            .put(route, body.as_ref())
+            .await
+    }
+
+    /// Set the role of a user's organization membership, inviting them to the
+    /// organization if they aren't already a member.
+    ///
+    /// **Note**
+    /// - Only authenticated organization owners can add a member to the
+    ///   organization or update the member's role.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// use octocrab::params::orgs::Role;
+    ///
+    /// let membership = octocrab.orgs("owner").update_membership("ferris", Role::Admin).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_membership(
+        &self,
+        username: impl AsRef<str>,
+        role: crate::params::orgs::Role,
+    ) -> crate::Result<crate::models::orgs::OrgMembership> {
+        let route = format!(
+            "/orgs/{org}/memberships/{username}",
+            org = self.owner,
+            username = username.as_ref(),
+        );
+
+        self.crab
+            .operation("orgs.update_membership")
+            .put(route, Some(&serde_json::json!({ "role": role })))
+            .await
+    }
+
+    /// Remove a user from the organization, or cancel their pending
+    /// invitation.
+    ///
+    /// **Note**
+    /// - The user will no longer have access to the organization's
+    ///   repositories, and will be removed from all teams.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// octocrab.orgs("owner").remove_membership("ferris").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_membership(&self, username: impl AsRef<str>) -> crate::Result<()> {
+        let route = format!(
+            "/orgs/{org}/memberships/{username}",
+            org = self.owner,
+            username = username.as_ref(),
+        );
+
+        let uri = Uri::builder()
+            .path_and_query(route)
+            .build()
+            .context(HttpSnafu)?;
+
+        crate::map_github_error(
+            self.crab
+                .operation("orgs.remove_membership")
+                ._delete(uri, None::<&()>)
+                .await?,
+        )
+        .await
+        .map(drop)
     }
 
     /// Check if a user is, publicly or privately, a member of the organization.
@@ -86,7 +169,11 @@ impl<'octo> OrgHandler<'octo> {
             .build()
             .context(HttpSnafu)?;
 
-        let response = self.crab._get(uri).await?;
+        let response = self
+            .crab
+            .operation("orgs.check_membership")
+            ._get(uri)
+            .await?;
 
         match response.status() {
             StatusCode::NO_CONTENT => Ok(true),
@@ -95,6 +182,99 @@ impl<'octo> OrgHandler<'octo> {
         }
     }
 
+    /// Lists the users blocked by the organization.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let blocked = octocrab::instance()
+    ///     .orgs("org")
+    ///     .list_blocked_users()
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_blocked_users(&self) -> list_blocked_users::ListOrgBlockedUsersBuilder<'octo, '_> {
+        list_blocked_users::ListOrgBlockedUsersBuilder::new(self)
+    }
+
+    /// Checks whether the organization has blocked `username`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let blocking = octocrab::instance()
+    ///     .orgs("org")
+    ///     .is_blocking("spammer")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn is_blocking(&self, username: impl AsRef<str>) -> crate::Result<bool> {
+        let route = format!(
+            "/orgs/{org}/blocks/{username}",
+            org = self.owner,
+            username = username.as_ref(),
+        );
+        let response = self.crab.operation("orgs.is_blocking")._get(route).await?;
+
+        match response.status() {
+            StatusCode::NO_CONTENT => Ok(true),
+            StatusCode::NOT_FOUND => Ok(false),
+            _ => crate::map_github_error(response).await.map(|_| false),
+        }
+    }
+
+    /// Blocks `username` from the organization's repositories.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .orgs("org")
+    ///     .block_user("spammer")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn block_user(&self, username: impl AsRef<str>) -> crate::Result<()> {
+        let route = format!(
+            "/orgs/{org}/blocks/{username}",
+            org = self.owner,
+            username = username.as_ref(),
+        );
+        crate::map_github_error(
+            self.crab
+                .operation("orgs.block_user")
+                ._put(route, None::<&()>)
+                .await?,
+        )
+        .await
+        .map(drop)
+    }
+
+    /// Unblocks `username` from the organization.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .orgs("org")
+    ///     .unblock_user("spammer")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn unblock_user(&self, username: impl AsRef<str>) -> crate::Result<()> {
+        let route = format!(
+            "/orgs/{org}/blocks/{username}",
+            org = self.owner,
+            username = username.as_ref(),
+        );
+        crate::map_github_error(
+            self.crab
+                .operation("orgs.unblock_user")
+                ._delete(route, None::<&()>)
+                .await?,
+        )
+        .await
+        .map(drop)
+    }
+
     /// Get an organization
     ///
     /// To see many of the organization response values, you need to be an
@@ -112,7 +292,10 @@ impl<'octo> OrgHandler<'octo> {
     pub async fn get(&self) -> crate::Result<crate::models::orgs::Organization> {
         let route = format!("/orgs/{org}", org = self.owner);
 
-        self.crab.get(route, None::<&()>).await
+        self.crab
+            .operation("orgs.get")
+            .get(route, None::<&()>)
+            .await
     }
 
     /// List repos for the specified organization.
@@ -141,6 +324,27 @@ impl<'octo> OrgHandler<'octo> {
         list_repos::ListReposBuilder::new(self)
     }
 
+    /// List issues across the organization's repositories that the
+    /// authenticated user can see. By default only issues assigned to the
+    /// user are returned, see [`ListOrgIssuesBuilder::filter`].
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::{issues::IssueFilter, State};
+    ///
+    /// let issues = octocrab::instance()
+    ///     .orgs("owner")
+    ///     .list_issues()
+    ///     .filter(IssueFilter::All)
+    ///     .state(State::Open)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_issues(&self) -> list_issues::ListOrgIssuesBuilder<'_, '_, '_> {
+        list_issues::ListOrgIssuesBuilder::new(self)
+    }
+
     /// List events on this organization.
     ///
     /// Takes an optional etag which allows for efficient polling. Here is a quick example to poll a
@@ -205,7 +409,11 @@ impl<'octo> OrgHandler<'octo> {
         hook: crate::models::hooks::Hook,
     ) -> crate::Result<crate::models::hooks::Hook> {
         let route = format!("/orgs/{org}/hooks", org = self.owner);
-        let res = self.crab.post(route, Some(&hook)).await?;
+        let res = self
+            .crab
+            .operation("orgs.create_hook")
+            .post(route, Some(&hook))
+            .await?;
 
         Ok(res)
     }
@@ -218,7 +426,16 @@ impl<'octo> OrgHandler<'octo> {
     /// # Examples
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::orgs::{MemberFilter, Role};
+    ///
     /// let org_members = octocrab::instance().orgs("org").list_members().send().await?;
+    /// let admins_without_2fa = octocrab::instance()
+    ///     .orgs("org")
+    ///     .list_members()
+    ///     .filter(MemberFilter::TwoFaDisabled)
+    ///     .role(Role::Admin)
+    ///     .send()
+    ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
@@ -226,6 +443,143 @@ impl<'octo> OrgHandler<'octo> {
         list_members::ListOrgMembersBuilder::new(self)
     }
 
+    /// Lists every member of the organization without two-factor
+    /// authentication enabled, fetching all pages.
+    ///
+    /// # Notes
+    /// Only organization owners can filter members by two-factor
+    /// authentication.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let members = octocrab::instance().orgs("org").members_without_2fa().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn members_without_2fa(&self) -> crate::Result<Vec<crate::models::Author>> {
+        let page = self
+            .list_members()
+            .filter(crate::params::orgs::MemberFilter::TwoFaDisabled)
+            .per_page(100)
+            .send()
+            .await?;
+        self.crab.all_pages(page).await
+    }
+
+    /// Lists the issue types configured for the organization.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let issue_types = octocrab::instance().orgs("org").list_issue_types().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_issue_types(&self) -> crate::Result<Vec<crate::models::issues::IssueType>> {
+        let route = format!("/orgs/{org}/issue-types", org = self.owner);
+        self.crab
+            .operation("orgs.list_issue_types")
+            .get(route, None::<&()>)
+            .await
+    }
+
+    /// Fetches the interaction limit in place for the organization's public
+    /// repositories, if any.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let limit = octocrab::instance()
+    ///     .orgs("org")
+    ///     .get_interaction_limits()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_interaction_limits(
+        &self,
+    ) -> crate::Result<Option<crate::models::interactions::InteractionLimit>> {
+        crate::api::interactions::get(
+            self.crab,
+            format!("/orgs/{}/interaction-limits", self.owner),
+        )
+        .await
+    }
+
+    /// Limits who can interact with the organization's public repositories,
+    /// replacing any limit already in place.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::interactions::{
+    ///     InteractionExpiry, InteractionGroup, SetInteractionLimit,
+    /// };
+    ///
+    /// let mut limit = SetInteractionLimit::new(InteractionGroup::CollaboratorsOnly);
+    /// limit.expiry = Some(InteractionExpiry::OneWeek);
+    /// octocrab::instance()
+    ///     .orgs("org")
+    ///     .set_interaction_limits(&limit)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_interaction_limits(
+        &self,
+        limit: &crate::models::interactions::SetInteractionLimit,
+    ) -> crate::Result<crate::models::interactions::InteractionLimit> {
+        crate::api::interactions::set(
+            self.crab,
+            format!("/orgs/{}/interaction-limits", self.owner),
+            limit,
+        )
+        .await
+    }
+
+    /// Removes the interaction limit in place for the organization's public
+    /// repositories.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .orgs("org")
+    ///     .remove_interaction_limits()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_interaction_limits(&self) -> crate::Result<()> {
+        crate::api::interactions::remove(
+            self.crab,
+            format!("/orgs/{}/interaction-limits", self.owner),
+        )
+        .await
+    }
+
+    /// Handle billing and usage of the organization
+    pub fn billing(&self) -> OrgBillingHandler<'_> {
+        OrgBillingHandler::new(self)
+    }
+
+    /// Handle packages published by the organization
+    pub fn packages(&self) -> crate::packages::PackagesHandler<'octo> {
+        crate::packages::PackagesHandler::new(
+            self.crab,
+            crate::packages::PackagesOwner::Route(format!("orgs/{}", self.owner)),
+        )
+    }
+
+    /// Handle webhooks on the organization
+    pub fn hooks(&self) -> crate::hooks::WebhooksHandler<'octo> {
+        crate::hooks::WebhooksHandler::for_org(self.crab, format!("/orgs/{}/hooks", self.owner))
+    }
+
+    /// Handle requests to access the organization with fine-grained personal
+    /// access tokens
+    pub fn pat_requests(&self) -> OrgPatRequestsHandler<'_> {
+        OrgPatRequestsHandler::new(self)
+    }
+
+    /// Handle rulesets applying across the repositories of the organization
+    pub fn rulesets(&self) -> OrgRulesetsHandler<'_> {
+        OrgRulesetsHandler::new(self)
+    }
+
     /// Handle secrets on the organizaton
     /// ```no_run
     /// ```
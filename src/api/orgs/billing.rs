@@ -0,0 +1,94 @@
+use super::OrgHandler;
+use crate::models::orgs::billing::{
+    ActionsBillingUsage, PackagesBillingUsage, SharedStorageBillingUsage,
+};
+use crate::models::orgs::Plan;
+
+/// A client to GitHub's organization billing API. All of its endpoints
+/// require an access token with the `admin:org` scope.
+///
+/// Created with [`OrgHandler::billing`].
+pub struct OrgBillingHandler<'octo> {
+    org: &'octo OrgHandler<'octo>,
+}
+
+impl<'octo> OrgBillingHandler<'octo> {
+    pub(crate) fn new(org: &'octo OrgHandler<'octo>) -> Self {
+        Self { org }
+    }
+
+    /// Fetches the GitHub Actions minutes used in the current billing cycle.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let usage = octocrab::instance()
+    ///     .orgs("org")
+    ///     .billing()
+    ///     .actions_usage()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn actions_usage(&self) -> crate::Result<ActionsBillingUsage> {
+        let route = format!("/orgs/{}/settings/billing/actions", self.org.owner);
+        self.org
+            .crab
+            .operation("orgs.billing.actions_usage")
+            .get(route, None::<&()>)
+            .await
+    }
+
+    /// Fetches the GitHub Packages bandwidth used in the current billing cycle.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let usage = octocrab::instance()
+    ///     .orgs("org")
+    ///     .billing()
+    ///     .packages_usage()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn packages_usage(&self) -> crate::Result<PackagesBillingUsage> {
+        let route = format!("/orgs/{}/settings/billing/packages", self.org.owner);
+        self.org
+            .crab
+            .operation("orgs.billing.packages_usage")
+            .get(route, None::<&()>)
+            .await
+    }
+
+    /// Fetches the estimated storage used by Actions artifacts and Packages
+    /// in the current billing cycle.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let usage = octocrab::instance()
+    ///     .orgs("org")
+    ///     .billing()
+    ///     .shared_storage_usage()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn shared_storage_usage(&self) -> crate::Result<SharedStorageBillingUsage> {
+        let route = format!("/orgs/{}/settings/billing/shared-storage", self.org.owner);
+        self.org
+            .crab
+            .operation("orgs.billing.shared_storage_usage")
+            .get(route, None::<&()>)
+            .await
+    }
+
+    /// Fetches the organization's plan, including its total and filled seats.
+    /// Returns `None` if the plan isn't visible to the authenticated user.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// if let Some(plan) = octocrab::instance().orgs("org").billing().seats().await? {
+    ///     println!("{:?} of {:?} seats filled", plan.filled_seats, plan.seats);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn seats(&self) -> crate::Result<Option<Plan>> {
+        self.org.get().await.map(|org| org.plan)
+    }
+}
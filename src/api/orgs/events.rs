@@ -75,17 +75,27 @@ impl<'octo, 'handler> ListOrgEventsBuilder<'octo, 'handler> {
             request = request.header(key, value);
         }
 
-        let request = self.handler.crab.build_request(request, None::<&()>)?;
+        let request = self
+            .handler
+            .crab
+            .operation("orgs.events")
+            .build_request(request, None::<&()>)?;
         let response = self.handler.crab.execute(request).await?;
         let etag = EntityTag::extract_from_response(&response);
+        let poll_interval = crate::etag::extract_poll_interval(&response);
         if response.status() == StatusCode::NOT_MODIFIED {
-            Ok(Etagged { etag, value: None })
+            Ok(Etagged {
+                etag,
+                value: None,
+                poll_interval,
+            })
         } else {
             <Page<events::Event>>::from_response(crate::map_github_error(response).await?)
                 .await
                 .map(|page| Etagged {
                     etag,
                     value: Some(page),
+                    poll_interval,
                 })
         }
     }
@@ -0,0 +1,46 @@
+use super::*;
+
+/// A builder pattern struct for listing the users blocked by an organization.
+///
+/// Created by [`OrgHandler::list_blocked_users`].
+#[derive(serde::Serialize)]
+pub struct ListOrgBlockedUsersBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r OrgHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListOrgBlockedUsersBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r OrgHandler<'octo>) -> Self {
+        Self {
+            handler,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<crate::Page<crate::models::SimpleUser>> {
+        let route = format!("/orgs/{org}/blocks", org = self.handler.owner);
+        self.handler
+            .crab
+            .operation("orgs.list_blocked_users")
+            .get(route, Some(&self))
+            .await
+    }
+}
@@ -0,0 +1,133 @@
+use super::*;
+
+#[derive(serde::Serialize)]
+pub struct ListOrgIssuesBuilder<'octo, 'b, 'd> {
+    #[serde(skip)]
+    handler: &'b OrgHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter: Option<crate::params::issues::IssueFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<crate::params::State>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "crate::api::comma_separated")]
+    labels: Option<&'d [String]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort: Option<crate::params::issues::Sort>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    direction: Option<crate::params::Direction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'b, 'd> ListOrgIssuesBuilder<'octo, 'b, 'd> {
+    pub(crate) fn new(handler: &'b OrgHandler<'octo>) -> Self {
+        Self {
+            handler,
+            filter: None,
+            state: None,
+            labels: None,
+            sort: None,
+            direction: None,
+            since: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Which issues to return, based on how the authenticated user is
+    /// involved with them. Default: assigned.
+    pub fn filter(mut self, filter: impl Into<crate::params::issues::IssueFilter>) -> Self {
+        self.filter = Some(filter.into());
+        self
+    }
+
+    /// Filter issues by `state`.
+    pub fn state(mut self, state: crate::params::State) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Filter issues by label.
+    pub fn labels(mut self, labels: &'d (impl AsRef<[String]> + ?Sized)) -> Self {
+        self.labels = Some(labels.as_ref());
+        self
+    }
+
+    /// What to sort results by. Can be either `created`, `updated` or
+    /// `comments`.
+    pub fn sort(mut self, sort: impl Into<crate::params::issues::Sort>) -> Self {
+        self.sort = Some(sort.into());
+        self
+    }
+
+    /// The direction of the sort. Can be either ascending or descending.
+    /// Default: descending.
+    pub fn direction(mut self, direction: impl Into<crate::params::Direction>) -> Self {
+        self.direction = Some(direction.into());
+        self
+    }
+
+    /// Only return issues updated after the given timestamp.
+    pub fn since(mut self, since: impl Into<chrono::DateTime<chrono::Utc>>) -> Self {
+        self.since = Some(since.into());
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<crate::Page<crate::models::issues::Issue>> {
+        let route = format!("/orgs/{owner}/issues", owner = self.handler.owner);
+        self.handler
+            .crab
+            .operation("orgs.list_issues")
+            .get(route, Some(&self))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[tokio::test]
+    async fn serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.orgs("rust-lang");
+        let labels = vec!["bug".to_string(), "help wanted".to_string()];
+        let list = handler
+            .list_issues()
+            .filter(crate::params::issues::IssueFilter::Mentioned)
+            .state(crate::params::State::Open)
+            .labels(&labels)
+            .sort(crate::params::issues::Sort::Updated)
+            .direction(crate::params::Direction::Ascending)
+            .per_page(100)
+            .page(1u8);
+
+        assert_eq!(
+            serde_json::to_value(list).unwrap(),
+            serde_json::json!({
+                "filter": "mentioned",
+                "state": "open",
+                "labels": "bug,help wanted",
+                "sort": "updated",
+                "direction": "asc",
+                "per_page": 100,
+                "page": 1,
+            })
+        )
+    }
+}
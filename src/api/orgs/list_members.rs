@@ -5,6 +5,10 @@ pub struct ListOrgMembersBuilder<'octo, 'r> {
     #[serde(skip)]
     handler: &'r OrgHandler<'octo>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    filter: Option<crate::params::orgs::MemberFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    role: Option<crate::params::orgs::Role>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     per_page: Option<u8>,
     #[serde(skip_serializing_if = "Option::is_none")]
     page: Option<u32>,
@@ -14,11 +18,26 @@ impl<'octo, 'r> ListOrgMembersBuilder<'octo, 'r> {
     pub fn new(handler: &'r OrgHandler<'octo>) -> Self {
         Self {
             handler,
+            filter: None,
+            role: None,
             per_page: None,
             page: None,
         }
     }
 
+    /// Only list members matching `filter`, e.g. those without two-factor
+    /// authentication.
+    pub fn filter(mut self, filter: impl Into<crate::params::orgs::MemberFilter>) -> Self {
+        self.filter = Some(filter.into());
+        self
+    }
+
+    /// Only list members with `role`.
+    pub fn role(mut self, role: impl Into<crate::params::orgs::Role>) -> Self {
+        self.role = Some(role.into());
+        self
+    }
+
     /// Results per page (max 100).
     pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
         self.per_page = Some(per_page.into());
@@ -33,6 +52,10 @@ impl<'octo, 'r> ListOrgMembersBuilder<'octo, 'r> {
 
     pub async fn send(self) -> crate::Result<crate::Page<crate::models::Author>> {
         let route = format!("/orgs/{org}/members", org = self.handler.owner);
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("orgs.list_members")
+            .get(route, Some(&self))
+            .await
     }
 }
@@ -63,7 +63,11 @@ impl<'octo, 'b> ListReposBuilder<'octo, 'b> {
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<crate::Page<crate::models::Repository>> {
         let route = format!("/orgs/{owner}/repos", owner = self.handler.owner);
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("orgs.list_repos")
+            .get(route, Some(&self))
+            .await
     }
 }
 
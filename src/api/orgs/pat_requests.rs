@@ -0,0 +1,228 @@
+use serde::Serialize;
+
+use super::OrgHandler;
+use crate::models::orgs::pat_requests::{PatRequest, PatRequestAction};
+use crate::models::{PatRequestId, Repository};
+use crate::Page;
+
+/// A client to GitHub's API for requests to access an organization with a
+/// fine-grained personal access token. Only GitHub Apps can use it, with an
+/// installation token having the `organization_personal_access_token_requests`
+/// permission.
+///
+/// Created with [`OrgHandler::pat_requests`].
+pub struct OrgPatRequestsHandler<'octo> {
+    org: &'octo OrgHandler<'octo>,
+}
+
+impl<'octo> OrgPatRequestsHandler<'octo> {
+    pub(crate) fn new(org: &'octo OrgHandler<'octo>) -> Self {
+        Self { org }
+    }
+
+    /// Lists the pending personal access token requests.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let requests = octocrab::instance()
+    ///     .orgs("org")
+    ///     .pat_requests()
+    ///     .list()
+    ///     .owner("octocat")
+    ///     .per_page(50)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListPatRequestsBuilder<'_, '_> {
+        ListPatRequestsBuilder::new(self)
+    }
+
+    /// Approves or denies a pending personal access token request, optionally
+    /// giving a reason.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::orgs::pat_requests::PatRequestAction;
+    ///
+    /// octocrab::instance()
+    ///     .orgs("org")
+    ///     .pat_requests()
+    ///     .review(42, PatRequestAction::Deny, Some("Use a GitHub App instead"))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn review(
+        &self,
+        request_id: impl Into<PatRequestId>,
+        action: PatRequestAction,
+        reason: Option<&str>,
+    ) -> crate::Result<()> {
+        let request_id = request_id.into();
+        let route = format!(
+            "/orgs/{}/personal-access-token-requests/{request_id}",
+            self.org.owner
+        );
+        let body = serde_json::json!({ "action": action, "reason": reason });
+        crate::map_github_error(
+            self.org
+                .crab
+                .operation("orgs.pat_requests.review")
+                ._post(route, Some(&body))
+                .await?,
+        )
+        .await
+        .map(drop)
+    }
+
+    /// Lists the repositories a personal access token request asks to access.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let repos = octocrab::instance()
+    ///     .orgs("org")
+    ///     .pat_requests()
+    ///     .list_repos_for_request(42)
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_repos_for_request(
+        &self,
+        request_id: impl Into<PatRequestId>,
+    ) -> ListPatRequestReposBuilder<'_, '_> {
+        ListPatRequestReposBuilder::new(self, request_id.into())
+    }
+}
+
+#[derive(Serialize)]
+pub struct ListPatRequestsBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r OrgPatRequestsHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    repository: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    permission: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    direction: Option<crate::params::Direction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListPatRequestsBuilder<'octo, 'r> {
+    fn new(handler: &'r OrgPatRequestsHandler<'octo>) -> Self {
+        Self {
+            handler,
+            owner: None,
+            repository: None,
+            permission: None,
+            direction: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Only list requests made by the user with this login.
+    pub fn owner(mut self, owner: impl Into<String>) -> Self {
+        self.owner = Some(owner.into());
+        self
+    }
+
+    /// Only list requests asking to access the repository with this name.
+    pub fn repository(mut self, repository: impl Into<String>) -> Self {
+        self.repository = Some(repository.into());
+        self
+    }
+
+    /// Only list requests asking for this permission, e.g. `contents`.
+    pub fn permission(mut self, permission: impl Into<String>) -> Self {
+        self.permission = Some(permission.into());
+        self
+    }
+
+    /// The direction of the sort by creation date. Default: descending.
+    pub fn direction(mut self, direction: impl Into<crate::params::Direction>) -> Self {
+        self.direction = Some(direction.into());
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Page<PatRequest>> {
+        let route = format!(
+            "/orgs/{}/personal-access-token-requests",
+            self.handler.org.owner
+        );
+        self.handler
+            .org
+            .crab
+            .operation("orgs.pat_requests.list")
+            .get(route, Some(&self))
+            .await
+    }
+}
+
+#[derive(Serialize)]
+pub struct ListPatRequestReposBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r OrgPatRequestsHandler<'octo>,
+    #[serde(skip)]
+    request_id: PatRequestId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListPatRequestReposBuilder<'octo, 'r> {
+    fn new(handler: &'r OrgPatRequestsHandler<'octo>, request_id: PatRequestId) -> Self {
+        Self {
+            handler,
+            request_id,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Page<Repository>> {
+        let route = format!(
+            "/orgs/{}/personal-access-token-requests/{}/repositories",
+            self.handler.org.owner, self.request_id
+        );
+        self.handler
+            .org
+            .crab
+            .operation("orgs.pat_requests.list_repos_for_request")
+            .get(route, Some(&self))
+            .await
+    }
+}
@@ -0,0 +1,189 @@
+use super::OrgHandler;
+use crate::models::orgs::rulesets::{OrgRulesetRequest, Ruleset};
+use crate::models::RulesetId;
+use crate::Page;
+
+/// A client to GitHub's organization rulesets API.
+///
+/// Created with [`OrgHandler::rulesets`].
+pub struct OrgRulesetsHandler<'octo> {
+    org: &'octo OrgHandler<'octo>,
+}
+
+impl<'octo> OrgRulesetsHandler<'octo> {
+    pub(crate) fn new(org: &'octo OrgHandler<'octo>) -> Self {
+        Self { org }
+    }
+
+    /// Lists the rulesets of the organization.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let rulesets = octocrab::instance()
+    ///     .orgs("org")
+    ///     .rulesets()
+    ///     .list()
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListRulesetsBuilder<'_, '_> {
+        ListRulesetsBuilder::new(self)
+    }
+
+    /// Fetches a single ruleset, including its conditions and rules.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let ruleset = octocrab::instance()
+    ///     .orgs("org")
+    ///     .rulesets()
+    ///     .get(42u64)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, ruleset_id: impl Into<RulesetId>) -> crate::Result<Ruleset> {
+        let route = format!("/orgs/{}/rulesets/{}", self.org.owner, ruleset_id.into());
+        self.org
+            .crab
+            .operation("orgs.rulesets.get")
+            .get(route, None::<&()>)
+            .await
+    }
+
+    /// Creates a ruleset.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::orgs::rulesets::{
+    ///     OrgRulesetRequest, RepositoryNameCondition, RulesetConditions, RulesetEnforcement,
+    ///     RulesetRule, RulesetTarget,
+    /// };
+    ///
+    /// let mut request = OrgRulesetRequest::new("no force pushes", RulesetEnforcement::Active);
+    /// request.target = Some(RulesetTarget::Branch);
+    /// request.conditions = Some(RulesetConditions {
+    ///     repository_name: Some(RepositoryNameCondition {
+    ///         include: vec!["service-*".to_string()],
+    ///         ..Default::default()
+    ///     }),
+    ///     ..Default::default()
+    /// });
+    /// request.rules = Some(vec![RulesetRule {
+    ///     r#type: "non_fast_forward".to_string(),
+    ///     parameters: None,
+    /// }]);
+    ///
+    /// let ruleset = octocrab::instance()
+    ///     .orgs("org")
+    ///     .rulesets()
+    ///     .create(&request)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create(&self, ruleset: &OrgRulesetRequest) -> crate::Result<Ruleset> {
+        let route = format!("/orgs/{}/rulesets", self.org.owner);
+        self.org
+            .crab
+            .operation("orgs.rulesets.create")
+            .post(route, Some(ruleset))
+            .await
+    }
+
+    /// Replaces the settings of a ruleset.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::orgs::rulesets::{OrgRulesetRequest, RulesetEnforcement};
+    ///
+    /// let ruleset = octocrab::instance()
+    ///     .orgs("org")
+    ///     .rulesets()
+    ///     .update(
+    ///         42u64,
+    ///         &OrgRulesetRequest::new("no force pushes", RulesetEnforcement::Disabled),
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update(
+        &self,
+        ruleset_id: impl Into<RulesetId>,
+        ruleset: &OrgRulesetRequest,
+    ) -> crate::Result<Ruleset> {
+        let route = format!("/orgs/{}/rulesets/{}", self.org.owner, ruleset_id.into());
+        self.org
+            .crab
+            .operation("orgs.rulesets.update")
+            .put(route, Some(ruleset))
+            .await
+    }
+
+    /// Deletes a ruleset.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .orgs("org")
+    ///     .rulesets()
+    ///     .delete(42u64)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(&self, ruleset_id: impl Into<RulesetId>) -> crate::Result<()> {
+        let route = format!("/orgs/{}/rulesets/{}", self.org.owner, ruleset_id.into());
+        crate::map_github_error(
+            self.org
+                .crab
+                .operation("orgs.rulesets.delete")
+                ._delete(route, None::<&()>)
+                .await?,
+        )
+        .await
+        .map(drop)
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct ListRulesetsBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r OrgRulesetsHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListRulesetsBuilder<'octo, 'r> {
+    fn new(handler: &'r OrgRulesetsHandler<'octo>) -> Self {
+        Self {
+            handler,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Page<Ruleset>> {
+        let route = format!("/orgs/{}/rulesets", self.handler.org.owner);
+        self.handler
+            .org
+            .crab
+            .operation("orgs.rulesets.list")
+            .get(route, Some(&self))
+            .await
+    }
+}
@@ -35,7 +35,11 @@ impl<'octo> OrgSecretsHandler<'octo> {
         &self,
     ) -> crate::Result<crate::models::orgs::secrets::OrganizationSecrets> {
         let route = format!("/orgs/{org}/actions/secrets", org = self.owner());
-        self.org.crab.get(route, None::<&()>).await
+        self.org
+            .crab
+            .operation("orgs.secrets.get_secrets")
+            .get(route, None::<&()>)
+            .await
     }
 
     // Gets your public key, which you need to encrypt secrets. You need to encrypt a secret before you can create or update secrets.
@@ -51,7 +55,11 @@ impl<'octo> OrgSecretsHandler<'octo> {
     /// # }
     pub async fn get_public_key(&self) -> crate::Result<crate::models::PublicKey> {
         let route = format!("/orgs/{org}/actions/secrets/public-key", org = self.owner());
-        self.org.crab.get(route, None::<&()>).await
+        self.org
+            .crab
+            .operation("orgs.secrets.get_public_key")
+            .get(route, None::<&()>)
+            .await
     }
 
     /// Gets a specific secret from the organization without revealing its encrypted values.
@@ -74,7 +82,11 @@ impl<'octo> OrgSecretsHandler<'octo> {
             org = self.owner(),
             secret_name = secret_name.as_ref()
         );
-        self.org.crab.get(route, None::<&()>).await
+        self.org
+            .crab
+            .operation("orgs.secrets.get_secret")
+            .get(route, None::<&()>)
+            .await
     }
 
     /// Creates or updates an organization secret with an encrypted value.
@@ -116,7 +128,12 @@ impl<'octo> OrgSecretsHandler<'octo> {
         );
 
         let resp = {
-            let resp = self.org.crab._put(route, Some(secret)).await?;
+            let resp = self
+                .org
+                .crab
+                .operation("orgs.secrets.create_or_update_secret")
+                ._put(route, Some(secret))
+                .await?;
             crate::map_github_error(resp).await?
         };
 
@@ -154,7 +171,12 @@ impl<'octo> OrgSecretsHandler<'octo> {
             secret_name = secret_name.as_ref()
         );
 
-        let resp = self.org.crab._delete(route, None::<&()>).await?;
+        let resp = self
+            .org
+            .crab
+            .operation("orgs.secrets.delete_secret")
+            ._delete(route, None::<&()>)
+            .await?;
         crate::map_github_error(resp).await?;
         Ok(())
     }
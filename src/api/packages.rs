@@ -0,0 +1,148 @@
+//! The packages API.
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+use crate::models::packages::{PackageType, PackageVersion};
+use crate::models::{PackageVersionId, UserId};
+use crate::{Octocrab, Result};
+
+/// Whose packages a [`PackagesHandler`] works with.
+pub(crate) enum PackagesOwner {
+    /// The route of an organization or user, e.g. `orgs/rust-lang`.
+    Route(String),
+    /// A user known by ID. There is no `/user/{id}/packages` route, so their
+    /// login is looked up first.
+    UserId(UserId),
+}
+
+/// A client to GitHub's packages API, for the packages of either an
+/// organization or a user.
+///
+/// Created with [`OrgHandler::packages`] or [`UserHandler::packages`].
+///
+/// [`OrgHandler::packages`]: crate::orgs::OrgHandler::packages
+/// [`UserHandler::packages`]: crate::api::users::UserHandler::packages
+pub struct PackagesHandler<'octo> {
+    crab: &'octo Octocrab,
+    owner: PackagesOwner,
+}
+
+impl<'octo> PackagesHandler<'octo> {
+    pub(crate) fn new(crab: &'octo Octocrab, owner: PackagesOwner) -> Self {
+        Self { crab, owner }
+    }
+
+    async fn version_route(
+        &self,
+        package_type: PackageType,
+        package_name: &str,
+        version_id: PackageVersionId,
+    ) -> Result<String> {
+        let owner = match &self.owner {
+            PackagesOwner::Route(route) => route.clone(),
+            PackagesOwner::UserId(id) => {
+                let profile = self.crab.users_by_id(*id).profile().await?;
+                format!("users/{}", profile.login)
+            }
+        };
+        Ok(format!(
+            "/{owner}/packages/{package_type}/{package_name}/versions/{version_id}",
+            package_name = utf8_percent_encode(package_name, NON_ALPHANUMERIC),
+        ))
+    }
+
+    /// Gets a specific version of a package.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::packages::PackageType;
+    ///
+    /// let version = octocrab::instance()
+    ///     .orgs("org")
+    ///     .packages()
+    ///     .get_package_version(PackageType::Container, "app", 42u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_package_version(
+        &self,
+        package_type: PackageType,
+        package_name: impl AsRef<str>,
+        version_id: PackageVersionId,
+    ) -> Result<PackageVersion> {
+        let route = self
+            .version_route(package_type, package_name.as_ref(), version_id)
+            .await?;
+        self.crab
+            .operation("packages.get_package_version")
+            .get(route, None::<&()>)
+            .await
+    }
+
+    /// Deletes a specific version of a package. The package's last version
+    /// can't be deleted this way, delete the package itself instead.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::packages::PackageType;
+    ///
+    /// octocrab::instance()
+    ///     .orgs("org")
+    ///     .packages()
+    ///     .delete_package_version(PackageType::Container, "app", 42u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_package_version(
+        &self,
+        package_type: PackageType,
+        package_name: impl AsRef<str>,
+        version_id: PackageVersionId,
+    ) -> Result<()> {
+        let route = self
+            .version_route(package_type, package_name.as_ref(), version_id)
+            .await?;
+        crate::map_github_error(
+            self.crab
+                .operation("packages.delete_package_version")
+                ._delete(route, None::<&()>)
+                .await?,
+        )
+        .await
+        .map(drop)
+    }
+
+    /// Restores a deleted version of a package, which is possible within 30
+    /// days of its deletion if its name and version are still available.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::packages::PackageType;
+    ///
+    /// octocrab::instance()
+    ///     .users("user")
+    ///     .packages()
+    ///     .restore_package_version(PackageType::Npm, "left-pad", 42u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn restore_package_version(
+        &self,
+        package_type: PackageType,
+        package_name: impl AsRef<str>,
+        version_id: PackageVersionId,
+    ) -> Result<()> {
+        let route = format!(
+            "{}/restore",
+            self.version_route(package_type, package_name.as_ref(), version_id)
+                .await?
+        );
+        crate::map_github_error(
+            self.crab
+                .operation("packages.restore_package_version")
+                ._post(route, None::<&()>)
+                .await?,
+        )
+        .await
+        .map(drop)
+    }
+}
@@ -24,7 +24,11 @@ impl<'octo, 'r> GetProjectBuilder<'octo, 'r> {
 
     pub async fn send(self) -> crate::Result<crate::models::Project> {
         let route = format!("/projects/{project_id}", project_id = self.project_id);
-        self.handler.crab.get(route, None::<&()>).await
+        self.handler
+            .crab
+            .operation("projects.get_project")
+            .get(route, None::<&()>)
+            .await
     }
 }
 
@@ -71,7 +75,11 @@ where
     pub async fn send(self) -> crate::Result<crate::models::Project> {
         let route = format!("/projects/{project_id}", project_id = self.project_id);
 
-        self.handler.crab.patch(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("projects.update_project")
+            .patch(route, Some(&self))
+            .await
     }
 }
 
@@ -96,9 +104,15 @@ impl<'octo, 'r> DeleteProjectBuilder<'octo, 'r> {
     pub async fn send(self) -> crate::Result<()> {
         let route = format!("/projects/{project_id}", project_id = self.project_id);
 
-        crate::map_github_error(self.handler.crab._delete(route, None::<&()>).await?)
-            .await
-            .map(drop)
+        crate::map_github_error(
+            self.handler
+                .crab
+                .operation("projects.delete_project")
+                ._delete(route, None::<&()>)
+                .await?,
+        )
+        .await
+        .map(drop)
     }
 }
 
@@ -131,7 +145,11 @@ impl<'octo, 'r> CreateUserProjectBuilder<'octo, 'r> {
     pub async fn send(self) -> crate::Result<crate::models::Project> {
         let route = "/user/projects";
 
-        self.handler.crab.post(route, Some(&self.body)).await
+        self.handler
+            .crab
+            .operation("projects.create_user_project")
+            .post(route, Some(&self.body))
+            .await
     }
 }
 
@@ -173,7 +191,11 @@ impl<'octo, 'r> ListUserProjectsBuilder<'octo, 'r> {
     pub async fn send(self) -> crate::Result<crate::Page<crate::models::Project>> {
         let route = format!("/users/{username}/projects", username = self.username);
 
-        self.handler.crab.get(route, None::<&()>).await
+        self.handler
+            .crab
+            .operation("projects.list_user_projects")
+            .get(route, None::<&()>)
+            .await
     }
 }
 
@@ -227,7 +249,11 @@ impl<'octo, 'r> ListOrgProjectsBuilder<'octo, 'r> {
 
     pub async fn send(self) -> crate::Result<crate::Page<crate::models::Project>> {
         let route = format!("/orgs/{org}/projects", org = self.org);
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("projects.list_organization_projects")
+            .get(route, Some(&self))
+            .await
     }
 }
 
@@ -262,7 +288,11 @@ impl<'octo, 'r> CreateOrgProjectsBuilder<'octo, 'r> {
 
     pub async fn send(self) -> crate::Result<crate::models::Project> {
         let route = format!("/orgs/{org}/projects", org = self.org);
-        self.handler.crab.post(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("projects.create_organization_project")
+            .post(route, Some(&self))
+            .await
     }
 }
 
@@ -311,7 +341,11 @@ impl<'octo, 'r> ListRepositoryProjectsBuilder<'octo, 'r> {
             owner = self.owner,
             repo = self.repo
         );
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("projects.list_repository_projects")
+            .get(route, Some(&self))
+            .await
     }
 }
 
@@ -407,6 +441,10 @@ impl<'octo, 'r> CreateRepositoryProjectsBuilder<'octo, 'r, Named> {
             owner = self.owner,
             repo = self.repo
         );
-        self.handler.crab.post(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("projects.create_repository_project")
+            .post(route, Some(&self))
+            .await
     }
 }
@@ -13,20 +13,32 @@ use crate::pulls::specific_pr::{SpecificPullRequestBuilder, SpecificPullRequestC
 use crate::{Octocrab, Page};
 
 pub use self::{
+    as_issue::PullRequestIssueHandler, comment::CreateReviewCommentBuilder,
     create::CreatePullRequestBuilder, list::ListPullRequestsBuilder,
     update::UpdatePullRequestBuilder,
 };
 
+#[cfg(feature = "tokio")]
+pub use self::mergeable::PollMergeableBuilder;
+
+mod as_issue;
+mod codeowners;
 mod comment;
 mod create;
 mod list;
 mod merge;
+#[cfg(feature = "tokio")]
+mod mergeable;
 mod specific_pr;
 mod update;
 
 /// A client to GitHub's pull request API.
 ///
 /// Created with [`Octocrab::pulls`].
+///
+/// Pull requests are also issues, so labels, assignees and conversation
+/// comments are managed with the issue endpoints using the pull request's
+/// number, see [`PullRequestHandler::as_issue`].
 pub struct PullRequestHandler<'octo> {
     crab: &'octo Octocrab,
     owner: String,
@@ -79,7 +91,7 @@ impl<'octo> PullRequestHandler<'octo> {
             .path_and_query(route)
             .build()
             .context(crate::error::HttpSnafu)?;
-        let response = self.crab._get(uri).await?;
+        let response = self.crab.operation("pulls.is_merged")._get(uri).await?;
 
         Ok(response.status() == 204)
     }
@@ -104,11 +116,37 @@ impl<'octo> PullRequestHandler<'octo> {
             .path_and_query(route)
             .build()
             .context(crate::error::HttpSnafu)?;
-        let response = self.crab._put(uri, None::<&()>).await?;
+        let response = self
+            .crab
+            .operation("pulls.update_branch")
+            ._put(uri, None::<&()>)
+            .await?;
 
         Ok(response.status() == 202)
     }
 
+    /// Operates on pull request `pr` through the issues API, e.g. to manage
+    /// its labels, assignees or conversation comments.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .as_issue(101)
+    ///     .add_labels(&["needs-review".to_string()])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn as_issue(&self, pr: u64) -> PullRequestIssueHandler<'octo> {
+        PullRequestIssueHandler::new(
+            crate::issues::IssueHandler::new(
+                self.crab,
+                crate::repos::RepoRef::ByOwnerAndName(self.owner.clone(), self.repo.clone()),
+            ),
+            pr,
+        )
+    }
+
     /// Get's a given pull request with by its `pr` number.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
@@ -124,7 +162,31 @@ impl<'octo> PullRequestHandler<'octo> {
             pr = pr
         );
 
-        self.http_get(route, None::<&()>).await
+        self.http_get("pulls.get", route, None::<&()>).await
+    }
+
+    /// Waits for GitHub to compute whether the pull request `pr` can be
+    /// merged. `mergeable` is computed in the background and is `null` for a
+    /// while after a push, so this re-fetches the pull request until it's
+    /// known, by default up to five times a second apart.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let state = octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .mergeable(101)
+    ///     .max_attempts(10)
+    ///     .send()
+    ///     .await?;
+    ///
+    /// if state.mergeable == Some(true) {
+    ///     println!("ready to merge: {:?}", state.mergeable_state);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "tokio")]
+    pub fn mergeable(&self, pr: u64) -> mergeable::PollMergeableBuilder<'octo, '_> {
+        mergeable::PollMergeableBuilder::new(self, pr)
     }
 
     /// Get's a given pull request's `diff`.
@@ -150,7 +212,10 @@ impl<'octo> PullRequestHandler<'octo> {
             .method(Method::GET)
             .uri(uri)
             .header(http::header::ACCEPT, crate::format_media_type("diff"));
-        let request = self.crab.build_request(request, None::<&()>)?;
+        let request = self
+            .crab
+            .operation("pulls.get_diff")
+            .build_request(request, None::<&()>)?;
         let response = crate::map_github_error(self.crab.execute(request).await?).await?;
         self.crab.body_to_string(response).await
     }
@@ -178,7 +243,10 @@ impl<'octo> PullRequestHandler<'octo> {
             .method("GET")
             .uri(uri)
             .header(http::header::ACCEPT, crate::format_media_type("patch"));
-        let request = self.crab.build_request(request, None::<&()>)?;
+        let request = self
+            .crab
+            .operation("pulls.get_patch")
+            .build_request(request, None::<&()>)?;
         let response = crate::map_github_error(self.crab.execute(request).await?).await?;
 
         self.crab.body_to_string(response).await
@@ -277,6 +345,29 @@ impl<'octo> PullRequestHandler<'octo> {
         ListReviewsBuilder::new(self, pr_number)
     }
 
+    /// Dismisses a review on a pull request, e.g. one that has gone stale,
+    /// leaving `message` as the reason. This is a shorthand for
+    /// [`PullRequestHandler::pr_review_actions`] followed by `dismiss`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let review = octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .dismiss_review(21, 42, "New commits were pushed")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn dismiss_review(
+        &self,
+        pr_number: u64,
+        review_id: u64,
+        message: impl Into<String>,
+    ) -> crate::Result<crate::models::pulls::Review> {
+        self.pr_review_actions(pr_number, review_id)
+            .dismiss(message)
+            .await
+    }
+
     /// Request a review from users or teams.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
@@ -302,7 +393,10 @@ impl<'octo> PullRequestHandler<'octo> {
         map.insert("reviewers".to_string(), reviewers.into().into());
         map.insert("team_reviewers".to_string(), team_reviewers.into().into());
 
-        self.crab.post(route, Some(&map)).await
+        self.crab
+            .operation("pulls.request_reviews")
+            .post(route, Some(&map))
+            .await
     }
 
     /// Remove a requested reviewer from users or teams.
@@ -330,7 +424,10 @@ impl<'octo> PullRequestHandler<'octo> {
         map.insert("reviewers".to_string(), reviewers.into().into());
         map.insert("team_reviewers".to_string(), team_reviewers.into().into());
 
-        self.crab.delete(route, Some(&map)).await
+        self.crab
+            .operation("pulls.remove_requested_reviewers")
+            .delete(route, Some(&map))
+            .await
     }
 
     /// List all `DiffEntry`s associated with the pull request.
@@ -349,7 +446,73 @@ impl<'octo> PullRequestHandler<'octo> {
             repo = self.repo,
         );
 
-        self.http_get(route, None::<&()>).await
+        self.http_get("pulls.list_files", route, None::<&()>).await
+    }
+
+    /// Suggests reviewers for the pull request from the repository's
+    /// `CODEOWNERS` file, i.e. the owners of the files the pull request
+    /// changes, in the order they're first encountered. The file is read from
+    /// the pull request's base commit, and from the same locations GitHub
+    /// uses (`.github/`, the root and `docs/`). Returns an empty list if the
+    /// repository has no `CODEOWNERS` file.
+    ///
+    /// Owners are returned as written in the file, i.e. as `@user`,
+    /// `@org/team` or an email address.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let reviewers = octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .suggested_reviewers(101)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn suggested_reviewers(&self, pr: u64) -> crate::Result<Vec<String>> {
+        let base = self.get(pr).await?.base.sha;
+        let repo = crate::repos::RepoHandler::new(
+            self.crab,
+            crate::repos::RepoRef::ByOwnerAndName(self.owner.clone(), self.repo.clone()),
+        );
+
+        let mut contents = None;
+        for location in codeowners::LOCATIONS {
+            match repo
+                .get_content()
+                .path(location)
+                .r#ref(base.as_str())
+                .send()
+                .await
+            {
+                Ok(mut items) => {
+                    contents = items
+                        .take_items()
+                        .into_iter()
+                        .next()
+                        .and_then(|content| content.decoded_content());
+                    break;
+                }
+                Err(crate::Error::GitHub { source, .. })
+                    if source.status_code == http::StatusCode::NOT_FOUND => {}
+                Err(e) => return Err(e),
+            }
+        }
+        let Some(contents) = contents else {
+            return Ok(Vec::new());
+        };
+        let code_owners = codeowners::CodeOwners::parse(&contents);
+
+        let first_page = self.list_files(pr).await?;
+        let files = self.crab.all_pages(first_page).await?;
+        let mut reviewers: Vec<String> = Vec::new();
+        for file in files {
+            for owner in code_owners.owners_of(&file.filename) {
+                if !reviewers.contains(owner) {
+                    reviewers.push(owner.clone());
+                }
+            }
+        }
+
+        Ok(reviewers)
     }
 
     /// Creates a new `ListCommentsBuilder` that can be configured to list and
@@ -377,6 +540,28 @@ impl<'octo> PullRequestHandler<'octo> {
         comment::ListCommentsBuilder::new(self, pr)
     }
 
+    /// Creates a review comment on the diff of a pull request.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::pulls::Side;
+    ///
+    /// let comment = octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .create_review_comment(5)
+    ///     .body("This could overflow")
+    ///     .commit_id("6dcb09b5b57875f334f61aebed695e2e4193db5e")
+    ///     .path("src/lib.rs")
+    ///     .line(42u64)
+    ///     .side(Side::Right)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_review_comment(&self, pr: u64) -> comment::CreateReviewCommentBuilder<'octo, '_> {
+        comment::CreateReviewCommentBuilder::new(self, pr)
+    }
+
     ///creates a new `CommentBuilder` for GET/PATCH/DELETE requests
     /// to the `/repos/{owner}/{repo}/pulls/{pr}/comments/{comment_id}` endpoint
     /// ```no_run
@@ -474,10 +659,94 @@ impl<'octo> PullRequestHandler<'octo> {
             comment_id = comment_id
         );
         self.crab
+            .operation("pulls.reply_to_comment")
             .post(route, Some(&json!({ "body": comment.into() })))
             .await
     }
 
+    /// Enables auto-merge for a pull request, so that it is merged with
+    /// `merge_method` as soon as all of its requirements are met.
+    ///
+    /// Auto-merge is only available through GraphQL, so this takes the pull
+    /// request's `node_id` (see [`PullRequest::node_id`]) rather than its
+    /// number.
+    ///
+    /// [`PullRequest::node_id`]: crate::models::pulls::PullRequest::node_id
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// use octocrab::params::pulls::MergeMethod;
+    ///
+    /// let pulls = octocrab.pulls("owner", "repo");
+    /// let pr = pulls.get(101).await?;
+    /// if let Some(node_id) = pr.node_id {
+    ///     pulls.enable_auto_merge(node_id, MergeMethod::Squash).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn enable_auto_merge(
+        &self,
+        pr_node_id: impl AsRef<str>,
+        merge_method: crate::params::pulls::MergeMethod,
+    ) -> crate::Result<()> {
+        use crate::params::pulls::MergeMethod;
+
+        let merge_method = match merge_method {
+            MergeMethod::Merge => "MERGE",
+            MergeMethod::Squash => "SQUASH",
+            MergeMethod::Rebase => "REBASE",
+        };
+        let response: serde_json::Value = self
+            .crab
+            .operation("pulls.enable_auto_merge")
+            .graphql(&json!({
+                "query": "mutation($pullRequestId: ID!, $mergeMethod: PullRequestMergeMethod!) { \
+                    enablePullRequestAutoMerge(input: { pullRequestId: $pullRequestId, mergeMethod: $mergeMethod }) { \
+                        clientMutationId \
+                    } \
+                }",
+                "variables": {
+                    "pullRequestId": pr_node_id.as_ref(),
+                    "mergeMethod": merge_method,
+                },
+            }))
+            .await?;
+
+        crate::map_graphql_errors(&response)
+    }
+
+    /// Disables auto-merge for a pull request, taking the pull request's
+    /// `node_id` as [`PullRequestHandler::enable_auto_merge`] does.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// octocrab
+    ///     .pulls("owner", "repo")
+    ///     .disable_auto_merge("PR_kwDOAHz1OX4uYAah")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn disable_auto_merge(&self, pr_node_id: impl AsRef<str>) -> crate::Result<()> {
+        let response: serde_json::Value = self
+            .crab
+            .operation("pulls.disable_auto_merge")
+            .graphql(&json!({
+                "query": "mutation($pullRequestId: ID!) { \
+                    disablePullRequestAutoMerge(input: { pullRequestId: $pullRequestId }) { \
+                        clientMutationId \
+                    } \
+                }",
+                "variables": {
+                    "pullRequestId": pr_node_id.as_ref(),
+                },
+            }))
+            .await?;
+
+        crate::map_graphql_errors(&response)
+    }
+
     /// Creates a new `MergePullRequestsBuilder` that can be configured used to
     /// merge a pull request.
     /// ```no_run
@@ -535,7 +804,11 @@ impl<'octo, 'r> ListReviewsBuilder<'octo, 'r> {
             pr = self.pr_number,
         );
 
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("pulls.list_reviews")
+            .get(route, Some(&self))
+            .await
     }
 }
 
@@ -554,6 +827,7 @@ pub struct ListReviewsBuilder<'octo, 'r> {
 impl<'octo> PullRequestHandler<'octo> {
     pub(crate) async fn http_get<R, A, P>(
         &self,
+        operation: &'static str,
         route: A,
         parameters: Option<&P>,
     ) -> crate::Result<R>
@@ -564,7 +838,7 @@ impl<'octo> PullRequestHandler<'octo> {
     {
         let uri = self.crab.parameterized_uri(route, parameters)?;
 
-        let mut request = Builder::new().uri(uri);
+        let mut request = Builder::new().uri(uri).extension(operation);
         if let Some(media_type) = self.media_type {
             request = request.header(
                 http::header::ACCEPT,
@@ -576,7 +850,12 @@ impl<'octo> PullRequestHandler<'octo> {
         R::from_response(crate::map_github_error(self.crab.execute(request).await?).await?).await
     }
 
-    pub(crate) async fn http_post<R, A, P>(&self, route: A, body: Option<&P>) -> crate::Result<R>
+    pub(crate) async fn http_post<R, A, P>(
+        &self,
+        operation: &'static str,
+        route: A,
+        body: Option<&P>,
+    ) -> crate::Result<R>
     where
         A: AsRef<str>,
         P: serde::Serialize + ?Sized,
@@ -586,14 +865,22 @@ impl<'octo> PullRequestHandler<'octo> {
             .path_and_query(route.as_ref())
             .build()
             .context(HttpSnafu)?;
-        let mut request = Builder::new().method(Method::POST).uri(uri);
+        let mut request = Builder::new()
+            .method(Method::POST)
+            .uri(uri)
+            .extension(operation);
         request = self.build_request(request);
         let request = self.crab.build_request(request, body)?;
 
         R::from_response(crate::map_github_error(self.crab.execute(request).await?).await?).await
     }
 
-    pub(crate) async fn http_put<R, A, P>(&self, route: A, body: Option<&P>) -> crate::Result<R>
+    pub(crate) async fn http_put<R, A, P>(
+        &self,
+        operation: &'static str,
+        route: A,
+        body: Option<&P>,
+    ) -> crate::Result<R>
     where
         A: AsRef<str>,
         P: serde::Serialize + ?Sized,
@@ -603,7 +890,10 @@ impl<'octo> PullRequestHandler<'octo> {
             .path_and_query(route.as_ref())
             .build()
             .context(HttpSnafu)?;
-        let mut request = Builder::new().method(Method::PUT).uri(uri);
+        let mut request = Builder::new()
+            .method(Method::PUT)
+            .uri(uri)
+            .extension(operation);
 
         request = self.build_request(request);
         let request = self.crab.build_request(request, body)?;
@@ -611,7 +901,12 @@ impl<'octo> PullRequestHandler<'octo> {
         R::from_response(crate::map_github_error(self.crab.execute(request).await?).await?).await
     }
 
-    pub(crate) async fn http_patch<R, A, P>(&self, route: A, body: Option<&P>) -> crate::Result<R>
+    pub(crate) async fn http_patch<R, A, P>(
+        &self,
+        operation: &'static str,
+        route: A,
+        body: Option<&P>,
+    ) -> crate::Result<R>
     where
         A: AsRef<str>,
         P: serde::Serialize + ?Sized,
@@ -621,7 +916,10 @@ impl<'octo> PullRequestHandler<'octo> {
             .path_and_query(route.as_ref())
             .build()
             .context(HttpSnafu)?;
-        let mut request = Builder::new().method(Method::PATCH).uri(uri);
+        let mut request = Builder::new()
+            .method(Method::PATCH)
+            .uri(uri)
+            .extension(operation);
 
         request = self.build_request(request);
         let request = self.crab.build_request(request, body)?;
@@ -0,0 +1,73 @@
+use crate::issues::{IssueHandler, ListCommentsBuilder, ListLabelsForIssueBuilder};
+use crate::{models, Result};
+
+/// A pull request seen through GitHub's issues API.
+///
+/// Every pull request is also an issue sharing the same number, and labels,
+/// assignees and conversation comments of a pull request are managed through
+/// the issue endpoints. This handler binds an [`IssueHandler`] to a pull
+/// request's number so those operations can be found from the pull request
+/// side.
+///
+/// Created with [`PullRequestHandler::as_issue`].
+///
+/// [`PullRequestHandler::as_issue`]: super::PullRequestHandler::as_issue
+pub struct PullRequestIssueHandler<'octo> {
+    issues: IssueHandler<'octo>,
+    number: u64,
+}
+
+impl<'octo> PullRequestIssueHandler<'octo> {
+    pub(crate) fn new(issues: IssueHandler<'octo>, number: u64) -> Self {
+        Self { issues, number }
+    }
+
+    /// The underlying [`IssueHandler`] for the pull request's repository, for
+    /// issue operations not covered by this handler.
+    pub fn issues(&self) -> &IssueHandler<'octo> {
+        &self.issues
+    }
+
+    /// Gets the issue representation of the pull request.
+    pub async fn get(&self) -> Result<models::issues::Issue> {
+        self.issues.get(self.number).await
+    }
+
+    /// Adds `labels` to the pull request.
+    pub async fn add_labels(&self, labels: &[String]) -> Result<Vec<models::Label>> {
+        self.issues.add_labels(self.number, labels).await
+    }
+
+    /// Removes `label` from the pull request.
+    pub async fn remove_label(&self, label: impl AsRef<str>) -> Result<Vec<models::Label>> {
+        self.issues.remove_label(self.number, label).await
+    }
+
+    /// Lists the labels on the pull request.
+    pub fn list_labels(&self) -> ListLabelsForIssueBuilder<'_, '_> {
+        self.issues.list_labels_for_issue(self.number)
+    }
+
+    /// Assigns `assignees` to the pull request.
+    pub async fn add_assignees(&self, assignees: &[&str]) -> Result<models::issues::Issue> {
+        self.issues.add_assignees(self.number, assignees).await
+    }
+
+    /// Removes `assignees` from the pull request.
+    pub async fn remove_assignees(&self, assignees: &[&str]) -> Result<models::issues::Issue> {
+        self.issues.remove_assignees(self.number, assignees).await
+    }
+
+    /// Creates a comment in the pull request's conversation. Use
+    /// [`PullRequestHandler::create_review_comment`] to comment on the diff.
+    ///
+    /// [`PullRequestHandler::create_review_comment`]: super::PullRequestHandler::create_review_comment
+    pub async fn create_comment(&self, body: impl AsRef<str>) -> Result<models::issues::Comment> {
+        self.issues.create_comment(self.number, body).await
+    }
+
+    /// Lists the comments in the pull request's conversation.
+    pub fn list_comments(&self) -> ListCommentsBuilder<'_, '_> {
+        self.issues.list_comments(self.number)
+    }
+}
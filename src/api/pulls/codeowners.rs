@@ -0,0 +1,199 @@
+//! A matcher for `CODEOWNERS` files.
+
+/// The locations GitHub looks for a `CODEOWNERS` file in, in order.
+pub(crate) const LOCATIONS: [&str; 3] = [".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+/// The rules of a parsed `CODEOWNERS` file.
+pub(crate) struct CodeOwners {
+    rules: Vec<Rule>,
+}
+
+struct Rule {
+    /// The pattern split on `/`, anchored to the repository's root.
+    segments: Vec<String>,
+    /// Whether the pattern only matches directories, i.e. ended in a `/`.
+    directory_only: bool,
+    owners: Vec<String>,
+}
+
+impl CodeOwners {
+    pub(crate) fn parse(contents: &str) -> Self {
+        let rules = contents
+            .lines()
+            .filter_map(|line| {
+                let mut tokens = line
+                    .split_whitespace()
+                    .take_while(|token| !token.starts_with('#'));
+                let pattern = tokens.next()?;
+                Some(Rule::new(pattern, tokens.map(String::from).collect()))
+            })
+            .collect();
+
+        Self { rules }
+    }
+
+    /// The owners of `path`, as given by the last matching rule. A matching
+    /// rule without owners leaves the path unowned.
+    pub(crate) fn owners_of(&self, path: &str) -> &[String] {
+        let path: Vec<&str> = path.trim_start_matches('/').split('/').collect();
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| rule.matches(&path))
+            .map_or(&[][..], |rule| rule.owners.as_slice())
+    }
+}
+
+impl Rule {
+    fn new(pattern: &str, owners: Vec<String>) -> Self {
+        let directory_only = pattern.ends_with('/');
+        let trimmed = pattern.trim_end_matches('/');
+        // Like in `.gitignore`, a pattern containing a slash other than a
+        // trailing one is relative to the root, otherwise it matches at any
+        // depth.
+        let anchored = trimmed.contains('/');
+        let mut segments: Vec<String> = Vec::new();
+        if !anchored {
+            segments.push("**".into());
+        }
+        segments.extend(
+            trimmed
+                .trim_start_matches('/')
+                .split('/')
+                .filter(|segment| !segment.is_empty())
+                .map(String::from),
+        );
+
+        Self {
+            segments,
+            directory_only,
+            owners,
+        }
+    }
+
+    fn matches(&self, path: &[&str]) -> bool {
+        // A pattern matching a directory also matches everything inside it,
+        // except for a trailing `/*`, which only matches direct children.
+        let covers_contents = self.segments.last().is_some_and(|last| last != "*");
+        (0..path.len())
+            .rev()
+            .map(|end| &path[..=end])
+            .enumerate()
+            .any(|(depth, prefix)| {
+                let allowed = match depth {
+                    0 => !self.directory_only,
+                    _ => covers_contents,
+                };
+                allowed && match_segments(&self.segments, prefix)
+            })
+    }
+}
+
+fn match_segments(pattern: &[String], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((first, rest)) if first == "**" => {
+            (0..=path.len()).any(|skip| match_segments(rest, &path[skip..]))
+        }
+        Some((first, rest)) => match path.split_first() {
+            Some((segment, path)) => match_wildcard(first, segment) && match_segments(rest, path),
+            None => false,
+        },
+    }
+}
+
+/// Matches a single path segment against a pattern supporting `*` and `?`.
+fn match_wildcard(pattern: &str, segment: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let segment: Vec<char> = segment.chars().collect();
+    let (mut p, mut s) = (0, 0);
+    let mut backtrack = None;
+    while s < segment.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, s));
+                p += 1;
+            }
+            Some('?') => {
+                p += 1;
+                s += 1;
+            }
+            Some(c) if *c == segment[s] => {
+                p += 1;
+                s += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    s = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CODEOWNERS: &str = r#"
+# Default owners for everything in the repo.
+*       @global-owner1 @global-owner2
+
+*.js    @js-owner # JavaScript files
+/build/logs/ @doctocat
+docs/*  docs@example.com
+apps/   @octocat
+/scripts/ @doctocat @octocat
+**/logs @octo-org/logs-team
+/apps/github
+"#;
+
+    fn owners(path: &str) -> Vec<String> {
+        CodeOwners::parse(CODEOWNERS).owners_of(path).to_vec()
+    }
+
+    #[test]
+    fn last_matching_rule_wins() {
+        assert_eq!(owners("README.md"), ["@global-owner1", "@global-owner2"]);
+        assert_eq!(owners("src/index.js"), ["@js-owner"]);
+    }
+
+    #[test]
+    fn anchored_patterns_only_match_from_the_root() {
+        assert_eq!(owners("docs/getting-started.md"), ["docs@example.com"]);
+        assert_eq!(
+            owners("docs/build-app/troubleshooting.md"),
+            ["@global-owner1", "@global-owner2"]
+        );
+        assert_eq!(owners("scripts/deploy.sh"), ["@doctocat", "@octocat"]);
+        assert_eq!(
+            owners("tools/scripts/deploy.sh"),
+            ["@global-owner1", "@global-owner2"]
+        );
+    }
+
+    #[test]
+    fn directory_patterns_match_nested_files() {
+        assert_eq!(owners("apps/web/main.rs"), ["@octocat"]);
+        assert_eq!(owners("src/apps/web/main.rs"), ["@octocat"]);
+        assert_eq!(owners("build/logs/2024/01.log"), ["@octo-org/logs-team"]);
+        assert_eq!(owners("deep/logs/out.txt"), ["@octo-org/logs-team"]);
+    }
+
+    #[test]
+    fn rules_without_owners_leave_paths_unowned() {
+        assert!(owners("apps/github/main.rs").is_empty());
+    }
+
+    #[test]
+    fn wildcards_match_within_a_segment() {
+        assert!(match_wildcard("*.rs", "main.rs"));
+        assert!(match_wildcard("ma?n.*", "main.rs"));
+        assert!(!match_wildcard("*.rs", "main.js"));
+        assert!(match_wildcard("*", ""));
+    }
+}
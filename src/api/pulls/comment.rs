@@ -1,6 +1,6 @@
 use serde_json::json;
 
-use crate::models::pulls::Comment;
+use crate::models::pulls::{Comment, ReviewComment, Side};
 
 use super::*;
 
@@ -84,7 +84,109 @@ impl<'octo, 'b> ListCommentsBuilder<'octo, 'b> {
                 "".into()
             },
         );
-        self.handler.http_get(route, Some(&self)).await
+        self.handler
+            .http_get("pulls.list_comments", route, Some(&self))
+            .await
+    }
+}
+
+/// A builder pattern struct for commenting on the diff of a pull request.
+///
+/// created by [`PullRequestHandler::create_review_comment`]
+#[derive(serde::Serialize)]
+pub struct CreateReviewCommentBuilder<'octo, 'b> {
+    #[serde(skip)]
+    handler: &'b PullRequestHandler<'octo>,
+    #[serde(skip)]
+    pr: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commit_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    side: Option<Side>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_line: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_side: Option<Side>,
+}
+
+impl<'octo, 'b> CreateReviewCommentBuilder<'octo, 'b> {
+    pub(crate) fn new(handler: &'b PullRequestHandler<'octo>, pr: u64) -> Self {
+        Self {
+            handler,
+            pr,
+            body: None,
+            commit_id: None,
+            path: None,
+            line: None,
+            side: None,
+            start_line: None,
+            start_side: None,
+        }
+    }
+
+    /// The text of the comment.
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// The SHA of the commit to comment on, usually the head of the pull request.
+    pub fn commit_id(mut self, commit_id: impl Into<String>) -> Self {
+        self.commit_id = Some(commit_id.into());
+        self
+    }
+
+    /// The path of the file to comment on, relative to the repository root.
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// The line of the diff the comment applies to. For a multi-line comment,
+    /// this is the last line of the range.
+    pub fn line(mut self, line: impl Into<u64>) -> Self {
+        self.line = Some(line.into());
+        self
+    }
+
+    /// Which side of the diff `line` refers to: `Left` for deletions and
+    /// `Right` for additions or unchanged lines.
+    pub fn side(mut self, side: Side) -> Self {
+        self.side = Some(side);
+        self
+    }
+
+    /// The first line of the range of a multi-line comment.
+    pub fn start_line(mut self, start_line: impl Into<u64>) -> Self {
+        self.start_line = Some(start_line.into());
+        self
+    }
+
+    /// Which side of the diff `start_line` refers to.
+    pub fn start_side(mut self, start_side: Side) -> Self {
+        self.start_side = Some(start_side);
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<ReviewComment> {
+        let route = format!(
+            "/repos/{owner}/{repo}/pulls/{pr}/comments",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+            pr = self.pr,
+        );
+        self.handler
+            .crab
+            .operation("pulls.create_review_comment")
+            .post(route, Some(&self))
+            .await
     }
 }
 
@@ -112,6 +214,7 @@ impl<'octo, 'b> CommentBuilder<'octo, 'b> {
     pub async fn get(self) -> crate::Result<Comment> {
         self.handler
             .crab
+            .operation("pulls.comment.get")
             .get(
                 format!(
                     "/repos/{owner}/{repo}/pulls/comments/{comment_id}",
@@ -128,6 +231,7 @@ impl<'octo, 'b> CommentBuilder<'octo, 'b> {
     pub async fn update(self, comment: &str) -> crate::Result<Comment> {
         self.handler
             .crab
+            .operation("pulls.comment.update")
             .patch(
                 format!(
                     "/repos/{owner}/{repo}/pulls/comments/{comment_id}",
@@ -144,6 +248,7 @@ impl<'octo, 'b> CommentBuilder<'octo, 'b> {
     pub async fn delete(self) -> crate::Result<()> {
         self.handler
             .crab
+            .operation("pulls.comment.delete")
             ._delete(
                 format!(
                     "/repos/{owner}/{repo}/pulls/comments/{comment_id}",
@@ -184,4 +289,30 @@ mod tests {
             })
         )
     }
+
+    #[tokio::test]
+    async fn serialize_create_review_comment() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.pulls("rust-lang", "rust");
+        let create = handler
+            .create_review_comment(1)
+            .body("Nit: typo")
+            .commit_id("6dcb09b5b57875f334f61aebed695e2e4193db5e")
+            .path("src/lib.rs")
+            .start_line(1u64)
+            .line(2u64)
+            .side(crate::models::pulls::Side::Right);
+
+        assert_eq!(
+            serde_json::to_value(create).unwrap(),
+            serde_json::json!({
+                "body": "Nit: typo",
+                "commit_id": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+                "path": "src/lib.rs",
+                "line": 2,
+                "side": "RIGHT",
+                "start_line": 1
+            })
+        )
+    }
 }
@@ -67,7 +67,9 @@ impl<'octo, 'b> CreatePullRequestBuilder<'octo, 'b> {
             repo = self.handler.repo
         );
 
-        self.handler.http_post(route, Some(&self)).await
+        self.handler
+            .http_post("pulls.create", route, Some(&self))
+            .await
     }
 }
 
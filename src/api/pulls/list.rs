@@ -94,7 +94,9 @@ impl<'octo, 'b> ListPullRequestsBuilder<'octo, 'b> {
             owner = self.handler.owner,
             repo = self.handler.repo
         );
-        self.handler.http_get(route, Some(&self)).await
+        self.handler
+            .http_get("pulls.list", route, Some(&self))
+            .await
     }
 }
 
@@ -66,7 +66,9 @@ impl<'octo, 'b> MergePullRequestsBuilder<'octo, 'b> {
             pull_number = self.pr_number,
         );
 
-        self.handler.http_put(route, Some(&self)).await
+        self.handler
+            .http_put("pulls.merge", route, Some(&self))
+            .await
     }
 }
 
@@ -0,0 +1,55 @@
+use std::time::Duration;
+
+use super::*;
+use crate::models::pulls::Mergeability;
+
+/// A builder pattern struct for waiting on GitHub to compute whether a pull
+/// request can be merged.
+///
+/// created by [`PullRequestHandler::mergeable`]
+pub struct PollMergeableBuilder<'octo, 'b> {
+    handler: &'b PullRequestHandler<'octo>,
+    pr_number: u64,
+    max_attempts: u32,
+    interval: Duration,
+}
+
+impl<'octo, 'b> PollMergeableBuilder<'octo, 'b> {
+    pub(crate) fn new(handler: &'b PullRequestHandler<'octo>, pr_number: u64) -> Self {
+        Self {
+            handler,
+            pr_number,
+            max_attempts: 5,
+            interval: Duration::from_secs(1),
+        }
+    }
+
+    /// How many times to fetch the pull request at most. Default: 5.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// How long to wait between two fetches. Default: one second.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Fetches the pull request until `mergeable` is known or the attempts run
+    /// out, in which case the last (still unknown) state is returned.
+    pub async fn send(self) -> crate::Result<Mergeability> {
+        let mut attempt = 1;
+        loop {
+            let pr = self.handler.get(self.pr_number).await?;
+            if pr.mergeable.is_some() || attempt >= self.max_attempts {
+                return Ok(Mergeability {
+                    mergeable: pr.mergeable,
+                    mergeable_state: pr.mergeable_state,
+                });
+            }
+            attempt += 1;
+            tokio::time::sleep(self.interval).await;
+        }
+    }
+}
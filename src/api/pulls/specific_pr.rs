@@ -59,7 +59,11 @@ impl<'octo, 'b> SpecificPullRequestBuilder<'octo, 'b> {
             repo = self.handler.repo,
             pr_number = self.pr_number
         );
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("pulls.pull_number.commits")
+            .get(route, Some(&self))
+            .await
     }
 
     /// Creates a new `ReviewsBuilder`
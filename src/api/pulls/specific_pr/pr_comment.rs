@@ -35,6 +35,7 @@ impl<'octo, 'b> SpecificPullRequestCommentBuilder<'octo, 'b> {
         );
         self.handler
             .crab
+            .operation("pulls.pull_number.comment.reply")
             .post(route, Some(&json!({ "body": comment.into() })))
             .await
     }
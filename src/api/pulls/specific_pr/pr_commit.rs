@@ -46,6 +46,10 @@ impl<'octo, 'r> SpecificPullRequestCommitBuilder<'octo, 'r> {
             pr = self.pr_number,
         );
 
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("pulls.pr_commits")
+            .get(route, Some(&self))
+            .await
     }
 }
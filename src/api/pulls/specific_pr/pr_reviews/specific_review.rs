@@ -49,7 +49,11 @@ impl<'octo, 'b> SpecificReviewBuilder<'octo, 'b> {
             pull_number = self.pr_number,
             review_id = self.review_id
         );
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("pulls.pull_number.reviews.review.get")
+            .get(route, Some(&self))
+            .await
     }
 
     ///Updates the contents of a specified review summary comment.
@@ -79,7 +83,11 @@ impl<'octo, 'b> SpecificReviewBuilder<'octo, 'b> {
             pull_number = self.pr_number,
             review_id = self.review_id
         );
-        self.handler.crab.patch(route, Some(&body.into())).await
+        self.handler
+            .crab
+            .operation("pulls.pull_number.reviews.review.update")
+            .patch(route, Some(&body.into()))
+            .await
     }
 
     ///Deletes a pull request review that has not been submitted. Submitted reviews cannot be deleted.
@@ -106,7 +114,11 @@ impl<'octo, 'b> SpecificReviewBuilder<'octo, 'b> {
             pull_number = self.pr_number,
             review_id = self.review_id
         );
-        self.handler.crab.delete(route, None::<&()>).await
+        self.handler
+            .crab
+            .operation("pulls.pull_number.reviews.review.delete_pending")
+            .delete(route, None::<&()>)
+            .await
     }
 
     ///Submits a pending review for a pull request.
@@ -140,6 +152,7 @@ impl<'octo, 'b> SpecificReviewBuilder<'octo, 'b> {
         );
         self.handler
             .crab
+            .operation("pulls.pull_number.reviews.review.submit")
             .post(
                 route,
                 Some(&serde_json::json!({ "body": body.into(), "event": action })),
@@ -173,6 +186,7 @@ impl<'octo, 'b> SpecificReviewBuilder<'octo, 'b> {
         );
         self.handler
             .crab
+            .operation("pulls.pull_number.reviews.review.dismiss")
             .put(
                 route,
                 Some(&serde_json::json!({ "message": message.into(), "event": "DISMISS" })),
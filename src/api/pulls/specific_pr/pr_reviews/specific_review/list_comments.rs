@@ -48,6 +48,10 @@ impl<'octo, 'b> ListReviewCommentsBuilder<'octo, 'b> {
             pull_number = self.pr_number,
             review_id = self.review_id
         );
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("pulls.pull_number.reviews.review.list_comments")
+            .get(route, Some(&self))
+            .await
     }
 }
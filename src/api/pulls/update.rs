@@ -75,7 +75,9 @@ impl<'octo, 'b> UpdatePullRequestBuilder<'octo, 'b> {
             pr = self.pull_number,
         );
 
-        self.handler.http_patch(route, Some(&self)).await
+        self.handler
+            .http_patch("pulls.update", route, Some(&self))
+            .await
     }
 }
 
@@ -1,5 +1,7 @@
 //! Github RateLimit API
 
+use web_time::{Duration, Instant};
+
 use crate::{models, Octocrab, Result};
 
 /// Handler for GitHub's rate_limit API.
@@ -7,11 +9,34 @@ use crate::{models, Octocrab, Result};
 /// Created with [`Octocrab::ratelimit`].
 pub struct RateLimitHandler<'octo> {
     crab: &'octo Octocrab,
+    cache_for: Option<Duration>,
 }
 
 impl<'octo> RateLimitHandler<'octo> {
     pub(crate) fn new(crab: &'octo Octocrab) -> Self {
-        Self { crab }
+        Self {
+            crab,
+            cache_for: None,
+        }
+    }
+
+    /// Reuse a previously fetched rate limit if it is younger than `ttl`.
+    /// Requests to `/rate_limit` don't count against the core limit, but
+    /// tools that check before every batch can still avoid the round trip.
+    /// The cache is shared between clones of the same [`Octocrab`].
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let ratelimit = octocrab::instance()
+    ///     .ratelimit()
+    ///     .cache_for(std::time::Duration::from_secs(5))
+    ///     .get()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cache_for(mut self, ttl: impl Into<Duration>) -> Self {
+        self.cache_for = Some(ttl.into());
+        self
     }
 
     /// Get the rate limit.
@@ -25,6 +50,53 @@ impl<'octo> RateLimitHandler<'octo> {
     /// # }
     /// ```
     pub async fn get(&self) -> Result<models::RateLimit> {
-        self.crab.get("/rate_limit", None::<&()>).await
+        if let Some(ttl) = self.cache_for {
+            let cache = self
+                .crab
+                .rate_limit_cache
+                .read()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            if let Some((fetched_at, ratelimit)) = cache.as_ref() {
+                if fetched_at.elapsed() < ttl {
+                    return Ok(ratelimit.clone());
+                }
+            }
+        }
+
+        let ratelimit: models::RateLimit = self
+            .crab
+            .operation("ratelimit.get")
+            .get("/rate_limit", None::<&()>)
+            .await?;
+        *self
+            .crab
+            .rate_limit_cache
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) =
+            Some((Instant::now(), ratelimit.clone()));
+
+        Ok(ratelimit)
+    }
+
+    /// Get the rate limit for a single resource, e.g. `search` or `graphql`.
+    /// Returns `None` if GitHub didn't report that resource.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::RateLimitResource;
+    ///
+    /// let search = octocrab::instance()
+    ///     .ratelimit()
+    ///     .resource(RateLimitResource::Search)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn resource(
+        &self,
+        resource: models::RateLimitResource,
+    ) -> Result<Option<models::Rate>> {
+        let ratelimit = self.get().await?;
+
+        Ok(ratelimit.resources.get(resource).cloned())
     }
 }
@@ -7,48 +7,68 @@ use http::Uri;
 use http_body_util::combinators::BoxBody;
 use snafu::ResultExt;
 
+mod autolinks;
 mod branches;
 mod collaborators;
 mod commits;
 mod contributors;
 mod dependabot;
+mod deploy_keys;
+mod deployments;
+mod environments;
 pub mod events;
 mod file;
 pub mod forks;
 mod generate;
+mod git;
 mod merges;
+mod pages;
 mod pulls;
 pub mod release_assets;
 pub mod releases;
 mod secret_scanning_alerts;
 mod secrets;
 mod stargazers;
+#[cfg(feature = "tokio")]
+mod stats;
 mod status;
 mod tags;
 mod teams;
+mod update;
+mod vulnerability_alerts;
 
 use crate::error::HttpSnafu;
 use crate::models::commits::GitCommitObject;
 use crate::models::{repos, RepositoryId};
 use crate::repos::file::GetReadmeBuilder;
 use crate::{models, params, Octocrab, Result};
+pub use autolinks::RepoAutolinksHandler;
 pub use branches::ListBranchesBuilder;
 pub use collaborators::ListCollaboratorsBuilder;
 pub use commits::ListCommitsBuilder;
 pub use contributors::ListContributorsBuilder;
 pub use dependabot::RepoDependabotAlertsHandler;
+pub use deploy_keys::{ListDeployKeysBuilder, RepoDeployKeysHandler};
+pub use deployments::RepoDeploymentsHandler;
+pub use environments::{RepoEnvironmentSecretsHandler, RepoEnvironmentsHandler};
 pub use file::{DeleteFileBuilder, GetContentBuilder, UpdateFileBuilder};
 pub use generate::GenerateRepositoryBuilder;
+pub use git::{GetTreeBuilder, RepoGitHandler};
 pub use merges::MergeBranchBuilder;
+pub use pages::{ListPageBuildsBuilder, RepoPagesHandler};
 pub use pulls::ListPullsBuilder;
 pub use release_assets::ReleaseAssetsHandler;
 pub use releases::ReleasesHandler;
 pub use secret_scanning_alerts::RepoSecretScanningAlertsHandler;
 pub use secrets::RepoSecretsHandler;
 pub use stargazers::ListStarGazersBuilder;
+#[cfg(feature = "tokio")]
+pub use stats::RepoStatsHandler;
 pub use status::{CreateStatusBuilder, ListStatusesBuilder};
 pub use tags::ListTagsBuilder;
 pub use teams::ListTeamsBuilder;
+pub use update::UpdateRepositoryBuilder;
+pub use vulnerability_alerts::{RepoAutomatedSecurityFixesHandler, RepoVulnerabilityAlertsHandler};
 
 #[derive(Clone)]
 pub(crate) enum RepoRef {
@@ -88,7 +108,10 @@ impl<'octo> RepoHandler<'octo> {
     pub async fn license(&self) -> Result<models::repos::Content> {
         let route = format!("/{}/license", self.repo);
 
-        self.crab.get(route, None::<&()>).await
+        self.crab
+            .operation("repos.license")
+            .get(route, None::<&()>)
+            .await
     }
 
     /// Get's a repository's public key.
@@ -101,7 +124,10 @@ impl<'octo> RepoHandler<'octo> {
     pub async fn public_key(&self) -> Result<models::PublicKey> {
         let route = format!("/{}/actions/secrets/public-key", self.repo);
 
-        self.crab.get(route, None::<&()>).await
+        self.crab
+            .operation("repos.public_key")
+            .get(route, None::<&()>)
+            .await
     }
 
     /// Fetches a single repository.
@@ -116,10 +142,61 @@ impl<'octo> RepoHandler<'octo> {
     /// ```
     pub async fn get(&self) -> Result<models::Repository> {
         let route = format!("/{}", self.repo);
-        self.crab.get(route, None::<&()>).await
+        self.crab
+            .operation("repos.get")
+            .get(route, None::<&()>)
+            .await
+    }
+
+    /// Fetches the repository and returns the merge methods it allows, so a
+    /// valid method can be picked before calling
+    /// [`PullRequestHandler::merge`](crate::pulls::PullRequestHandler::merge).
+    /// See [`Repository::allowed_merge_methods`](models::Repository::allowed_merge_methods).
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::pulls::MergeMethod;
+    ///
+    /// let methods = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .allowed_merge_methods()
+    ///     .await?;
+    /// let method = if methods.contains(&MergeMethod::Squash) {
+    ///     MergeMethod::Squash
+    /// } else {
+    ///     MergeMethod::Merge
+    /// };
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn allowed_merge_methods(&self) -> Result<Vec<params::pulls::MergeMethod>> {
+        self.get().await.map(|repo| repo.allowed_merge_methods())
+    }
+
+    /// Updates the repository's settings. Only the fields that are set on the
+    /// builder are changed.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::repos::Visibility;
+    ///
+    /// let repo = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .update()
+    ///     .description("Settings as code")
+    ///     .visibility(Visibility::Private)
+    ///     .delete_branch_on_merge(true)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self) -> UpdateRepositoryBuilder<'octo, '_> {
+        UpdateRepositoryBuilder::new(self)
     }
 
-    /// Fetches a repository's metrics.
+    /// Fetches a repository's community profile metrics, i.e. its health
+    /// percentage and which community files (README, LICENSE,
+    /// CODE_OF_CONDUCT, CONTRIBUTING, issue and pull request templates) it
+    /// has. Files that are missing are reported as `None`.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
     /// let repo = octocrab::instance()
@@ -131,7 +208,10 @@ impl<'octo> RepoHandler<'octo> {
     /// ```
     pub async fn get_community_profile_metrics(&self) -> Result<models::RepositoryMetrics> {
         let route = format!("/{}/community/profile", self.repo);
-        self.crab.get(route, None::<&()>).await
+        self.crab
+            .operation("repos.get_community_profile_metrics")
+            .get(route, None::<&()>)
+            .await
     }
 
     /// Fetches a single reference in the Git database.
@@ -153,9 +233,151 @@ impl<'octo> RepoHandler<'octo> {
         let route = format!(
             "/{repo}/git/ref/{reference}",
             repo = self.repo,
-            reference = reference.ref_url(),
+            reference = reference.git_ref_path()?,
         );
-        self.crab.get(route, None::<&()>).await
+        self.crab
+            .operation("repos.get_ref")
+            .get(route, None::<&()>)
+            .await
+    }
+
+    /// Fetches the SHA of the latest commit on `branch`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let sha = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .branch_sha("main")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn branch_sha(&self, branch: impl Into<String>) -> Result<String> {
+        let reference = self
+            .get_ref(&params::repos::Reference::Branch(branch.into()))
+            .await?;
+
+        match reference.object {
+            repos::Object::Commit { sha, .. } | repos::Object::Tag { sha, .. } => Ok(sha),
+        }
+    }
+
+    /// Commits `files` to `branch` as a single commit on top of the branch's
+    /// current head, returning the SHA of the new commit. The branch is
+    /// fast-forwarded, so this fails if it moved while the commit was being
+    /// created.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::repos::git::FileChange;
+    ///
+    /// let sha = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .commit_files(
+    ///         "main",
+    ///         "Update docs",
+    ///         vec![
+    ///             FileChange::upsert("docs/README.md", "# Docs"),
+    ///             FileChange::delete("docs/OLD.md"),
+    ///         ],
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn commit_files(
+        &self,
+        branch: impl Into<String>,
+        message: impl Into<String>,
+        files: Vec<repos::git::FileChange>,
+    ) -> Result<String> {
+        let branch = branch.into();
+        let git = self.git();
+
+        let head = self.branch_sha(branch.clone()).await?;
+        // Fetching a tree by commit SHA resolves to that commit's root tree.
+        let base_tree = git.get_tree(head.clone()).send().await?;
+        let tree = git
+            .create_tree(
+                Some(base_tree.sha),
+                files.into_iter().map(Into::into).collect(),
+            )
+            .await?;
+        let commit = git
+            .create_commit(message, tree.sha, vec![head])
+            .send()
+            .await?;
+        git.update_ref(
+            &params::repos::Reference::Branch(branch),
+            commit.sha.clone(),
+            false,
+        )
+        .await?;
+
+        Ok(commit.sha)
+    }
+
+    /// Fetches the SHA of the latest commit on each of `branches` with a
+    /// single GraphQL query. Branches that don't exist are left out of the
+    /// returned map.
+    ///
+    /// The repository must have been created with [`Octocrab::repos`], as the
+    /// GraphQL API needs its owner and name.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let shas = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .batch_branch_shas(&["main", "release", "feature"])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn batch_branch_shas(
+        &self,
+        branches: &[&str],
+    ) -> Result<std::collections::HashMap<String, String>> {
+        let RepoRef::ByOwnerAndName(owner, name) = &self.repo else {
+            return Err(crate::Error::Other {
+                source: "batch_branch_shas requires a repository referenced by owner and name"
+                    .into(),
+                backtrace: snafu::Backtrace::capture(),
+            });
+        };
+        if branches.is_empty() {
+            return Ok(Default::default());
+        }
+
+        let mut parameters = vec!["$owner: String!".to_string(), "$name: String!".to_string()];
+        let mut fields = Vec::with_capacity(branches.len());
+        let mut variables = serde_json::json!({ "owner": owner, "name": name });
+        for (index, branch) in branches.iter().enumerate() {
+            parameters.push(format!("$b{index}: String!"));
+            fields.push(format!(
+                "b{index}: ref(qualifiedName: $b{index}) {{ target {{ oid }} }}"
+            ));
+            variables[format!("b{index}")] = format!("refs/heads/{branch}").into();
+        }
+        let query = format!(
+            "query({}) {{ repository(owner: $owner, name: $name) {{ {} }} }}",
+            parameters.join(", "),
+            fields.join(" "),
+        );
+
+        let response: serde_json::Value = self
+            .crab
+            .operation("repos.batch_branch_shas")
+            .graphql(&serde_json::json!({ "query": query, "variables": variables }))
+            .await?;
+        crate::map_graphql_errors(&response)?;
+
+        let repository = &response["data"]["repository"];
+        Ok(branches
+            .iter()
+            .enumerate()
+            .filter_map(|(index, branch)| {
+                repository[format!("b{index}")]["target"]["oid"]
+                    .as_str()
+                    .map(|oid| (branch.to_string(), oid.to_string()))
+            })
+            .collect())
     }
 
     /// Fetches information about a git tag with the given `tag_sha`.
@@ -176,7 +398,10 @@ impl<'octo> RepoHandler<'octo> {
             repo = self.repo,
             tag_sha = tag_sha.into(),
         );
-        self.crab.get(route, None::<&()>).await
+        self.crab
+            .operation("repos.get_tag")
+            .get(route, None::<&()>)
+            .await
     }
 
     /// Creates a new reference for the repository.
@@ -198,12 +423,14 @@ impl<'octo> RepoHandler<'octo> {
         reference: &params::repos::Reference,
         sha: impl Into<String>,
     ) -> Result<models::repos::Ref> {
+        reference.git_ref_path()?;
         let route = format!("/{}/git/refs", self.repo);
         self.crab
+            .operation("repos.create_ref")
             .post(
                 route,
                 Some(&serde_json::json!({
-                    "ref": reference.full_ref_url(),
+                    "ref": reference.full_ref(),
                     "sha": sha.into(),
                 })),
             )
@@ -211,6 +438,11 @@ impl<'octo> RepoHandler<'octo> {
     }
 
     /// Deletes an existing reference from the repository.
+    ///
+    /// GitHub answers with `422 Unprocessable Entity` if the reference doesn't
+    /// exist or can't be deleted, e.g. because the branch is protected. That
+    /// error is returned as [`Error::Reference`](crate::Error::Reference),
+    /// which names the reference and wraps GitHub's error unchanged.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
     /// # let master_sha = "";
@@ -228,11 +460,28 @@ impl<'octo> RepoHandler<'octo> {
         let route = format!(
             "/{repo}/git/refs/{ref}",
             repo = self.repo,
-            ref = reference.ref_url()
+            ref = reference.git_ref_path()?
         );
-        crate::map_github_error(self.crab._delete(route, None::<&()>).await?)
-            .await
-            .map(drop)
+        match crate::map_github_error(
+            self.crab
+                .operation("repos.delete_ref")
+                ._delete(route, None::<&()>)
+                .await?,
+        )
+        .await
+        {
+            Ok(_) => Ok(()),
+            Err(crate::Error::GitHub { source, backtrace })
+                if source.status_code == http::StatusCode::UNPROCESSABLE_ENTITY =>
+            {
+                Err(crate::Error::Reference {
+                    reference: reference.full_ref(),
+                    source,
+                    backtrace,
+                })
+            }
+            Err(e) => Err(e),
+        }
     }
 
     /// Get repository content.
@@ -510,7 +759,30 @@ impl<'octo> RepoHandler<'octo> {
     /// ```
     pub async fn list_languages(&self) -> Result<models::repos::Languages> {
         let route = format!("/{}/languages", self.repo);
-        self.crab.get(route, None::<&()>).await
+        self.crab
+            .operation("repos.list_languages")
+            .get(route, None::<&()>)
+            .await
+    }
+
+    /// Exports the software bill of materials (SBOM) of the repository from its
+    /// dependency graph, in SPDX format.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let sbom = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .sbom()
+    ///     .await?;
+    /// println!("{} packages", sbom.package_count());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn sbom(&self) -> Result<models::repos::Sbom> {
+        let route = format!("/{}/dependency-graph/sbom", self.repo);
+        self.crab
+            .operation("repos.sbom")
+            .get(route, None::<&()>)
+            .await
     }
 
     /// Creates a `ReleaseAssetsHandler` for the specified repository.
@@ -524,13 +796,37 @@ impl<'octo> RepoHandler<'octo> {
     }
 
     /// Create a status for a specified commit in the specified repository.
-    pub fn create_status(&self, sha: String, state: models::StatusState) -> CreateStatusBuilder {
-        CreateStatusBuilder::new(self, sha, state)
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::StatusState;
+    /// use octocrab::params::repos::Reference;
+    ///
+    /// let status = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .create_status(
+    ///         &Reference::Commit("6dcb09b5b57875f334f61aebed695e2e4193db5e".to_string()),
+    ///         StatusState::Success,
+    ///     )
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_status(
+        &self,
+        sha: impl Into<params::repos::Commitish>,
+        state: models::StatusState,
+    ) -> CreateStatusBuilder<'_, '_> {
+        CreateStatusBuilder::new(self, sha.into().0, state)
     }
 
-    /// List statuses for a reference.
-    pub fn list_statuses(&self, sha: String) -> ListStatusesBuilder<'_, '_> {
-        ListStatusesBuilder::new(self, sha)
+    /// List statuses for a reference, which may be a
+    /// [`params::repos::Reference`] or a commit SHA.
+    pub fn list_statuses(
+        &self,
+        reference: impl Into<params::repos::Commitish>,
+    ) -> ListStatusesBuilder<'_, '_> {
+        ListStatusesBuilder::new(self, reference.into().0)
     }
 
     /// List pull requests for a reference.
@@ -602,12 +898,17 @@ impl<'octo> RepoHandler<'octo> {
         hook: crate::models::hooks::Hook,
     ) -> crate::Result<crate::models::hooks::Hook> {
         let route = format!("/{}/hooks", self.repo);
-        let res = self.crab.post(route, Some(&hook)).await?;
+        let res = self
+            .crab
+            .operation("repos.create_hook")
+            .post(route, Some(&hook))
+            .await?;
 
         Ok(res)
     }
 
-    /// Gets the combined status for the specified reference.
+    /// Gets the combined status for the specified reference, which may be a
+    /// [`params::repos::Reference`] or a commit SHA.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
     /// use octocrab::params::repos::Reference;
@@ -621,14 +922,224 @@ impl<'octo> RepoHandler<'octo> {
     /// ```
     pub async fn combined_status_for_ref(
         &self,
-        reference: &params::repos::Reference,
+        reference: impl Into<params::repos::Commitish>,
     ) -> Result<models::CombinedStatus> {
         let route = format!(
             "/{repo}/commits/{reference}/status",
             repo = self.repo,
-            reference = reference.ref_url(),
+            reference = reference.into().ref_url_path(),
+        );
+        self.crab
+            .operation("repos.combined_status_for_ref")
+            .get(route, None::<&()>)
+            .await
+    }
+
+    /// Gets the status checks required to pass before merging into `branch`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let checks = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .get_required_status_checks("main")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_required_status_checks(
+        &self,
+        branch: impl AsRef<str>,
+    ) -> Result<models::repos::RequiredStatusChecks> {
+        let route = format!(
+            "/{repo}/branches/{branch}/protection/required_status_checks",
+            repo = self.repo,
+            branch = branch.as_ref(),
+        );
+        self.crab
+            .operation("repos.get_required_status_checks")
+            .get(route, None::<&()>)
+            .await
+    }
+
+    /// Groups the status checks required by the protection of `branch` by the
+    /// state of their latest commit status or check run on `reference`, e.g.
+    /// the head of a pull request targeting `branch`. A check run counts as
+    /// passing if it concluded with `success`, `neutral` or `skipped`.
+    ///
+    /// The required checks are read from the protection's `checks`, falling
+    /// back to the deprecated `contexts` when it is empty. A check that must
+    /// be set by a specific app only counts check runs created by that app,
+    /// as commit statuses don't record the app that set them.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let status = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .required_status_checks_status("main", "feature".to_string())
+    ///     .await?;
+    ///
+    /// if !status.pending.is_empty() {
+    ///     println!("waiting on {}", status.pending.join(", "));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn required_status_checks_status(
+        &self,
+        branch: impl AsRef<str>,
+        reference: impl Into<params::repos::Commitish>,
+    ) -> Result<models::repos::RequiredStatusChecksStatus> {
+        let reference = reference.into();
+        let required = self.get_required_status_checks(branch).await?;
+        let route = format!(
+            "/{repo}/commits/{reference}/status",
+            repo = self.repo,
+            reference = reference.ref_url_path(),
+        );
+        let (combined, check_runs): (models::CombinedStatus, _) = futures::future::try_join(
+            self.crab
+                .operation("repos.required_status_checks_status")
+                .get(route, None::<&()>),
+            self.all_check_runs(&reference),
+        )
+        .await?;
+
+        let required: Vec<(String, Option<models::AppId>)> = if required.checks.is_empty() {
+            required
+                .contexts
+                .into_iter()
+                .map(|context| (context, None))
+                .collect()
+        } else {
+            required
+                .checks
+                .into_iter()
+                .map(|check| (check.context, check.app_id))
+                .collect()
+        };
+
+        let mut result = models::repos::RequiredStatusChecksStatus::default();
+        for (context, app_id) in required {
+            let state = combined
+                .statuses
+                .iter()
+                .filter(|_| app_id.is_none())
+                .find(|status| status.context.as_deref() == Some(context.as_str()))
+                .map(|status| status.state);
+            let check_run = check_runs
+                .iter()
+                .filter(|run| run.name == context)
+                .filter(|run| match app_id {
+                    Some(app_id) => run.app.as_ref().is_some_and(|app| app.id == app_id),
+                    None => true,
+                })
+                .max_by_key(|run| run.id);
+
+            match (state, check_run) {
+                (Some(models::StatusState::Success), _) => result.passing.push(context),
+                (Some(models::StatusState::Failure | models::StatusState::Error), _) => {
+                    result.failing.push(context)
+                }
+                (Some(_), _) | (None, None) => result.pending.push(context),
+                (None, Some(run)) => match run.conclusion.as_deref() {
+                    Some("success" | "neutral" | "skipped") => result.passing.push(context),
+                    Some(_) => result.failing.push(context),
+                    None => result.pending.push(context),
+                },
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Checks whether `reference` is ready to merge by fetching its combined
+    /// commit status and its check runs concurrently. The verdict passes when
+    /// the combined status is `success` (or no statuses were reported at all)
+    /// and the latest run of every check completed with a `success`, `neutral`
+    /// or `skipped` conclusion.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let readiness = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .merge_readiness("feature")
+    ///     .await?;
+    ///
+    /// if !readiness.all_passing {
+    ///     println!("statuses are {:?}", readiness.combined_status);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn merge_readiness(
+        &self,
+        reference: impl Into<params::repos::Commitish>,
+    ) -> Result<models::repos::MergeReadiness> {
+        let reference = reference.into();
+        let status_route = format!(
+            "/{repo}/commits/{reference}/status",
+            repo = self.repo,
+            reference = reference.ref_url_path(),
         );
-        self.crab.get(route, None::<&()>).await
+        let (combined, check_runs): (models::CombinedStatus, _) = futures::future::try_join(
+            self.crab
+                .operation("repos.merge_readiness")
+                .get(status_route, None::<&()>),
+            self.all_check_runs(&reference),
+        )
+        .await?;
+
+        let statuses_passing =
+            combined.state == models::StatusState::Success || combined.total_count == 0;
+        // Re-runs leave older runs with the same name behind, so only the
+        // latest run of each check counts.
+        let names: std::collections::HashSet<_> =
+            check_runs.iter().map(|run| run.name.as_str()).collect();
+        let checks_passing = names.into_iter().all(|name| {
+            check_runs
+                .iter()
+                .filter(|run| run.name == name)
+                .max_by_key(|run| run.id)
+                .is_some_and(|run| {
+                    matches!(
+                        run.conclusion.as_deref(),
+                        Some("success" | "neutral" | "skipped")
+                    )
+                })
+        });
+
+        Ok(models::repos::MergeReadiness {
+            combined_status: combined.state,
+            all_passing: statuses_passing && checks_passing,
+            check_runs,
+        })
+    }
+
+    /// Fetches every page of the check runs of `reference`.
+    async fn all_check_runs(
+        &self,
+        reference: &params::repos::Commitish,
+    ) -> Result<Vec<models::checks::CheckRun>> {
+        let first_page = match &self.repo {
+            RepoRef::ByOwnerAndName(owner, name) => {
+                self.crab
+                    .checks(owner, name)
+                    .list_check_runs_for_git_ref(reference.clone())
+                    .per_page(100u8)
+                    .send_page()
+                    .await?
+            }
+            RepoRef::ById(_) => {
+                let route = format!(
+                    "/{repo}/commits/{reference}/check-runs",
+                    repo = self.repo,
+                    reference = reference.ref_url_path(),
+                );
+                self.crab
+                    .operation("repos.all_check_runs")
+                    .get(route, Some(&[("per_page", 100u8)]))
+                    .await?
+            }
+        };
+
+        self.crab.all_pages(first_page).await
     }
 
     /// Creates a new repository from repository if it is a template.
@@ -670,7 +1181,11 @@ impl<'octo> RepoHandler<'octo> {
             .method(http::Method::GET)
             .header(ACCEPT, "application/vnd.github.v3.raw");
         self.crab
-            .execute(self.crab.build_request(request, None::<&()>)?)
+            .execute(
+                self.crab
+                    .operation("repos.raw_file")
+                    .build_request(request, None::<&()>)?,
+            )
             .await
     }
 
@@ -686,9 +1201,106 @@ impl<'octo> RepoHandler<'octo> {
             .path_and_query(route)
             .build()
             .context(HttpSnafu)?;
-        crate::map_github_error(self.crab._delete(uri, None::<&()>).await?)
+        crate::map_github_error(
+            self.crab
+                .operation("repos.delete")
+                ._delete(uri, None::<&()>)
+                .await?,
+        )
+        .await
+        .map(drop)
+    }
+
+    /// Transfers this repository to `new_owner`, optionally giving the teams
+    /// in `team_ids` access to it when the new owner is an organization.
+    ///
+    /// GitHub accepts the transfer with `202 Accepted` and completes it in the
+    /// background, so the returned repository is only partially populated and
+    /// may still report the previous owner.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let repo = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .transfer("new-org", Some(vec![12.into(), 345.into()]))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn transfer(
+        &self,
+        new_owner: impl Into<String>,
+        team_ids: Option<Vec<models::TeamId>>,
+    ) -> Result<models::Repository> {
+        let route = format!("/{}/transfer", self.repo);
+        let mut body = serde_json::json!({ "new_owner": new_owner.into() });
+        if let Some(team_ids) = team_ids {
+            body["team_ids"] = serde_json::json!(team_ids);
+        }
+
+        self.crab
+            .operation("repos.transfer")
+            .post(route, Some(&body))
+            .await
+    }
+
+    /// Fetches the interaction limit in place for the repository, if any.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let limit = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .get_interaction_limits()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_interaction_limits(
+        &self,
+    ) -> crate::Result<Option<crate::models::interactions::InteractionLimit>> {
+        crate::api::interactions::get(self.crab, format!("/{}/interaction-limits", self.repo)).await
+    }
+
+    /// Limits who can interact with the repository, replacing any limit
+    /// already in place.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::interactions::{
+    ///     InteractionExpiry, InteractionGroup, SetInteractionLimit,
+    /// };
+    ///
+    /// let mut limit = SetInteractionLimit::new(InteractionGroup::CollaboratorsOnly);
+    /// limit.expiry = Some(InteractionExpiry::OneWeek);
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .set_interaction_limits(&limit)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_interaction_limits(
+        &self,
+        limit: &crate::models::interactions::SetInteractionLimit,
+    ) -> crate::Result<crate::models::interactions::InteractionLimit> {
+        crate::api::interactions::set(
+            self.crab,
+            format!("/{}/interaction-limits", self.repo),
+            limit,
+        )
+        .await
+    }
+
+    /// Removes the interaction limit in place for the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .remove_interaction_limits()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_interaction_limits(&self) -> crate::Result<()> {
+        crate::api::interactions::remove(self.crab, format!("/{}/interaction-limits", self.repo))
             .await
-            .map(drop)
     }
 
     /// Stream the repository contents as a .tar.gz
@@ -699,14 +1311,19 @@ impl<'octo> RepoHandler<'octo> {
         let route = format!(
             "/{repo}/tarball/{reference}",
             repo = self.repo,
-            reference = reference.into(),
+            reference = reference.into().ref_url_path(),
         );
         let uri = Uri::builder()
             .path_and_query(route)
             .build()
             .context(HttpSnafu)?;
         self.crab
-            .follow_location_to_data(self.crab._get(uri).await?)
+            .follow_location_to_data(
+                self.crab
+                    .operation("repos.download_tarball")
+                    ._get(uri)
+                    .await?,
+            )
             .await
     }
 
@@ -722,10 +1339,54 @@ impl<'octo> RepoHandler<'octo> {
             .build()
             .context(HttpSnafu)?;
 
-        let response = self.crab._get(uri).await?;
+        let response = self
+            .crab
+            .operation("repos.is_collaborator")
+            ._get(uri)
+            .await?;
         Ok(response.status().is_success())
     }
 
+    /// Fetches `username`'s permission level on the repository. Returns
+    /// `None` if the user isn't a collaborator.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let permission = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .get_collaborator_permission("octocat")
+    ///     .await?;
+    ///
+    /// let is_maintainer = permission.is_some_and(|p| p.permission == "admin");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_collaborator_permission(
+        &self,
+        username: impl AsRef<str>,
+    ) -> Result<Option<models::repos::CollaboratorPermission>> {
+        let route = format!(
+            "/{repo}/collaborators/{username}/permission",
+            repo = self.repo,
+            username = username.as_ref(),
+        );
+        let uri = Uri::builder()
+            .path_and_query(route)
+            .build()
+            .context(HttpSnafu)?;
+
+        let response = self
+            .crab
+            .operation("repos.get_collaborator_permission")
+            ._get(uri)
+            .await?;
+        if response.status() == http::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        let response = crate::map_github_error(response).await?;
+        crate::FromResponse::from_response(response).await.map(Some)
+    }
+
     /// Merges `head` into the `base` branch.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
@@ -752,11 +1413,73 @@ impl<'octo> RepoHandler<'octo> {
         RepoSecretsHandler::new(self)
     }
 
+    /// Handle deployments of the repository
+    pub fn deployments(&self) -> RepoDeploymentsHandler<'_> {
+        RepoDeploymentsHandler::new(self)
+    }
+
+    /// Handle deployment environments on the repository
+    pub fn environments(&self) -> RepoEnvironmentsHandler<'_> {
+        RepoEnvironmentsHandler::new(self)
+    }
+
+    /// Handle the GitHub Pages site of the repository
+    pub fn pages(&self) -> RepoPagesHandler<'_> {
+        RepoPagesHandler::new(self)
+    }
+
+    /// Handle the Git database (blobs, trees, commits and refs) of the repository
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let tree = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .git()
+    ///     .get_tree("main")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn git(&self) -> RepoGitHandler<'_> {
+        RepoGitHandler::new(self)
+    }
+
+    /// Handle statistics of the repository, retrying while GitHub computes them
+    #[cfg(feature = "tokio")]
+    pub fn stats(&self) -> RepoStatsHandler<'_> {
+        RepoStatsHandler::new(self)
+    }
+
+    /// Handle deploy keys on the repository
+    pub fn deploy_keys(&self) -> RepoDeployKeysHandler<'_> {
+        RepoDeployKeysHandler::new(self)
+    }
+
+    /// Handle autolinks to external resources on the repository
+    pub fn autolinks(&self) -> RepoAutolinksHandler<'_> {
+        RepoAutolinksHandler::new(self)
+    }
+
+    /// Handle webhooks on the repository
+    pub fn hooks(&self) -> crate::hooks::WebhooksHandler<'octo> {
+        crate::hooks::WebhooksHandler::for_repo(self.crab, format!("/{}/hooks", self.repo))
+    }
+
     /// Handle dependabot alerts on the repository
     pub fn dependabot(&self) -> RepoDependabotAlertsHandler<'_> {
         RepoDependabotAlertsHandler::new(self)
     }
 
+    /// Handle dependency vulnerability alerts on the repository
+    pub fn vulnerability_alerts(&self) -> RepoVulnerabilityAlertsHandler<'_> {
+        RepoVulnerabilityAlertsHandler::new(self)
+    }
+
+    /// Handle automated security fixes on the repository
+    pub fn automated_security_fixes(&self) -> RepoAutomatedSecurityFixesHandler<'_> {
+        RepoAutomatedSecurityFixesHandler::new(self)
+    }
+
     /// Handle secrets scanning alerts on the repository
     pub fn secrets_scanning(&self) -> RepoSecretScanningAlertsHandler<'_> {
         RepoSecretScanningAlertsHandler::new(self)
@@ -861,6 +1584,10 @@ impl<'octo, 'req> CreateGitCommitObjectBuilder<'octo, 'req> {
     /// Sends the request
     pub async fn send(&self) -> Result<GitCommitObject> {
         let route = format!("/{}/git/commits", self.repo);
-        self.handler.crab.post(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("repos.create_git_commit_object")
+            .post(route, Some(&self))
+            .await
     }
 }
@@ -0,0 +1,116 @@
+use super::RepoHandler;
+use crate::models::repos::Autolink;
+use crate::models::AutolinkId;
+use crate::Page;
+
+/// A client to GitHub's repository autolinks API. All of its endpoints
+/// require admin access to the repository.
+///
+/// Created with [`RepoHandler::autolinks`].
+pub struct RepoAutolinksHandler<'octo> {
+    handler: &'octo RepoHandler<'octo>,
+}
+
+impl<'octo> RepoAutolinksHandler<'octo> {
+    pub(crate) fn new(repo: &'octo RepoHandler<'octo>) -> Self {
+        Self { handler: repo }
+    }
+
+    /// Lists the autolinks of the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let autolinks = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .autolinks()
+    ///     .list()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list(&self) -> crate::Result<Page<Autolink>> {
+        let route = format!("/{}/autolinks", self.handler.repo);
+        self.handler
+            .crab
+            .operation("repos.autolinks.list")
+            .get(route, None::<&()>)
+            .await
+    }
+
+    /// Creates an autolink turning references starting with `key_prefix`
+    /// into links to `url_template`, in which `<num>` is replaced by the
+    /// reference's identifier.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let autolink = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .autolinks()
+    ///     .create("JIRA-", "https://jira.example.com/browse/JIRA-<num>", false)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create(
+        &self,
+        key_prefix: impl Into<String>,
+        url_template: impl Into<String>,
+        is_alphanumeric: bool,
+    ) -> crate::Result<Autolink> {
+        let route = format!("/{}/autolinks", self.handler.repo);
+        self.handler
+            .crab
+            .operation("repos.autolinks.create")
+            .post(
+                route,
+                Some(&serde_json::json!({
+                    "key_prefix": key_prefix.into(),
+                    "url_template": url_template.into(),
+                    "is_alphanumeric": is_alphanumeric,
+                })),
+            )
+            .await
+    }
+
+    /// Fetches a single autolink.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let autolink = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .autolinks()
+    ///     .get(1u64)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, autolink_id: impl Into<AutolinkId>) -> crate::Result<Autolink> {
+        let route = format!("/{}/autolinks/{}", self.handler.repo, autolink_id.into());
+        self.handler
+            .crab
+            .operation("repos.autolinks.get")
+            .get(route, None::<&()>)
+            .await
+    }
+
+    /// Deletes an autolink.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .autolinks()
+    ///     .delete(1u64)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(&self, autolink_id: impl Into<AutolinkId>) -> crate::Result<()> {
+        let route = format!("/{}/autolinks/{}", self.handler.repo, autolink_id.into());
+        crate::map_github_error(
+            self.handler
+                .crab
+                .operation("repos.autolinks.delete")
+                ._delete(route, None::<&()>)
+                .await?,
+        )
+        .await
+        .map(drop)
+    }
+}
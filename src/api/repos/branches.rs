@@ -45,6 +45,10 @@ impl<'octo, 'r> ListBranchesBuilder<'octo, 'r> {
     /// Sends the actual request.
     pub async fn send(self) -> Result<crate::Page<models::repos::Branch>> {
         let route = format!("/{}/branches", self.handler.repo);
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("repos.list_branches")
+            .get(route, Some(&self))
+            .await
     }
 }
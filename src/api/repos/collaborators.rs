@@ -46,6 +46,10 @@ impl<'octo, 'r> ListCollaboratorsBuilder<'octo, 'r> {
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<crate::Page<crate::models::Collaborator>> {
         let route = format!("/{}/collaborators", self.handler.repo);
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("repos.list_collaborators")
+            .get(route, Some(&self))
+            .await
     }
 }
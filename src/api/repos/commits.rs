@@ -94,6 +94,10 @@ impl<'octo, 'r> ListCommitsBuilder<'octo, 'r> {
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<crate::Page<crate::models::repos::RepoCommit>> {
         let route = format!("/{}/commits", self.handler.repo);
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("repos.list_commits")
+            .get(route, Some(&self))
+            .await
     }
 }
@@ -43,6 +43,10 @@ impl<'octo, 'r> ListContributorsBuilder<'octo, 'r> {
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<crate::Page<crate::models::Contributor>> {
         let route = format!("/{}/contributors", self.handler.repo);
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("repos.list_contributors")
+            .get(route, Some(&self))
+            .await
     }
 }
@@ -15,14 +15,19 @@ struct Params {
     #[serde(skip_serializing_if = "Option::is_none")]
     page: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "crate::api::comma_separated")]
     state: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "crate::api::comma_separated")]
     severity: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "crate::api::comma_separated")]
     ecosystem: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "crate::api::comma_separated")]
     package: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "crate::api::comma_separated")]
     manifest: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     scope: Option<String>,
@@ -67,7 +72,11 @@ impl<'octo> RepoDependabotAlertsHandler<'octo> {
         &self,
     ) -> crate::Result<crate::Page<crate::models::repos::dependabot::DependabotAlert>> {
         let route = format!("/{}/dependabot/alerts", self.handler.repo);
-        self.handler.crab.get(route, Some(&self.params)).await
+        self.handler
+            .crab
+            .operation("repos.dependabot.get_alerts")
+            .get(route, Some(&self.params))
+            .await
     }
 
     /// Results per page (max 100).
@@ -146,7 +155,11 @@ impl<'octo> RepoDependabotAlertsHandler<'octo> {
         alert_number: u32,
     ) -> crate::Result<crate::models::repos::dependabot::DependabotAlert> {
         let route = format!("/{}/dependabot/alerts/{}", self.handler.repo, alert_number);
-        self.handler.crab.get(route, None::<&()>).await
+        self.handler
+            .crab
+            .operation("repos.dependabot.get_alert")
+            .get(route, None::<&()>)
+            .await
     }
 
     /// Updates a dependabot alert.
@@ -175,6 +188,10 @@ impl<'octo> RepoDependabotAlertsHandler<'octo> {
         alert_update: Option<&crate::models::repos::dependabot::UpdateDependabotAlert<'_>>,
     ) -> crate::Result<crate::models::repos::dependabot::DependabotAlert> {
         let route = format!("/{}/dependabot/alerts/{}", self.handler.repo, alert_number);
-        self.handler.crab.patch(route, alert_update).await
+        self.handler
+            .crab
+            .operation("repos.dependabot.update_alert")
+            .patch(route, alert_update)
+            .await
     }
 }
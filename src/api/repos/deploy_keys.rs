@@ -0,0 +1,156 @@
+use super::RepoHandler;
+use crate::models::repos::DeployKey;
+use crate::models::DeployKeyId;
+use crate::Page;
+
+/// A client to GitHub's repository deploy keys API.
+///
+/// Created with [`RepoHandler::deploy_keys`].
+pub struct RepoDeployKeysHandler<'octo> {
+    handler: &'octo RepoHandler<'octo>,
+}
+
+impl<'octo> RepoDeployKeysHandler<'octo> {
+    pub(crate) fn new(repo: &'octo RepoHandler<'octo>) -> Self {
+        Self { handler: repo }
+    }
+
+    /// Lists the deploy keys of the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let keys = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .deploy_keys()
+    ///     .list()
+    ///     .per_page(50)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListDeployKeysBuilder<'octo, '_> {
+        ListDeployKeysBuilder::new(self)
+    }
+
+    /// Fetches a single deploy key.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let key = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .deploy_keys()
+    ///     .get(42u64)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, key_id: impl Into<DeployKeyId>) -> crate::Result<DeployKey> {
+        let route = format!("/{}/keys/{}", self.handler.repo, key_id.into());
+        self.handler
+            .crab
+            .operation("repos.deploy_keys.get")
+            .get(route, None::<&()>)
+            .await
+    }
+
+    /// Adds a deploy key to the repository. Keys that aren't `read_only`
+    /// can also push to the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let key = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .deploy_keys()
+    ///     .create("CI", "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAI", true)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create(
+        &self,
+        title: impl Into<String>,
+        key: impl Into<String>,
+        read_only: bool,
+    ) -> crate::Result<DeployKey> {
+        let route = format!("/{}/keys", self.handler.repo);
+
+        let params = serde_json::json!({
+            "title": title.into(),
+            "key": key.into(),
+            "read_only": read_only,
+        });
+        self.handler
+            .crab
+            .operation("repos.deploy_keys.create")
+            .post(route, Some(&params))
+            .await
+    }
+
+    /// Removes a deploy key from the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .deploy_keys()
+    ///     .delete(42u64)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(&self, key_id: impl Into<DeployKeyId>) -> crate::Result<()> {
+        let route = format!("/{}/keys/{}", self.handler.repo, key_id.into());
+        crate::map_github_error(
+            self.handler
+                .crab
+                .operation("repos.deploy_keys.delete")
+                ._delete(route, None::<&()>)
+                .await?,
+        )
+        .await
+        .map(drop)
+    }
+}
+
+/// A builder pattern struct for listing deploy keys.
+///
+/// Created by [`RepoDeployKeysHandler::list`].
+#[derive(serde::Serialize)]
+pub struct ListDeployKeysBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoDeployKeysHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListDeployKeysBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoDeployKeysHandler<'octo>) -> Self {
+        Self {
+            handler,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Page<DeployKey>> {
+        let route = format!("/{}/keys", self.handler.handler.repo);
+        self.handler
+            .handler
+            .crab
+            .operation("repos.deploy_keys.list")
+            .get(route, Some(&self))
+            .await
+    }
+}
@@ -0,0 +1,73 @@
+use super::RepoHandler;
+use crate::models::repos::deployments::DeploymentStatus;
+use crate::models::DeploymentId;
+use crate::Page;
+
+/// A client to GitHub's deployments API.
+///
+/// Created with [`RepoHandler::deployments`].
+pub struct RepoDeploymentsHandler<'octo> {
+    handler: &'octo RepoHandler<'octo>,
+}
+
+impl<'octo> RepoDeploymentsHandler<'octo> {
+    pub(crate) fn new(repo: &'octo RepoHandler<'octo>) -> Self {
+        Self { handler: repo }
+    }
+
+    /// Lists the statuses of a deployment, most recent first.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let statuses = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .deployments()
+    ///     .list_statuses(42u64)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_statuses(
+        &self,
+        deployment_id: impl Into<DeploymentId>,
+    ) -> crate::Result<Page<DeploymentStatus>> {
+        let route = format!(
+            "/{}/deployments/{}/statuses",
+            self.handler.repo,
+            deployment_id.into()
+        );
+        self.handler
+            .crab
+            .operation("repos.deployments.list_statuses")
+            .get(route, None::<&()>)
+            .await
+    }
+
+    /// Fetches the most recent status of a deployment, if it has any.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let status = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .deployments()
+    ///     .latest_status(42u64)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn latest_status(
+        &self,
+        deployment_id: impl Into<DeploymentId>,
+    ) -> crate::Result<Option<DeploymentStatus>> {
+        let route = format!(
+            "/{}/deployments/{}/statuses",
+            self.handler.repo,
+            deployment_id.into()
+        );
+        let mut page: Page<DeploymentStatus> = self
+            .handler
+            .crab
+            .operation("repos.deployments.latest_status")
+            .get(route, Some(&[("per_page", 1)]))
+            .await?;
+        Ok(page.take_items().into_iter().next())
+    }
+}
@@ -0,0 +1,190 @@
+use http::StatusCode;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+use super::RepoHandler;
+use crate::models::repos::secrets::{CreateRepositorySecret, CreateRepositorySecretResponse};
+
+/// A client to GitHub's deployment environments API.
+///
+/// Created with [`RepoHandler::environments`].
+pub struct RepoEnvironmentsHandler<'octo> {
+    handler: &'octo RepoHandler<'octo>,
+}
+
+impl<'octo> RepoEnvironmentsHandler<'octo> {
+    pub(crate) fn new(repo: &'octo RepoHandler<'octo>) -> Self {
+        Self { handler: repo }
+    }
+
+    /// Handle the secrets of the environment named `environment`.
+    pub fn secrets(&self, environment: impl Into<String>) -> RepoEnvironmentSecretsHandler<'octo> {
+        RepoEnvironmentSecretsHandler {
+            handler: self.handler,
+            environment: environment.into(),
+        }
+    }
+}
+
+/// A client to GitHub's environment secrets API.
+///
+/// Created with [`RepoEnvironmentsHandler::secrets`].
+pub struct RepoEnvironmentSecretsHandler<'octo> {
+    handler: &'octo RepoHandler<'octo>,
+    environment: String,
+}
+
+impl<'octo> RepoEnvironmentSecretsHandler<'octo> {
+    fn route(&self) -> String {
+        format!(
+            "/{}/environments/{}/secrets",
+            self.handler.repo,
+            utf8_percent_encode(&self.environment, NON_ALPHANUMERIC)
+        )
+    }
+
+    /// Lists all secrets available in an environment without revealing their encrypted values.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let all_secrets = octocrab.repos("owner", "repo")
+    ///     .environments()
+    ///     .secrets("production")
+    ///     .list()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list(&self) -> crate::Result<crate::models::repos::secrets::RepositorySecrets> {
+        self.handler
+            .crab
+            .operation("repos.environments.secrets.list")
+            .get(self.route(), None::<&()>)
+            .await
+    }
+
+    /// Gets the public key of an environment, which you need to encrypt secrets.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let public_key = octocrab.repos("owner", "repo")
+    ///     .environments()
+    ///     .secrets("production")
+    ///     .get_public_key()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_public_key(&self) -> crate::Result<crate::models::PublicKey> {
+        let route = format!("{}/public-key", self.route());
+        self.handler
+            .crab
+            .operation("repos.environments.secrets.get_public_key")
+            .get(route, None::<&()>)
+            .await
+    }
+
+    /// Gets a single environment secret without revealing its encrypted value.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let secret_info = octocrab.repos("owner", "repo")
+    ///     .environments()
+    ///     .secrets("production")
+    ///     .get("TOKEN")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(
+        &self,
+        secret_name: impl AsRef<str>,
+    ) -> crate::Result<crate::models::repos::secrets::RepositorySecret> {
+        let route = format!("{}/{}", self.route(), secret_name.as_ref());
+        self.handler
+            .crab
+            .operation("repos.environments.secrets.get")
+            .get(route, None::<&()>)
+            .await
+    }
+
+    /// Creates or updates an environment secret with a value encrypted with
+    /// the environment's public key.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// use octocrab::models::repos::secrets::CreateRepositorySecretResponse;
+    ///
+    /// let result = octocrab.repos("owner", "repo")
+    ///     .environments()
+    ///     .secrets("production")
+    ///     .create_or_update("GH_TOKEN", "some-b64-encrypted-string", "123456")
+    ///     .await?;
+    ///
+    /// match result {
+    ///    CreateRepositorySecretResponse::Created => println!("Created secret!"),
+    ///    CreateRepositorySecretResponse::Updated => println!("Updated secret!"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_or_update(
+        &self,
+        secret_name: impl AsRef<str>,
+        encrypted_value: impl AsRef<str>,
+        key_id: impl AsRef<str>,
+    ) -> crate::Result<CreateRepositorySecretResponse> {
+        let route = format!("{}/{}", self.route(), secret_name.as_ref());
+        let secret = CreateRepositorySecret {
+            encrypted_value: encrypted_value.as_ref(),
+            key_id: key_id.as_ref(),
+        };
+
+        let resp = {
+            let resp = self
+                .handler
+                .crab
+                .operation("repos.environments.secrets.create_or_update")
+                ._put(route, Some(&secret))
+                .await?;
+            crate::map_github_error(resp).await?
+        };
+
+        match resp.status() {
+            StatusCode::CREATED => Ok(CreateRepositorySecretResponse::Created),
+            StatusCode::NO_CONTENT => Ok(CreateRepositorySecretResponse::Updated),
+            status_code => Err(crate::Error::Other {
+                source: format!(
+                    "Unexpected status code from request: {}",
+                    status_code.as_str()
+                )
+                .into(),
+                backtrace: snafu::Backtrace::capture(),
+            }),
+        }
+    }
+
+    /// Deletes an environment secret.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// octocrab.repos("owner", "repo")
+    ///     .environments()
+    ///     .secrets("production")
+    ///     .delete("GH_TOKEN")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(&self, secret_name: impl AsRef<str>) -> crate::Result<()> {
+        let route = format!("{}/{}", self.route(), secret_name.as_ref());
+
+        let resp = self
+            .handler
+            .crab
+            .operation("repos.environments.secrets.delete")
+            ._delete(route, None::<&()>)
+            .await?;
+        crate::map_github_error(resp).await?;
+        Ok(())
+    }
+}
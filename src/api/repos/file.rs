@@ -27,8 +27,8 @@ impl<'octo, 'r> GetContentBuilder<'octo, 'r> {
 
     /// The name of the commit/branch/tag.
     /// Default: the repository’s default branch (usually `master)
-    pub fn r#ref(mut self, r#ref: impl Into<String>) -> Self {
-        self.r#ref = Some(r#ref.into());
+    pub fn r#ref(mut self, r#ref: impl Into<params::repos::Commitish>) -> Self {
+        self.r#ref = Some(r#ref.into().0);
         self
     }
 
@@ -36,7 +36,11 @@ impl<'octo, 'r> GetContentBuilder<'octo, 'r> {
     pub async fn send(self) -> Result<models::repos::ContentItems> {
         let path = self.path.clone().unwrap_or(String::from(""));
         let route = format!("/{}/contents/{path}", self.handler.repo, path = path,);
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("repos.get_content")
+            .get(route, Some(&self))
+            .await
     }
 }
 
@@ -68,8 +72,8 @@ impl<'octo, 'r> GetReadmeBuilder<'octo, 'r> {
 
     /// The name of the commit/branch/tag.
     /// Default: the repository’s default branch (usually `master)
-    pub fn r#ref(mut self, r#ref: impl Into<String>) -> Self {
-        self.r#ref = Some(r#ref.into());
+    pub fn r#ref(mut self, r#ref: impl Into<params::repos::Commitish>) -> Self {
+        self.r#ref = Some(r#ref.into().0);
         self
     }
 
@@ -77,7 +81,11 @@ impl<'octo, 'r> GetReadmeBuilder<'octo, 'r> {
     pub async fn send(self) -> Result<models::repos::Content> {
         let path = self.path.clone().unwrap_or(String::from(""));
         let route = format!("/{}/readme/{path}", self.handler.repo, path = path,);
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("repos.get_readme")
+            .get(route, Some(&self))
+            .await
     }
 }
 
@@ -140,7 +148,11 @@ impl<'octo, 'r> UpdateFileBuilder<'octo, 'r> {
     /// Sends the actual request.
     pub async fn send(self) -> Result<models::repos::FileUpdate> {
         let route = format!("/{}/contents/{path}", self.handler.repo, path = self.path,);
-        self.handler.crab.put(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("repos.create_file")
+            .put(route, Some(&self))
+            .await
     }
 }
 
@@ -199,7 +211,11 @@ impl<'octo, 'r> DeleteFileBuilder<'octo, 'r> {
     /// Sends the actual request.
     pub async fn send(self) -> Result<models::repos::FileDeletion> {
         let route = format!("/{}/contents/{path}", self.handler.repo, path = self.path,);
-        self.handler.crab.delete(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("repos.delete_file")
+            .delete(route, Some(&self))
+            .await
     }
 }
 
@@ -251,6 +267,32 @@ mod tests {
         )
     }
 
+    #[tokio::test]
+    async fn serialize_get_content_ref() {
+        let octocrab = crate::instance();
+        let repo = octocrab.repos("owner", "repo");
+        let by_name = repo.get_content().path("README.md").r#ref("main");
+        let branch = String::from("main");
+        let by_string = repo.get_content().path("README.md").r#ref(&branch);
+        let by_reference = repo
+            .get_content()
+            .path("README.md")
+            .r#ref(&crate::params::repos::Reference::Tag("v1.0".to_string()));
+
+        assert_eq!(
+            serde_json::to_value(by_name).unwrap(),
+            serde_json::json!({ "ref": "main" })
+        );
+        assert_eq!(
+            serde_json::to_value(by_string).unwrap(),
+            serde_json::json!({ "ref": "main" })
+        );
+        assert_eq!(
+            serde_json::to_value(by_reference).unwrap(),
+            serde_json::json!({ "ref": "tags/v1.0" })
+        );
+    }
+
     #[tokio::test]
     async fn serialize_delete() {
         let octocrab = crate::instance();
@@ -44,7 +44,11 @@ impl<'octo, 'r> ListForksBuilder<'octo, 'r> {
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<crate::Page<crate::models::Repository>> {
         let route = format!("/{}/forks", self.handler.repo);
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("repos.list_forks")
+            .get(route, Some(&self))
+            .await
     }
 }
 #[derive(serde::Serialize)]
@@ -89,7 +93,11 @@ impl<'octo, 'r> CreateForkBuilder<'octo, 'r> {
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<crate::models::Repository> {
         let route = format!("/{}/forks", self.handler.repo);
-        self.handler.crab.post(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("repos.create_fork")
+            .post(route, Some(&self))
+            .await
     }
 }
 
@@ -67,7 +67,11 @@ impl<'octo, 'r> GenerateRepositoryBuilder<'octo, 'r> {
             "application/vnd.github.baptiste-preview+json",
         );
 
-        let request = self.handler.crab.build_request(request, Some(&self))?;
+        let request = self
+            .handler
+            .crab
+            .operation("repos.generate")
+            .build_request(request, Some(&self))?;
 
         let response = self.handler.crab.execute(request).await?;
         crate::map_github_error(response).await.map(drop)
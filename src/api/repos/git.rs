@@ -0,0 +1,381 @@
+use super::{CreateGitCommitObjectBuilder, RepoHandler};
+use crate::models::repos::git::{
+    Blob, BlobEncoding, CreateTreeEntry, TagObjectType, Tree, TreeEntryType,
+};
+use crate::{models, params};
+
+/// A client to GitHub's Git database API.
+///
+/// Created with [`RepoHandler::git`].
+pub struct RepoGitHandler<'octo> {
+    handler: &'octo RepoHandler<'octo>,
+}
+
+impl<'octo> RepoGitHandler<'octo> {
+    pub(crate) fn new(repo: &'octo RepoHandler<'octo>) -> Self {
+        Self { handler: repo }
+    }
+
+    /// Creates a new blob with the given `content`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// use octocrab::models::repos::git::BlobEncoding;
+    ///
+    /// let blob = octocrab.repos("owner", "repo")
+    ///     .git()
+    ///     .create_blob("Hello, world!", BlobEncoding::Utf8)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_blob(
+        &self,
+        content: impl Into<String>,
+        encoding: BlobEncoding,
+    ) -> crate::Result<Blob> {
+        let route = format!("/{}/git/blobs", self.handler.repo);
+        self.handler
+            .crab
+            .operation("repos.git.create_blob")
+            .post(
+                route,
+                Some(&serde_json::json!({
+                    "content": content.into(),
+                    "encoding": encoding,
+                })),
+            )
+            .await
+    }
+
+    /// Fetches the tree with the given `sha`, or the tree of the commit or
+    /// branch it names.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let tree = octocrab.repos("owner", "repo")
+    ///     .git()
+    ///     .get_tree("main")
+    ///     .recursive(true)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_tree(&self, sha: impl Into<String>) -> GetTreeBuilder<'octo> {
+        GetTreeBuilder {
+            handler: self.handler,
+            sha: sha.into(),
+            recursive: false,
+        }
+    }
+
+    /// Creates a new tree from `entries`. When `base_tree` is set the entries
+    /// are applied on top of it, otherwise the tree contains only `entries`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// use octocrab::models::repos::git::CreateTreeEntry;
+    ///
+    /// let tree = octocrab.repos("owner", "repo")
+    ///     .git()
+    ///     .create_tree(
+    ///         Some("9fb037999f264ba9a7fc6274d15fa3ae2ab98312".to_string()),
+    ///         vec![CreateTreeEntry::content("README.md", "# Hello")],
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_tree(
+        &self,
+        base_tree: Option<String>,
+        entries: Vec<CreateTreeEntry>,
+    ) -> crate::Result<Tree> {
+        let route = format!("/{}/git/trees", self.handler.repo);
+        let mut body = serde_json::json!({ "tree": entries });
+        if let Some(base_tree) = base_tree {
+            body["base_tree"] = serde_json::Value::String(base_tree);
+        }
+        self.handler
+            .crab
+            .operation("repos.git.create_tree")
+            .post(route, Some(&body))
+            .await
+    }
+
+    /// Creates a new commit pointing to `tree` with the given `parents`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let commit = octocrab.repos("owner", "repo")
+    ///     .git()
+    ///     .create_commit(
+    ///         "Update README",
+    ///         "cd8274d15fa3ae2ab983129fb037999f264ba9a7",
+    ///         vec!["7d1b31e74ee336d15cbd21741bc88a537ed063a0".to_string()],
+    ///     )
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_commit(
+        &self,
+        message: impl Into<String>,
+        tree: impl Into<String>,
+        parents: Vec<String>,
+    ) -> CreateGitCommitObjectBuilder<'_, '_> {
+        CreateGitCommitObjectBuilder::new(
+            self.handler,
+            self.handler.repo.clone(),
+            message.into(),
+            tree.into(),
+        )
+        .parents(parents)
+    }
+
+    /// Creates a new reference pointing to `sha`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// use octocrab::params::repos::Reference;
+    ///
+    /// octocrab.repos("owner", "repo")
+    ///     .git()
+    ///     .create_ref(
+    ///         &Reference::Branch("feature".to_string()),
+    ///         "7d1b31e74ee336d15cbd21741bc88a537ed063a0",
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_ref(
+        &self,
+        reference: &params::repos::Reference,
+        sha: impl Into<String>,
+    ) -> crate::Result<models::repos::Ref> {
+        self.handler.create_ref(reference, sha).await
+    }
+
+    /// Creates an annotated tag object named `tag` pointing to `object`. This
+    /// doesn't create the `refs/tags/{tag}` reference; use
+    /// [`RepoGitHandler::create_ref`] or [`RepoGitHandler::create_tag_and_ref`]
+    /// to publish it. When `tagger` is `None` the authenticated user is used.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// use octocrab::models::repos::git::TagObjectType;
+    ///
+    /// let tag = octocrab.repos("owner", "repo")
+    ///     .git()
+    ///     .create_tag(
+    ///         "v1.0.0",
+    ///         "Release 1.0.0",
+    ///         "7d1b31e74ee336d15cbd21741bc88a537ed063a0",
+    ///         TagObjectType::Commit,
+    ///         None,
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_tag(
+        &self,
+        tag: impl Into<String>,
+        message: impl Into<String>,
+        object: impl Into<String>,
+        r#type: TagObjectType,
+        tagger: Option<models::repos::CommitAuthor>,
+    ) -> crate::Result<models::repos::GitTag> {
+        #[derive(serde::Serialize)]
+        struct Inner {
+            tag: String,
+            message: String,
+            object: String,
+            r#type: TagObjectType,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            tagger: Option<models::repos::CommitAuthor>,
+        }
+
+        let route = format!("/{}/git/tags", self.handler.repo);
+        let body = Inner {
+            tag: tag.into(),
+            message: message.into(),
+            object: object.into(),
+            r#type,
+            tagger,
+        };
+        self.handler
+            .crab
+            .operation("repos.git.create_tag")
+            .post(route, Some(&body))
+            .await
+    }
+
+    /// Creates an annotated tag object and the `refs/tags/{tag}` reference
+    /// pointing to it, returning both.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// use octocrab::models::repos::git::TagObjectType;
+    ///
+    /// let (tag, reference) = octocrab.repos("owner", "repo")
+    ///     .git()
+    ///     .create_tag_and_ref(
+    ///         "v1.0.0",
+    ///         "Release 1.0.0",
+    ///         "7d1b31e74ee336d15cbd21741bc88a537ed063a0",
+    ///         TagObjectType::Commit,
+    ///         None,
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_tag_and_ref(
+        &self,
+        tag: impl Into<String>,
+        message: impl Into<String>,
+        object: impl Into<String>,
+        r#type: TagObjectType,
+        tagger: Option<models::repos::CommitAuthor>,
+    ) -> crate::Result<(models::repos::GitTag, models::repos::Ref)> {
+        let tag = self
+            .create_tag(tag, message, object, r#type, tagger)
+            .await?;
+        let reference = self
+            .create_ref(&params::repos::Reference::Tag(tag.tag.clone()), &tag.sha)
+            .await?;
+        Ok((tag, reference))
+    }
+
+    /// Updates an existing reference to point to `sha`. Unless `force` is
+    /// set, the update must be a fast-forward.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// use octocrab::params::repos::Reference;
+    ///
+    /// octocrab.repos("owner", "repo")
+    ///     .git()
+    ///     .update_ref(
+    ///         &Reference::Branch("main".to_string()),
+    ///         "7638417db6d59f3c431d3e1f261cc637155684cd",
+    ///         false,
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_ref(
+        &self,
+        reference: &params::repos::Reference,
+        sha: impl Into<String>,
+        force: bool,
+    ) -> crate::Result<models::repos::Ref> {
+        let route = format!(
+            "/{repo}/git/refs/{reference}",
+            repo = self.handler.repo,
+            reference = reference.git_ref_path()?,
+        );
+        self.handler
+            .crab
+            .operation("repos.git.update_ref")
+            .patch(
+                route,
+                Some(&serde_json::json!({
+                    "sha": sha.into(),
+                    "force": force,
+                })),
+            )
+            .await
+    }
+}
+
+/// A builder pattern struct for fetching a tree.
+///
+/// Created by [`RepoGitHandler::get_tree`].
+pub struct GetTreeBuilder<'octo> {
+    handler: &'octo RepoHandler<'octo>,
+    sha: String,
+    recursive: bool,
+}
+
+impl<'octo> GetTreeBuilder<'octo> {
+    /// Whether to include the entries of all subtrees as well. Recursive
+    /// listings are truncated by GitHub for very large trees, see
+    /// [`Tree::truncated`] and [`GetTreeBuilder::send_complete`].
+    pub fn recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Tree> {
+        self.fetch(&self.sha, self.recursive).await
+    }
+
+    /// Fetches every entry of the tree and its subtrees. Whenever a recursive
+    /// listing comes back truncated, that tree is listed non-recursively and
+    /// each of its subtrees is fetched separately, so the result is never
+    /// truncated.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let tree = octocrab.repos("owner", "repo")
+    ///     .git()
+    ///     .get_tree("main")
+    ///     .send_complete()
+    ///     .await?;
+    /// assert!(!tree.truncated);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_complete(self) -> crate::Result<Tree> {
+        let mut root = self.fetch(&self.sha, true).await?;
+        if !root.truncated {
+            return Ok(root);
+        }
+
+        let mut entries = Vec::new();
+        let mut pending = vec![(root.sha.clone(), String::new(), false)];
+        while let Some((sha, prefix, recursive)) = pending.pop() {
+            let tree = self.fetch(&sha, recursive).await?;
+            if recursive && tree.truncated {
+                pending.push((sha, prefix, false));
+                continue;
+            }
+
+            for mut entry in tree.tree {
+                entry.path = format!("{prefix}{}", entry.path);
+                if !recursive && entry.r#type == TreeEntryType::Tree {
+                    pending.push((entry.sha.clone(), format!("{}/", entry.path), true));
+                }
+                entries.push(entry);
+            }
+        }
+
+        root.tree = entries;
+        root.truncated = false;
+        Ok(root)
+    }
+
+    async fn fetch(&self, sha: &str, recursive: bool) -> crate::Result<Tree> {
+        let route = format!("/{}/git/trees/{sha}", self.handler.repo);
+        let parameters = recursive.then_some(GetTreeParameters { recursive: 1 });
+        self.handler
+            .crab
+            .operation("repos.git.get_tree.fetch")
+            .get(route, parameters.as_ref())
+            .await
+    }
+}
+
+/// GitHub treats any value of `recursive` as `true`, so it is only sent when
+/// set.
+#[derive(serde::Serialize)]
+struct GetTreeParameters {
+    recursive: u8,
+}
@@ -0,0 +1,140 @@
+use super::RepoHandler;
+use crate::models::repos::{PageBuild, PageBuildStatus, Pages};
+use crate::Page;
+
+/// A client to GitHub's Pages API.
+///
+/// Created with [`RepoHandler::pages`].
+pub struct RepoPagesHandler<'octo> {
+    handler: &'octo RepoHandler<'octo>,
+}
+
+impl<'octo> RepoPagesHandler<'octo> {
+    pub(crate) fn new(repo: &'octo RepoHandler<'octo>) -> Self {
+        Self { handler: repo }
+    }
+
+    /// Fetches information about the GitHub Pages site of the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let pages = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .pages()
+    ///     .get()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self) -> crate::Result<Pages> {
+        let route = format!("/{}/pages", self.handler.repo);
+        self.handler
+            .crab
+            .operation("repos.pages.get")
+            .get(route, None::<&()>)
+            .await
+    }
+
+    /// Lists the builds of the GitHub Pages site.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let builds = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .pages()
+    ///     .list_builds()
+    ///     .per_page(50)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_builds(&self) -> ListPageBuildsBuilder<'octo, '_> {
+        ListPageBuildsBuilder::new(self)
+    }
+
+    /// Fetches the latest build of the GitHub Pages site.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let build = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .pages()
+    ///     .latest_build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn latest_build(&self) -> crate::Result<PageBuild> {
+        let route = format!("/{}/pages/builds/latest", self.handler.repo);
+        self.handler
+            .crab
+            .operation("repos.pages.latest_build")
+            .get(route, None::<&()>)
+            .await
+    }
+
+    /// Requests a build of the GitHub Pages site from the latest revision of
+    /// its source branch, without needing to push a commit.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let status = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .pages()
+    ///     .request_build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn request_build(&self) -> crate::Result<PageBuildStatus> {
+        let route = format!("/{}/pages/builds", self.handler.repo);
+        self.handler
+            .crab
+            .operation("repos.pages.request_build")
+            .post(route, None::<&()>)
+            .await
+    }
+}
+
+/// A builder pattern struct for listing GitHub Pages builds.
+///
+/// Created by [`RepoPagesHandler::list_builds`].
+#[derive(serde::Serialize)]
+pub struct ListPageBuildsBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoPagesHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListPageBuildsBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoPagesHandler<'octo>) -> Self {
+        Self {
+            handler,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Page<PageBuild>> {
+        let route = format!("/{}/pages/builds", self.handler.handler.repo);
+        self.handler
+            .handler
+            .crab
+            .operation("repos.pages.list_builds")
+            .get(route, Some(&self))
+            .await
+    }
+}
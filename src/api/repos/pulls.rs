@@ -37,6 +37,10 @@ impl<'octo, 'r> ListPullsBuilder<'octo, 'r> {
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<crate::Page<crate::models::pulls::PullRequest>> {
         let route = format!("/{}/commits/{sha}/pulls", self.handler.repo, sha = self.sha,);
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("repos.list_pulls")
+            .get(route, Some(&self))
+            .await
     }
 }
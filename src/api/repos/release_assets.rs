@@ -25,7 +25,11 @@ impl<'octo, 'r> ReleaseAssetsHandler<'octo, 'r> {
     pub async fn get(&self, id: u64) -> Result<models::repos::Asset> {
         let route = format!("/{}/releases/assets/{id}", self.handler.repo, id = id,);
 
-        self.handler.crab.get(route, None::<&()>).await
+        self.handler
+            .crab
+            .operation("repos.release_assets.get")
+            .get(route, None::<&()>)
+            .await
     }
     /// Creates a new [`UpdateReleaseAssetBuilder`] with `asset_id`.
     /// ```no_run
@@ -62,7 +66,11 @@ impl<'octo, 'r> ReleaseAssetsHandler<'octo, 'r> {
     pub async fn delete(&self, id: u64) -> Result<()> {
         let route = format!("/{}/releases/assets/{id}", self.handler.repo, id = id,);
 
-        self.handler.crab._delete(route, None::<&()>).await?;
+        self.handler
+            .crab
+            .operation("repos.release_assets.delete")
+            ._delete(route, None::<&()>)
+            .await?;
         Ok(())
     }
 
@@ -102,7 +110,11 @@ impl<'octo, 'r> ReleaseAssetsHandler<'octo, 'r> {
             .method(http::Method::GET)
             .uri(uri)
             .header(http::header::ACCEPT, "application/octet-stream");
-        let request = self.handler.crab.build_request(builder, None::<&()>)?;
+        let request = self
+            .handler
+            .crab
+            .operation("repos.release_assets.stream")
+            .build_request(builder, None::<&()>)?;
         let response = self.handler.crab.execute(request).await?;
         let response = self.handler.crab.follow_location_to_data(response).await?;
         Ok(http_body_util::BodyStream::new(response.into_body())
@@ -171,6 +183,11 @@ impl<'octo, 'repos, 'handler, 'name, 'label, 'state>
             repo = self.handler.handler.repo,
             asset_id = self.asset_id,
         );
-        self.handler.handler.crab.patch(route, Some(&self)).await
+        self.handler
+            .handler
+            .crab
+            .operation("repos.release_assets.update")
+            .patch(route, Some(&self))
+            .await
     }
 }
@@ -104,6 +104,12 @@ impl<'octo, 'r> ReleasesHandler<'octo, 'r> {
     }
 
     /// Gets the latest release.
+    ///
+    /// This is GitHub's notion of "latest": the most recent non-prerelease,
+    /// non-draft release, or the release explicitly marked as latest. It
+    /// fails with a `404` if there is no such release, even when the
+    /// repository has prereleases. Use [`ReleasesHandler::latest`] to include
+    /// prereleases.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
     /// let release = octocrab::instance()
@@ -117,7 +123,73 @@ impl<'octo, 'r> ReleasesHandler<'octo, 'r> {
     pub async fn get_latest(&self) -> crate::Result<models::repos::Release> {
         let route = format!("/{}/releases/latest", self.handler.repo,);
 
-        self.handler.crab.get(route, None::<&()>).await
+        self.handler
+            .crab
+            .operation("repos.releases.get_latest")
+            .get(route, None::<&()>)
+            .await
+    }
+
+    /// Gets the latest release, returning `None` if there is none.
+    ///
+    /// With [`Prereleases::Exclude`](params::repos::releases::Prereleases::Exclude)
+    /// this is [`ReleasesHandler::get_latest`], i.e. GitHub's
+    /// `/releases/latest`, which never returns drafts or prereleases. With
+    /// [`Prereleases::Include`](params::repos::releases::Prereleases::Include),
+    /// releases are listed 100 at a time until a page contains a non-draft
+    /// release, and the one with the newest `published_at` on that page is
+    /// returned, whether it is a prerelease or not. A repository that doesn't
+    /// exist is still an error.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::repos::releases::Prereleases;
+    ///
+    /// let newest = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .releases()
+    ///     .latest(Prereleases::Include)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn latest(
+        &self,
+        prereleases: params::repos::releases::Prereleases,
+    ) -> crate::Result<Option<models::repos::Release>> {
+        if prereleases == params::repos::releases::Prereleases::Exclude {
+            let route = format!("/{}/releases/latest", self.handler.repo);
+            let response = self
+                .handler
+                .crab
+                .operation("repos.releases.latest")
+                ._get(route)
+                .await?;
+            if response.status() != http::StatusCode::NOT_FOUND {
+                return FromResponse::from_response(crate::map_github_error(response).await?)
+                    .await
+                    .map(Some);
+            }
+            // `/releases/latest` also 404s for repositories that don't exist,
+            // so only report "no release" once the repository is known to.
+            self.list().per_page(1u8).send().await?;
+            return Ok(None);
+        }
+
+        let mut page = self.list().per_page(100u8).send().await?;
+        loop {
+            let latest = page
+                .take_items()
+                .into_iter()
+                .filter(|release| !release.draft)
+                .max_by_key(|release| release.published_at);
+            if latest.is_some() {
+                return Ok(latest);
+            }
+            match self.handler.crab.get_page(&page.next).await? {
+                Some(next_page) => page = next_page,
+                None => return Ok(None),
+            }
+        }
     }
 
     /// Gets the release using its tag.
@@ -134,7 +206,11 @@ impl<'octo, 'r> ReleasesHandler<'octo, 'r> {
     pub async fn get_by_tag(&self, tag: &str) -> crate::Result<models::repos::Release> {
         let route = format!("/{}/releases/tags/{tag}", self.handler.repo, tag = tag,);
 
-        self.handler.crab.get(route, None::<&()>).await
+        self.handler
+            .crab
+            .operation("repos.releases.get_by_tag")
+            .get(route, None::<&()>)
+            .await
     }
 
     /// Gets the release using its id.
@@ -151,7 +227,11 @@ impl<'octo, 'r> ReleasesHandler<'octo, 'r> {
     pub async fn get(&self, number: u64) -> Result<models::repos::Release> {
         let route = format!("/{}/releases/{number}", self.handler.repo, number = number,);
 
-        self.handler.crab.get(route, None::<&()>).await
+        self.handler
+            .crab
+            .operation("repos.releases.get")
+            .get(route, None::<&()>)
+            .await
     }
 
     /// Generates [`crate::models::repos::ReleaseNotes`] which describe
@@ -261,7 +341,11 @@ impl<'octo, 'r> ReleasesHandler<'octo, 'r> {
     pub async fn delete(&self, id: u64) -> Result<()> {
         let route = format!("/{}/releases/{id}", self.handler.repo, id = id,);
 
-        self.handler.crab._delete(route, None::<&()>).await?;
+        self.handler
+            .crab
+            .operation("repos.releases.delete")
+            ._delete(route, None::<&()>)
+            .await?;
         Ok(())
     }
 }
@@ -303,7 +387,12 @@ impl<'octo, 'r1, 'r2> ListReleasesBuilder<'octo, 'r1, 'r2> {
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<crate::Page<crate::models::repos::Release>> {
         let route = format!("/{}/releases", self.handler.handler.repo);
-        self.handler.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .handler
+            .crab
+            .operation("repos.releases.list")
+            .get(route, Some(&self))
+            .await
     }
 }
 
@@ -417,7 +506,12 @@ impl<'octo, 'repos, 'handler, 'tag_name, 'target_commitish, 'name, 'body>
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<crate::models::repos::Release> {
         let route = format!("/{}/releases", self.handler.handler.repo);
-        self.handler.handler.crab.post(route, Some(&self)).await
+        self.handler
+            .handler
+            .crab
+            .operation("repos.releases.create")
+            .post(route, Some(&self))
+            .await
     }
 }
 
@@ -520,7 +614,12 @@ impl<'octo, 'repos, 'handler, 'tag_name, 'target_commitish, 'name, 'body>
             repo = self.handler.handler.repo,
             release_id = self.release_id,
         );
-        self.handler.handler.crab.patch(route, Some(&self)).await
+        self.handler
+            .handler
+            .crab
+            .operation("repos.releases.update")
+            .patch(route, Some(&self))
+            .await
     }
 }
 
@@ -614,8 +713,13 @@ impl<
     pub async fn send(self) -> crate::Result<crate::models::repos::ReleaseNotes> {
         let route = format!("/{}/releases/generate-notes", self.handler.handler.repo,);
 
-        let result: Result<crate::models::repos::ReleaseNotes> =
-            self.handler.handler.crab.post(route, Some(&self)).await;
+        let result: Result<crate::models::repos::ReleaseNotes> = self
+            .handler
+            .handler
+            .crab
+            .operation("repos.releases.generate_release_notes")
+            .post(route, Some(&self))
+            .await;
         result
     }
 }
@@ -664,7 +768,12 @@ impl<'octo, 'r1, 'r2> ListReleaseAssetsBuilder<'octo, 'r1, 'r2> {
             repo = self.handler.handler.repo,
             release_id = self.release_id,
         );
-        self.handler.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .handler
+            .crab
+            .operation("repos.releases.assets")
+            .get(route, Some(&self))
+            .await
     }
 }
 
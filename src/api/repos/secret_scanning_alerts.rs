@@ -17,8 +17,10 @@ struct Params {
     #[serde(skip_serializing_if = "Option::is_none")]
     state: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "crate::api::comma_separated")]
     resolution: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "crate::api::comma_separated")]
     validity: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     sort: Option<String>,
@@ -74,7 +76,11 @@ impl<'octo> RepoSecretScanningAlertsHandler<'octo> {
     ) -> crate::Result<crate::Page<crate::models::repos::secret_scanning_alert::SecretScanningAlert>>
     {
         let route = format!("/{}/secret-scanning/alerts", self.handler.repo);
-        self.handler.crab.get(route, Some(&self.params)).await
+        self.handler
+            .crab
+            .operation("repos.secret_scanning_alerts.get_alerts")
+            .get(route, Some(&self.params))
+            .await
     }
 
     /// Results per page (max 100).
@@ -168,7 +174,11 @@ impl<'octo> RepoSecretScanningAlertsHandler<'octo> {
             "/{}/secret-scanning/alerts/{}",
             self.handler.repo, alert_number
         );
-        self.handler.crab.get(route, None::<&()>).await
+        self.handler
+            .crab
+            .operation("repos.secret_scanning_alerts.get_alert")
+            .get(route, None::<&()>)
+            .await
     }
 
     /// Updates a Secret Scanning alert.
@@ -202,10 +212,14 @@ impl<'octo> RepoSecretScanningAlertsHandler<'octo> {
             "/{}/secret-scanning/alerts/{}",
             self.handler.repo, alert_number
         );
-        self.handler.crab.patch(route, alert_update).await
+        self.handler
+            .crab
+            .operation("repos.secret_scanning_alerts.update_alert")
+            .patch(route, alert_update)
+            .await
     }
 
-    // Get a Secret Scanning alert locations.
+    /// Get a Secret Scanning alert locations.
     /// You must authenticate using an access token with the `repo` or `security_events ` scope to use this endpoint.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
@@ -230,6 +244,10 @@ impl<'octo> RepoSecretScanningAlertsHandler<'octo> {
             "/{}/secret-scanning/alerts/{}/locations",
             self.handler.repo, alert_number
         );
-        self.handler.crab.get(route, None::<&()>).await
+        self.handler
+            .crab
+            .operation("repos.secret_scanning_alerts.get_alert_locations")
+            .get(route, None::<&()>)
+            .await
     }
 }
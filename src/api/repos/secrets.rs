@@ -32,7 +32,11 @@ impl<'octo> RepoSecretsHandler<'octo> {
         &self,
     ) -> crate::Result<crate::models::repos::secrets::RepositorySecrets> {
         let route = format!("/{}/actions/secrets", self.handler.repo);
-        self.handler.crab.get(route, None::<&()>).await
+        self.handler
+            .crab
+            .operation("repos.secrets.get_secrets")
+            .get(route, None::<&()>)
+            .await
     }
 
     /// Gets your public key, which you need to encrypt secrets.
@@ -51,7 +55,11 @@ impl<'octo> RepoSecretsHandler<'octo> {
     /// # }
     pub async fn get_public_key(&self) -> crate::Result<crate::models::PublicKey> {
         let route = format!("/{}/actions/secrets/public-key", self.handler.repo);
-        self.handler.crab.get(route, None::<&()>).await
+        self.handler
+            .crab
+            .operation("repos.secrets.get_public_key")
+            .get(route, None::<&()>)
+            .await
     }
 
     /// Gets a single repository secret without revealing its encrypted value.
@@ -75,7 +83,11 @@ impl<'octo> RepoSecretsHandler<'octo> {
             self.handler.repo,
             secret_name = secret_name.as_ref()
         );
-        self.handler.crab.get(route, None::<&()>).await
+        self.handler
+            .crab
+            .operation("repos.secrets.get_secret")
+            .get(route, None::<&()>)
+            .await
     }
 
     /// Creates or updates a repository secret with an encrypted value.
@@ -113,7 +125,12 @@ impl<'octo> RepoSecretsHandler<'octo> {
         );
 
         let resp = {
-            let resp = self.handler.crab._put(route, Some(secret)).await?;
+            let resp = self
+                .handler
+                .crab
+                .operation("repos.secrets.create_or_update_secret")
+                ._put(route, Some(secret))
+                .await?;
             crate::map_github_error(resp).await?
         };
 
@@ -151,7 +168,12 @@ impl<'octo> RepoSecretsHandler<'octo> {
             secret_name = secret_name.as_ref()
         );
 
-        let resp = self.handler.crab._delete(route, None::<&()>).await?;
+        let resp = self
+            .handler
+            .crab
+            .operation("repos.secrets.delete_secret")
+            ._delete(route, None::<&()>)
+            .await?;
         crate::map_github_error(resp).await?;
         Ok(())
     }
@@ -40,6 +40,7 @@ impl<'octo, 'r> ListStarGazersBuilder<'octo, 'r> {
 
         self.handler
             .crab
+            .operation("repos.list_stargazers")
             .get_with_headers(route, Some(&self), Some(headers))
             .await
     }
@@ -0,0 +1,146 @@
+use std::time::Duration;
+
+use http::{StatusCode, Uri};
+use snafu::ResultExt;
+
+use super::RepoHandler;
+use crate::error::HttpSnafu;
+use crate::models::repos::stats::{
+    ContributorStats, Participation, WeeklyCodeFrequency, WeeklyCommitActivity,
+};
+use crate::FromResponse;
+
+/// A client to GitHub's repository statistics API.
+///
+/// GitHub computes statistics in the background and answers with
+/// `202 Accepted` until they're ready, so every request is retried with an
+/// exponential backoff until the data is available or the attempts run out.
+///
+/// Created with [`RepoHandler::stats`].
+pub struct RepoStatsHandler<'octo> {
+    handler: &'octo RepoHandler<'octo>,
+    max_attempts: u32,
+    backoff: Duration,
+}
+
+impl<'octo> RepoStatsHandler<'octo> {
+    pub(crate) fn new(handler: &'octo RepoHandler<'octo>) -> Self {
+        Self {
+            handler,
+            max_attempts: 5,
+            backoff: Duration::from_secs(1),
+        }
+    }
+
+    /// How many times to request the statistics at most. Default: 5.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// How long to wait before the first retry, doubling after each further
+    /// attempt. Default: one second.
+    pub fn backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Fetches the number of commits, additions and deletions per week of
+    /// each contributor.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let contributors = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .stats()
+    ///     .contributors()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn contributors(&self) -> crate::Result<Vec<ContributorStats>> {
+        self.get("contributors").await
+    }
+
+    /// Fetches the number of commits per day of the last year, grouped by week.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let activity = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .stats()
+    ///     .commit_activity()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn commit_activity(&self) -> crate::Result<Vec<WeeklyCommitActivity>> {
+        self.get("commit_activity").await
+    }
+
+    /// Fetches the number of additions and deletions per week.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let frequency = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .stats()
+    ///     .code_frequency()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn code_frequency(&self) -> crate::Result<Vec<WeeklyCodeFrequency>> {
+        self.get("code_frequency").await
+    }
+
+    /// Fetches the weekly commit counts of the last year, for everyone and
+    /// for the repository's owner.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let participation = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .stats()
+    ///     .participation()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn participation(&self) -> crate::Result<Participation> {
+        self.get("participation").await
+    }
+
+    async fn get<T: FromResponse + Default>(&self, statistic: &str) -> crate::Result<T> {
+        let route = format!("/{}/stats/{statistic}", self.handler.repo);
+        let mut backoff = self.backoff;
+        let mut attempt = 1;
+        loop {
+            let uri = Uri::builder()
+                .path_and_query(route.as_str())
+                .build()
+                .context(HttpSnafu)?;
+            let response = self
+                .handler
+                .crab
+                .operation("repos.stats.get")
+                ._get(uri)
+                .await?;
+            match response.status() {
+                StatusCode::ACCEPTED if attempt < self.max_attempts => {
+                    attempt += 1;
+                    tokio::time::sleep(backoff).await;
+                    backoff *= 2;
+                }
+                StatusCode::ACCEPTED => {
+                    return Err(crate::Error::Other {
+                        source: format!(
+                            "statistics were still being computed after {attempt} attempts"
+                        )
+                        .into(),
+                        backtrace: snafu::Backtrace::capture(),
+                    })
+                }
+                // Returned for repositories without any commits.
+                StatusCode::NO_CONTENT => return Ok(T::default()),
+                _ => return T::from_response(crate::map_github_error(response).await?).await,
+            }
+        }
+    }
+}
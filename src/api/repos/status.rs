@@ -62,8 +62,16 @@ impl<'octo, 'r> CreateStatusBuilder<'octo, 'r> {
 
     /// Sends the actual request.
     pub async fn send(self) -> Result<Status> {
-        let route = format!("/{}/statuses/{sha}", self.handler.repo, sha = self.sha);
-        self.handler.crab.post(route, Some(&self)).await
+        let route = format!(
+            "/{}/statuses/{sha}",
+            self.handler.repo,
+            sha = params::repos::encode_ref_path(&self.sha)
+        );
+        self.handler
+            .crab
+            .operation("repos.create_status")
+            .post(route, Some(&self))
+            .await
     }
 }
 
@@ -106,8 +114,12 @@ impl<'octo, 'r> ListStatusesBuilder<'octo, 'r> {
         let route = format!(
             "/{repo}/commits/{sha}/statuses",
             repo = self.handler.repo,
-            sha = self.sha,
+            sha = params::repos::encode_ref_path(&self.sha),
         );
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("repos.list_statuses")
+            .get(route, Some(&self))
+            .await
     }
 }
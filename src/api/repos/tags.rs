@@ -34,6 +34,10 @@ impl<'octo, 'r> ListTagsBuilder<'octo, 'r> {
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<crate::Page<crate::models::repos::Tag>> {
         let route = format!("/{}/tags", self.handler.repo);
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("repos.list_tags")
+            .get(route, Some(&self))
+            .await
     }
 }
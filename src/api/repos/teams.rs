@@ -34,6 +34,10 @@ impl<'octo, 'r> ListTeamsBuilder<'octo, 'r> {
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<crate::Page<crate::models::teams::Team>> {
         let route = format!("/{}/teams", self.handler.repo);
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("repos.list_teams")
+            .get(route, Some(&self))
+            .await
     }
 }
@@ -0,0 +1,144 @@
+use super::*;
+
+/// A builder pattern struct for updating a repository's settings.
+///
+/// Created with [`RepoHandler::update`].
+#[derive(serde::Serialize)]
+pub struct UpdateRepositoryBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    homepage: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    private: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visibility: Option<params::repos::Visibility>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_branch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    has_issues: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    has_wiki: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow_squash_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    delete_branch_on_merge: Option<bool>,
+}
+
+impl<'octo, 'r> UpdateRepositoryBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>) -> Self {
+        Self {
+            handler,
+            name: None,
+            description: None,
+            homepage: None,
+            private: None,
+            visibility: None,
+            default_branch: None,
+            has_issues: None,
+            has_wiki: None,
+            allow_squash_merge: None,
+            delete_branch_on_merge: None,
+        }
+    }
+
+    /// Renames the repository.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// A short description of the repository.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// A URL with more information about the repository.
+    pub fn homepage(mut self, homepage: impl Into<String>) -> Self {
+        self.homepage = Some(homepage.into());
+        self
+    }
+
+    /// Whether the repository is private.
+    pub fn private(mut self, private: impl Into<bool>) -> Self {
+        self.private = Some(private.into());
+        self
+    }
+
+    /// The visibility of the repository.
+    pub fn visibility(mut self, visibility: impl Into<params::repos::Visibility>) -> Self {
+        self.visibility = Some(visibility.into());
+        self
+    }
+
+    /// Updates the default branch of the repository.
+    pub fn default_branch(mut self, default_branch: impl Into<String>) -> Self {
+        self.default_branch = Some(default_branch.into());
+        self
+    }
+
+    /// Whether issues are enabled.
+    pub fn has_issues(mut self, has_issues: impl Into<bool>) -> Self {
+        self.has_issues = Some(has_issues.into());
+        self
+    }
+
+    /// Whether the wiki is enabled.
+    pub fn has_wiki(mut self, has_wiki: impl Into<bool>) -> Self {
+        self.has_wiki = Some(has_wiki.into());
+        self
+    }
+
+    /// Whether pull requests can be squash-merged.
+    pub fn allow_squash_merge(mut self, allow_squash_merge: impl Into<bool>) -> Self {
+        self.allow_squash_merge = Some(allow_squash_merge.into());
+        self
+    }
+
+    /// Whether head branches are deleted automatically when pull requests
+    /// are merged.
+    pub fn delete_branch_on_merge(mut self, delete_branch_on_merge: impl Into<bool>) -> Self {
+        self.delete_branch_on_merge = Some(delete_branch_on_merge.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<models::Repository> {
+        let route = format!("/{}", self.handler.repo);
+        self.handler
+            .crab
+            .operation("repos.update")
+            .patch(route, Some(&self))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[tokio::test]
+    async fn serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.repos("owner", "repo");
+        let update = handler
+            .update()
+            .description("A new description")
+            .visibility(crate::params::repos::Visibility::Internal)
+            .has_wiki(false)
+            .delete_branch_on_merge(true);
+
+        assert_eq!(
+            serde_json::to_value(update).unwrap(),
+            serde_json::json!({
+                "description": "A new description",
+                "visibility": "internal",
+                "has_wiki": false,
+                "delete_branch_on_merge": true,
+            })
+        )
+    }
+}
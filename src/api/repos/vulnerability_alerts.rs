@@ -0,0 +1,197 @@
+use http::StatusCode;
+
+use super::RepoHandler;
+use crate::models::repos::AutomatedSecurityFixes;
+
+/// A client to GitHub's repository vulnerability alerts API.
+///
+/// Created with [`RepoHandler::vulnerability_alerts`].
+pub struct RepoVulnerabilityAlertsHandler<'octo> {
+    handler: &'octo RepoHandler<'octo>,
+}
+
+impl<'octo> RepoVulnerabilityAlertsHandler<'octo> {
+    pub(crate) fn new(repo: &'octo RepoHandler<'octo>) -> Self {
+        Self { handler: repo }
+    }
+
+    fn route(&self) -> String {
+        format!("/{}/vulnerability-alerts", self.handler.repo)
+    }
+
+    /// Checks whether dependency alerts are enabled for the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let enabled = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .vulnerability_alerts()
+    ///     .is_enabled()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn is_enabled(&self) -> crate::Result<bool> {
+        let response = self
+            .handler
+            .crab
+            .operation("repos.vulnerability_alerts.is_enabled")
+            ._get(self.route())
+            .await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(false);
+        }
+        crate::map_github_error(response).await.map(|_| true)
+    }
+
+    /// Enables dependency alerts for the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .vulnerability_alerts()
+    ///     .enable()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn enable(&self) -> crate::Result<()> {
+        crate::map_github_error(
+            self.handler
+                .crab
+                .operation("repos.vulnerability_alerts.enable")
+                ._put(self.route(), None::<&()>)
+                .await?,
+        )
+        .await
+        .map(drop)
+    }
+
+    /// Disables dependency alerts for the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .vulnerability_alerts()
+    ///     .disable()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn disable(&self) -> crate::Result<()> {
+        crate::map_github_error(
+            self.handler
+                .crab
+                .operation("repos.vulnerability_alerts.disable")
+                ._delete(self.route(), None::<&()>)
+                .await?,
+        )
+        .await
+        .map(drop)
+    }
+}
+
+/// A client to GitHub's automated security fixes (Dependabot security
+/// updates) API.
+///
+/// Created with [`RepoHandler::automated_security_fixes`].
+pub struct RepoAutomatedSecurityFixesHandler<'octo> {
+    handler: &'octo RepoHandler<'octo>,
+}
+
+impl<'octo> RepoAutomatedSecurityFixesHandler<'octo> {
+    pub(crate) fn new(repo: &'octo RepoHandler<'octo>) -> Self {
+        Self { handler: repo }
+    }
+
+    fn route(&self) -> String {
+        format!("/{}/automated-security-fixes", self.handler.repo)
+    }
+
+    /// Fetches the automated security fixes settings of the repository.
+    /// Returns `None` if dependency alerts aren't enabled.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let settings = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .automated_security_fixes()
+    ///     .get()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self) -> crate::Result<Option<AutomatedSecurityFixes>> {
+        let response = self
+            .handler
+            .crab
+            .operation("repos.automated_security_fixes.get")
+            ._get(self.route())
+            .await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        let response = crate::map_github_error(response).await?;
+        crate::FromResponse::from_response(response).await.map(Some)
+    }
+
+    /// Checks whether automated security fixes are enabled for the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let enabled = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .automated_security_fixes()
+    ///     .is_enabled()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn is_enabled(&self) -> crate::Result<bool> {
+        Ok(self.get().await?.is_some_and(|settings| settings.enabled))
+    }
+
+    /// Enables automated security fixes for the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .automated_security_fixes()
+    ///     .enable()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn enable(&self) -> crate::Result<()> {
+        crate::map_github_error(
+            self.handler
+                .crab
+                .operation("repos.automated_security_fixes.enable")
+                ._put(self.route(), None::<&()>)
+                .await?,
+        )
+        .await
+        .map(drop)
+    }
+
+    /// Disables automated security fixes for the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .automated_security_fixes()
+    ///     .disable()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn disable(&self) -> crate::Result<()> {
+        crate::map_github_error(
+            self.handler
+                .crab
+                .operation("repos.automated_security_fixes.disable")
+                ._delete(self.route(), None::<&()>)
+                .await?,
+        )
+        .await
+        .map(drop)
+    }
+}
@@ -31,7 +31,12 @@ impl<'octo> SearchHandler<'octo> {
         self,
         query: &'query (impl AsRef<str> + ?Sized),
     ) -> QueryHandler<'octo, 'query, models::Repository> {
-        QueryHandler::new(self.crab, "repositories", query.as_ref())
+        QueryHandler::new(
+            self.crab,
+            "repositories",
+            "search.repositories",
+            query.as_ref(),
+        )
     }
 
     /// Searches for all the commits matching the search query.
@@ -51,7 +56,7 @@ impl<'octo> SearchHandler<'octo> {
         self,
         query: &'query (impl AsRef<str> + ?Sized),
     ) -> QueryHandler<'octo, 'query, models::repos::Commit> {
-        QueryHandler::new(self.crab, "commits", query.as_ref())
+        QueryHandler::new(self.crab, "commits", "search.commits", query.as_ref())
     }
 
     /// Searches for all users matching the search query.
@@ -71,7 +76,7 @@ impl<'octo> SearchHandler<'octo> {
         self,
         query: &'query (impl AsRef<str> + ?Sized),
     ) -> QueryHandler<'octo, 'query, models::Author> {
-        QueryHandler::new(self.crab, "users", query.as_ref())
+        QueryHandler::new(self.crab, "users", "search.users", query.as_ref())
     }
 
     /// Searches for all the issues matching the search query.
@@ -91,7 +96,12 @@ impl<'octo> SearchHandler<'octo> {
         self,
         query: &'query (impl AsRef<str> + ?Sized),
     ) -> QueryHandler<'octo, 'query, models::issues::Issue> {
-        QueryHandler::new(self.crab, "issues", query.as_ref())
+        QueryHandler::new(
+            self.crab,
+            "issues",
+            "search.issues_and_pull_requests",
+            query.as_ref(),
+        )
     }
 
     /// Searches for all code matching the search query.
@@ -111,7 +121,27 @@ impl<'octo> SearchHandler<'octo> {
         self,
         query: &'query (impl AsRef<str> + ?Sized),
     ) -> QueryHandler<'octo, 'query, models::Code> {
-        QueryHandler::new(self.crab, "code", query.as_ref())
+        QueryHandler::new(self.crab, "code", "search.code", query.as_ref())
+    }
+
+    /// Searches for all topics matching the search query.
+    /// ```no_run
+    ///# async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance()
+    ///     .search()
+    ///     .topics("ruby is:featured")
+    ///     .send()
+    ///     .await?;
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn topics<'query>(
+        self,
+        query: &'query (impl AsRef<str> + ?Sized),
+    ) -> QueryHandler<'octo, 'query, models::Topic> {
+        let mut handler = QueryHandler::new(self.crab, "topics", "search.topics", query.as_ref());
+        handler.preview = Some("application/vnd.github.mercy-preview+json");
+        handler
     }
 }
 
@@ -124,6 +154,8 @@ pub struct QueryHandler<'octo, 'query, T> {
     crab: &'octo Octocrab,
     #[serde(skip)]
     route: &'static str,
+    #[serde(skip)]
+    operation: &'static str,
     #[serde(rename = "q")]
     query: &'query str,
     per_page: Option<u8>,
@@ -132,12 +164,22 @@ pub struct QueryHandler<'octo, 'query, T> {
     sort: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     order: Option<String>,
+    #[serde(skip)]
+    text_matches: bool,
+    #[serde(skip)]
+    preview: Option<&'static str>,
 }
 
 impl<'octo, 'query, T> QueryHandler<'octo, 'query, T> {
-    pub(crate) fn new(crab: &'octo Octocrab, route: &'static str, query: &'query str) -> Self {
+    pub(crate) fn new(
+        crab: &'octo Octocrab,
+        route: &'static str,
+        operation: &'static str,
+        query: &'query str,
+    ) -> Self {
         Self {
             crab,
+            operation,
             order: None,
             page: None,
             per_page: None,
@@ -145,6 +187,8 @@ impl<'octo, 'query, T> QueryHandler<'octo, 'query, T> {
             return_type: std::marker::PhantomData,
             route,
             sort: None,
+            text_matches: false,
+            preview: None,
         }
     }
 
@@ -173,13 +217,37 @@ impl<'octo, 'query, T> QueryHandler<'octo, 'query, T> {
         self.page = Some(page.into());
         self
     }
+
+    /// Whether to request text match metadata, i.e. the fragments of each
+    /// result that matched the query, e.g. in [`models::Code::text_matches`].
+    pub fn text_matches(mut self, text_matches: bool) -> Self {
+        self.text_matches = text_matches;
+        self
+    }
 }
 
 impl<'octo, 'query, T: serde::de::DeserializeOwned> QueryHandler<'octo, 'query, T> {
     /// Send the actual request.
     pub async fn send(self) -> crate::Result<crate::Page<T>> {
+        let route = format!("/search/{}", self.route);
+        let media_types: Vec<&str> = self
+            .text_matches
+            .then_some("application/vnd.github.text-match+json")
+            .into_iter()
+            .chain(self.preview)
+            .collect();
+        let headers = (!media_types.is_empty()).then(|| {
+            let mut headers = http::header::HeaderMap::new();
+            headers.insert(
+                http::header::ACCEPT,
+                media_types.join(", ").parse().unwrap(),
+            );
+            headers
+        });
+
         self.crab
-            .get(&format!("/search/{}", self.route), Some(&self))
+            .operation(self.operation)
+            .get_with_headers(route, Some(&self), headers)
             .await
     }
 }
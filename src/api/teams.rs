@@ -65,7 +65,10 @@ impl<'octo> TeamHandler<'octo> {
             org = self.owner,
             team = team_slug.into(),
         );
-        self.crab.get(route, None::<&()>).await
+        self.crab
+            .operation("teams.get")
+            .get(route, None::<&()>)
+            .await
     }
 
     /// Creates a new team in the organization.
@@ -127,9 +130,14 @@ impl<'octo> TeamHandler<'octo> {
             .path_and_query(route)
             .build()
             .context(HttpSnafu)?;
-        crate::map_github_error(self.crab._delete(uri, None::<&()>).await?)
-            .await
-            .map(drop)
+        crate::map_github_error(
+            self.crab
+                .operation("teams.delete")
+                ._delete(uri, None::<&()>)
+                .await?,
+        )
+        .await
+        .map(drop)
     }
 
     /// List the child teams of a team in the organization.
@@ -42,6 +42,10 @@ impl<'octo, 'r> ListChildTeamsBuilder<'octo, 'r> {
             org = self.handler.owner,
             team = self.slug,
         );
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("teams.list_children")
+            .get(route, Some(&self))
+            .await
     }
 }
@@ -72,6 +72,10 @@ impl<'octo, 'h, 'a, 'b> CreateTeamBuilder<'octo, 'h, 'a, 'b> {
     /// Sends the actual request.
     pub async fn send(self) -> Result<models::teams::Team> {
         let route = format!("/orgs/{org}/teams", org = self.handler.owner,);
-        self.handler.crab.post(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("teams.create")
+            .post(route, Some(&self))
+            .await
     }
 }
@@ -59,6 +59,10 @@ impl<'octo, 'r> EditTeamBuilder<'octo, 'r> {
             org = self.handler.owner,
             team = self.slug,
         );
-        self.handler.crab.patch(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("teams.edit")
+            .patch(route, Some(&self))
+            .await
     }
 }
@@ -42,6 +42,10 @@ impl<'octo, 'r> ListTeamInvitationsBuilder<'octo, 'r> {
             org = self.handler.owner,
             team = self.slug,
         );
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("teams.invitations")
+            .get(route, Some(&self))
+            .await
     }
 }
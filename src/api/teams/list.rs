@@ -35,6 +35,10 @@ impl<'octo, 'r> ListTeamsBuilder<'octo, 'r> {
     /// Sends the actual request.
     pub async fn send(self) -> Result<Page<models::teams::RequestedTeam>> {
         let route = format!("/orgs/{owner}/teams", owner = self.handler.owner);
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("teams.list")
+            .get(route, Some(&self))
+            .await
     }
 }
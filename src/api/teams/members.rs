@@ -42,6 +42,10 @@ impl<'octo, 'r> ListTeamMembersBuilder<'octo, 'r> {
             org = self.handler.owner,
             team = self.slug,
         );
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("teams.members")
+            .get(route, Some(&self))
+            .await
     }
 }
@@ -61,7 +61,10 @@ impl<'octo> TeamRepoHandler<'octo> {
             .method("GET")
             .uri(uri)
             .header(ACCEPT, crate::format_media_type("inertia-preview+json"));
-        let request = self.crab.build_request(request, None::<&()>)?;
+        let request = self
+            .crab
+            .operation("teams.repos.check_manages")
+            .build_request(request, None::<&()>)?;
 
         let res = self.crab.execute(request).await?;
         if res.status() == StatusCode::NOT_FOUND {
@@ -104,9 +107,14 @@ impl<'octo> TeamRepoHandler<'octo> {
             .path_and_query(route)
             .build()
             .context(HttpSnafu)?;
-        crate::map_github_error(self.crab._put(uri, perm_body.as_ref()).await?)
-            .await
-            .map(drop)
+        crate::map_github_error(
+            self.crab
+                .operation("teams.repos.add_or_update")
+                ._put(uri, perm_body.as_ref())
+                .await?,
+        )
+        .await
+        .map(drop)
     }
 
     /// Removes a repository from a team.
@@ -133,6 +141,7 @@ impl<'octo> TeamRepoHandler<'octo> {
             repo = repo_name.into(),
         );
         self.crab
+            .operation("teams.repos.remove")
             ._delete(
                 self.crab.parameterized_uri(route, None::<&()>)?,
                 None::<&()>,
@@ -1,12 +1,9 @@
 //! The users API.
 
-use std::backtrace::Backtrace;
-
-use http::StatusCode;
-use snafu::GenerateImplicitData;
-
 pub use self::follow::{ListUserFollowerBuilder, ListUserFollowingBuilder};
+pub use self::user_orgs::ListUserOrgsBuilder;
 use self::user_repos::ListUserReposBuilder;
+pub use self::user_starred::ListUserStarredReposBuilder;
 use crate::api::users::user_blocks::BlockedUsersBuilder;
 use crate::api::users::user_emails::UserEmailsOpsBuilder;
 use crate::api::users::user_git_ssh_keys::UserGitSshKeysOpsBuilder;
@@ -15,16 +12,18 @@ use crate::api::users::user_social_accounts::UserSocialAccountsOpsBuilder;
 use crate::api::users::user_ssh_signing_keys::UserSshSigningKeysOpsBuilder;
 use crate::models::UserId;
 use crate::params::users::emails::EmailVisibilityState;
-use crate::{error, GitHubError, Octocrab};
+use crate::Octocrab;
 
 mod follow;
 mod user_blocks;
 mod user_emails;
 mod user_git_ssh_keys;
 mod user_gpg_keys;
+mod user_orgs;
 mod user_repos;
 mod user_social_accounts;
 mod user_ssh_signing_keys;
+mod user_starred;
 
 pub(crate) enum UserRef {
     ByString(String),
@@ -56,7 +55,10 @@ impl<'octo> UserHandler<'octo> {
         // build the route to get info on this user
         let route = format!("/{}", self.user);
         // get info on the specified user
-        self.crab.get(route, None::<&()>).await
+        self.crab
+            .operation("users.profile")
+            .get(route, None::<&()>)
+            .await
     }
 
     /// List this users that follow this user
@@ -73,6 +75,87 @@ impl<'octo> UserHandler<'octo> {
         ListUserReposBuilder::new(self)
     }
 
+    /// List the organizations this user is a public member of. Use
+    /// [`CurrentAuthHandler::list_orgs`](crate::current::CurrentAuthHandler::list_orgs)
+    /// to include the authenticated user's private memberships.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let orgs = octocrab::instance()
+    ///     .users("octocat")
+    ///     .list_orgs()
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_orgs(&self) -> ListUserOrgsBuilder<'_, '_> {
+        ListUserOrgsBuilder::new(self)
+    }
+
+    /// List the repositories this user has starred
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::users::starred::Sort;
+    ///
+    /// let starred = octocrab::instance()
+    ///     .users("octocat")
+    ///     .starred()
+    ///     .sort(Sort::Created)
+    ///     .send_with_timestamps()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn starred(&self) -> ListUserStarredReposBuilder<'_, '_> {
+        ListUserStarredReposBuilder::new(self)
+    }
+
+    /// Lists the public events performed by this user, or their private
+    /// events too when authenticated as them. Pass the etag of a previous
+    /// response to only fetch new events, and wait
+    /// [`Etagged::poll_interval`](crate::etag::Etagged::poll_interval)
+    /// between polls. The same as
+    /// [`EventsBuilder::for_user`](crate::events::EventsBuilder::for_user).
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let events = octocrab::instance()
+    ///     .users("octocat")
+    ///     .events()
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    ///
+    /// let new_events = octocrab::instance()
+    ///     .users("octocat")
+    ///     .events()
+    ///     .etag(events.etag)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn events(&self) -> crate::events::EventsBuilder<'octo> {
+        self.crab.events().for_user_ref(&self.user)
+    }
+
+    /// Lists the events received by this user, i.e. the activity of the users
+    /// they follow and the repositories they watch. The same as
+    /// [`EventsBuilder::received_by_user`](crate::events::EventsBuilder::received_by_user).
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let events = octocrab::instance()
+    ///     .users("octocat")
+    ///     .received_events()
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn received_events(&self) -> crate::events::EventsBuilder<'octo> {
+        self.crab.events().received_by_user_ref(&self.user)
+    }
+
     /// API for listing blocked users
     /// you must pass authentication information with your requests
     pub fn blocks(&self) -> BlockedUsersBuilder {
@@ -95,9 +178,7 @@ impl<'octo> UserHandler<'octo> {
     ///    Ok(is_blocked)
     ///  }
     pub async fn is_blocked(&self, username: &str) -> crate::Result<bool> {
-        let route = format!("/user/blocks/{}", username);
-        let response = self.crab._get(route).await?;
-        Ok(response.status() == 204)
+        self.crab.current().is_blocking(username).await
     }
 
     ///## Blocks the given user
@@ -115,21 +196,7 @@ impl<'octo> UserHandler<'octo> {
     ///    .await
     ///  }
     pub async fn block_user(&self, username: &str) -> crate::Result<()> {
-        let route = format!("/user/blocks/{}", username);
-        /* '204 not found' is returned if user blocked */
-        let result: crate::Result<()> = self.crab.put(route, None::<&()>).await;
-        match result {
-            Ok(_) => Err(error::Error::GitHub {
-                source: GitHubError {
-                    status_code: StatusCode::OK,
-                    documentation_url: None,
-                    errors: None,
-                    message: "".to_string(),
-                },
-                backtrace: Backtrace::capture(),
-            }),
-            Err(_v) => Ok(()),
-        }
+        self.crab.current().block_user(username).await
     }
 
     ///## Unblocks the given user
@@ -147,9 +214,7 @@ impl<'octo> UserHandler<'octo> {
     ///    .await
     ///  }
     pub async fn unblock_user(&self, username: &str) -> crate::Result<()> {
-        let route = format!("/user/blocks/{}", username);
-
-        self.crab.delete(route, None::<&()>).await
+        self.crab.current().unblock_user(username).await
     }
 
     ///## Set primary email visibility for the authenticated user
@@ -175,11 +240,10 @@ impl<'octo> UserHandler<'octo> {
         &self,
         visibility: EmailVisibilityState,
     ) -> crate::Result<Vec<crate::models::UserEmailInfo>> {
-        let route = String::from("/user/email/visibility");
-        let params = serde_json::json!({
-            "visibility": serde_json::to_string(&visibility).unwrap(),
-        });
-        self.crab.patch(route, Some(&params)).await
+        self.crab
+            .current()
+            .set_primary_email_visibility(visibility)
+            .await
     }
 
     ///Email addresses operations builder
@@ -223,4 +287,14 @@ impl<'octo> UserHandler<'octo> {
     pub fn ssh_signing_keys(&self) -> UserSshSigningKeysOpsBuilder<'_, '_> {
         UserSshSigningKeysOpsBuilder::new(self)
     }
+
+    /// Handle packages published by the user. For a user created with
+    /// [`Octocrab::users_by_id`], each request first looks up their login.
+    pub fn packages(&self) -> crate::packages::PackagesHandler<'octo> {
+        let owner = match &self.user {
+            UserRef::ByString(_) => crate::packages::PackagesOwner::Route(self.user.to_string()),
+            UserRef::ById(id) => crate::packages::PackagesOwner::UserId(*id),
+        };
+        crate::packages::PackagesHandler::new(self.crab, owner)
+    }
 }
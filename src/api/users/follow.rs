@@ -38,8 +38,12 @@ impl<'octo, 'b> ListUserFollowerBuilder<'octo, 'b> {
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<Page<crate::models::Follower>> {
         // build the route to get this users followers
-        let route = format!("/users/{}/followers", self.handler.user);
-        self.handler.crab.get(route, Some(&self)).await
+        let route = format!("/{}/followers", self.handler.user);
+        self.handler
+            .crab
+            .operation("users.followers")
+            .get(route, Some(&self))
+            .await
     }
 }
 
@@ -79,8 +83,12 @@ impl<'octo, 'b> ListUserFollowingBuilder<'octo, 'b> {
 
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<Page<crate::models::Followee>> {
-        // build the route to get this users followers
-        let route = format!("/users/{}/following", self.handler.user);
-        self.handler.crab.get(route, Some(&self)).await
+        // build the route to get who this user follows
+        let route = format!("/{}/following", self.handler.user);
+        self.handler
+            .crab
+            .operation("users.following")
+            .get(route, Some(&self))
+            .await
     }
 }
@@ -51,7 +51,13 @@ impl<'octo, 'b> BlockedUsersBuilder<'octo, 'b> {
     ///    Ok(blocked_users.items)
     ///  }
     pub async fn list(&self) -> crate::Result<crate::Page<models::SimpleUser>> {
-        let route = "/user/blocks".to_string();
-        self.handler.crab.get(route, None::<&()>).await
+        let mut builder = self.handler.crab.current().list_blocked_users();
+        if let Some(per_page) = self.per_page {
+            builder = builder.per_page(per_page);
+        }
+        if let Some(page) = self.page {
+            builder = builder.page(page);
+        }
+        builder.send().await
     }
 }
@@ -1,6 +1,5 @@
 use crate::api::users::UserHandler;
-use crate::models::UserEmailInfo;
-use crate::{FromResponse, Page};
+use crate::Page;
 
 #[derive(serde::Serialize)]
 pub struct UserEmailsOpsBuilder<'octo, 'b> {
@@ -53,8 +52,14 @@ impl<'octo, 'b> UserEmailsOpsBuilder<'octo, 'b> {
     ///        .await
     ///  }
     pub async fn list(&self) -> crate::Result<Page<crate::models::UserEmailInfo>> {
-        let route = "/user/emails".to_string();
-        self.handler.crab.get(route, Some(&self)).await
+        let mut builder = self.handler.crab.current().list_emails();
+        if let Some(per_page) = self.per_page {
+            builder = builder.per_page(per_page);
+        }
+        if let Some(page) = self.page {
+            builder = builder.page(page);
+        }
+        builder.send().await
     }
 
     ///## List public email addresses for the authenticated user
@@ -80,7 +85,11 @@ impl<'octo, 'b> UserEmailsOpsBuilder<'octo, 'b> {
     ///  }
     pub async fn list_public(&self) -> crate::Result<Page<crate::models::UserEmailInfo>> {
         let route = "/user/public_emails".to_string();
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("users.emails.list_public")
+            .get(route, Some(&self))
+            .await
     }
 
     ///## Add an email address(es) for the authenticated user
@@ -105,17 +114,8 @@ impl<'octo, 'b> UserEmailsOpsBuilder<'octo, 'b> {
         &self,
         emails: Vec<String>,
     ) -> crate::Result<Vec<crate::models::UserEmailInfo>> {
-        let route = "/user/emails".to_string();
-
-        let params = serde_json::json!({
-            "emails": serde_json::Value::from(emails),
-        });
-        let response = self.handler.crab._post(route, Some(&params)).await?;
-        if response.status() != http::StatusCode::CREATED {
-            return Err(crate::map_github_error(response).await.unwrap_err());
-        }
-
-        <Vec<UserEmailInfo>>::from_response(crate::map_github_error(response).await?).await
+        let emails: Vec<&str> = emails.iter().map(String::as_str).collect();
+        self.handler.crab.current().add_emails(&emails).await
     }
 
     ///## Delete an email address(es) for the authenticated user
@@ -136,16 +136,7 @@ impl<'octo, 'b> UserEmailsOpsBuilder<'octo, 'b> {
     ///        .await
     ///  }
     pub async fn delete(&self, emails: Vec<String>) -> crate::Result<()> {
-        let route = "/user/emails".to_string();
-
-        let params = serde_json::json!({
-            "emails": serde_json::Value::from(emails),
-        });
-        let response = self.handler.crab._delete(route, Some(&params)).await?;
-        if response.status() != http::StatusCode::NO_CONTENT {
-            return Err(crate::map_github_error(response).await.unwrap_err());
-        }
-
-        Ok(())
+        let emails: Vec<&str> = emails.iter().map(String::as_str).collect();
+        self.handler.crab.current().delete_emails(&emails).await
     }
 }
@@ -56,7 +56,11 @@ impl<'octo, 'b> UserGitSshKeysOpsBuilder<'octo, 'b> {
     ///  }
     pub async fn list(&self) -> crate::Result<Page<crate::models::GitSshKey>> {
         let route = "/user/keys".to_string();
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("users.git_ssh_keys.list")
+            .get(route, Some(&self))
+            .await
     }
 
     ///## Create a public SSH key for the authenticated user
@@ -86,7 +90,12 @@ impl<'octo, 'b> UserGitSshKeysOpsBuilder<'octo, 'b> {
             "title": title,
             "key": key,
         });
-        let response = self.handler.crab._post(route, Some(&params)).await?;
+        let response = self
+            .handler
+            .crab
+            .operation("users.git_ssh_keys.add")
+            ._post(route, Some(&params))
+            .await?;
         if response.status() != http::StatusCode::CREATED {
             return Err(crate::map_github_error(response).await.unwrap_err());
         }
@@ -116,7 +125,12 @@ impl<'octo, 'b> UserGitSshKeysOpsBuilder<'octo, 'b> {
     pub async fn delete(&self, git_ssh_key_id: u64) -> crate::Result<()> {
         let route = format!("/user/keys/{git_ssh_key_id}");
 
-        let response = self.handler.crab._delete(route, None::<&()>).await?;
+        let response = self
+            .handler
+            .crab
+            .operation("users.git_ssh_keys.delete")
+            ._delete(route, None::<&()>)
+            .await?;
         if response.status() != http::StatusCode::NO_CONTENT {
             return Err(crate::map_github_error(response).await.unwrap_err());
         }
@@ -147,6 +161,10 @@ impl<'octo, 'b> UserGitSshKeysOpsBuilder<'octo, 'b> {
     ///  }
     pub async fn get(&self, git_ssh_key_id: u64) -> crate::Result<GitSshKey> {
         let route = format!("/user/keys/{git_ssh_key_id}");
-        self.handler.crab.get(route, None::<&()>).await
+        self.handler
+            .crab
+            .operation("users.git_ssh_keys.get")
+            .get(route, None::<&()>)
+            .await
     }
 }
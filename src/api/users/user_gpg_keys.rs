@@ -54,7 +54,11 @@ impl<'octo, 'b> UserGpgKeysOpsBuilder<'octo, 'b> {
     ///  }
     pub async fn list(&self) -> crate::Result<Page<crate::models::GpgKey>> {
         let route = "/user/gpg_keys".to_string();
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("users.gpg_keys.list")
+            .get(route, Some(&self))
+            .await
     }
 
     ///## View extended details for a single GPG key for the authenticated user
@@ -80,7 +84,11 @@ impl<'octo, 'b> UserGpgKeysOpsBuilder<'octo, 'b> {
     ///  }
     pub async fn get(&self, gpg_key_id: u64) -> crate::Result<GpgKey> {
         let route = format!("/user/gpg_keys/{gpg_key_id}");
-        self.handler.crab.get(route, None::<&()>).await
+        self.handler
+            .crab
+            .operation("users.gpg_keys.get")
+            .get(route, None::<&()>)
+            .await
     }
 
     ///## Create a GPG key for the authenticated user
@@ -108,7 +116,12 @@ impl<'octo, 'b> UserGpgKeysOpsBuilder<'octo, 'b> {
             "name": name,
             "armored_public_key": armored_public_key,
         });
-        let response = self.handler.crab._post(route, Some(&params)).await?;
+        let response = self
+            .handler
+            .crab
+            .operation("users.gpg_keys.add")
+            ._post(route, Some(&params))
+            .await?;
         if response.status() != http::StatusCode::CREATED {
             return Err(crate::map_github_error(response).await.unwrap_err());
         }
@@ -136,7 +149,12 @@ impl<'octo, 'b> UserGpgKeysOpsBuilder<'octo, 'b> {
     pub async fn delete(&self, gpg_key_id: u64) -> crate::Result<()> {
         let route = format!("/user/gpg_keys/{gpg_key_id}");
 
-        let response = self.handler.crab._delete(route, None::<&()>).await?;
+        let response = self
+            .handler
+            .crab
+            .operation("users.gpg_keys.delete")
+            ._delete(route, None::<&()>)
+            .await?;
         if response.status() != http::StatusCode::NO_CONTENT {
             return Err(crate::map_github_error(response).await.unwrap_err());
         }
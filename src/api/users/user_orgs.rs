@@ -0,0 +1,67 @@
+use crate::api::users::UserHandler;
+use crate::models::orgs::Organization;
+use crate::Page;
+
+/// A builder pattern struct for listing a user's public organization
+/// memberships.
+///
+/// created by [`UserHandler::list_orgs`]
+#[derive(serde::Serialize)]
+pub struct ListUserOrgsBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r UserHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListUserOrgsBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r UserHandler<'octo>) -> Self {
+        Self {
+            handler,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Page<Organization>> {
+        let route = format!("/{}/orgs", self.handler.user);
+        self.handler
+            .crab
+            .operation("users.list_orgs")
+            .get(route, Some(&self))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[tokio::test]
+    async fn serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.users("octocat");
+        let list = handler.list_orgs().per_page(100u8).page(2u32);
+
+        assert_eq!(
+            serde_json::to_value(list).unwrap(),
+            serde_json::json!({
+                "per_page": 100,
+                "page": 2
+            })
+        )
+    }
+}
@@ -67,7 +67,11 @@ impl<'octo, 'b> ListUserReposBuilder<'octo, 'b> {
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<Page<crate::models::Repository>> {
         let route = format!("/{user}/repos", user = self.handler.user);
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("users.repos")
+            .get(route, Some(&self))
+            .await
     }
 }
 
@@ -54,7 +54,11 @@ impl<'octo, 'b> UserSocialAccountsOpsBuilder<'octo, 'b> {
     ///  }
     pub async fn list(&self) -> crate::Result<Page<crate::models::SocialAccount>> {
         let route = "/user/social_accounts".to_string();
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("users.social_accounts.list")
+            .get(route, Some(&self))
+            .await
     }
 
     ///## Add social accounts for the authenticated user
@@ -83,7 +87,12 @@ impl<'octo, 'b> UserSocialAccountsOpsBuilder<'octo, 'b> {
         let params = serde_json::json!({
             "account_urls": account_urls,
         });
-        let response = self.handler.crab._post(route, Some(&params)).await?;
+        let response = self
+            .handler
+            .crab
+            .operation("users.social_accounts.add")
+            ._post(route, Some(&params))
+            .await?;
         if response.status() != http::StatusCode::CREATED {
             return Err(crate::map_github_error(response).await.unwrap_err());
         }
@@ -118,7 +127,12 @@ impl<'octo, 'b> UserSocialAccountsOpsBuilder<'octo, 'b> {
             "account_urls": account_urls,
         });
 
-        let response = self.handler.crab._delete(route, Some(&params)).await?;
+        let response = self
+            .handler
+            .crab
+            .operation("users.social_accounts.delete")
+            ._delete(route, Some(&params))
+            .await?;
         if response.status() != http::StatusCode::NO_CONTENT {
             return Err(crate::map_github_error(response).await.unwrap_err());
         }
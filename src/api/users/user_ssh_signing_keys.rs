@@ -56,7 +56,11 @@ impl<'octo, 'b> UserSshSigningKeysOpsBuilder<'octo, 'b> {
     ///  }
     pub async fn list(&self) -> crate::Result<Page<crate::models::SshSigningKey>> {
         let route = "/user/ssh_signing_keys".to_string();
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("users.ssh_signing_keys.list")
+            .get(route, Some(&self))
+            .await
     }
 
     ///## Get extended details for an SSH signing key for the authenticated user
@@ -82,7 +86,11 @@ impl<'octo, 'b> UserSshSigningKeysOpsBuilder<'octo, 'b> {
     ///  }
     pub async fn get(&self, ssh_signing_key_id: u64) -> crate::Result<SshSigningKey> {
         let route = format!("/user/ssh_signing_keys/{ssh_signing_key_id}");
-        self.handler.crab.get(route, None::<&()>).await
+        self.handler
+            .crab
+            .operation("users.ssh_signing_keys.get")
+            .get(route, None::<&()>)
+            .await
     }
 
     ///## Create a SSH signing key for the authenticated user
@@ -112,7 +120,12 @@ impl<'octo, 'b> UserSshSigningKeysOpsBuilder<'octo, 'b> {
             "title": title,
             "key": key,
         });
-        let response = self.handler.crab._post(route, Some(&params)).await?;
+        let response = self
+            .handler
+            .crab
+            .operation("users.ssh_signing_keys.add")
+            ._post(route, Some(&params))
+            .await?;
         if response.status() != http::StatusCode::CREATED {
             return Err(crate::map_github_error(response).await.unwrap_err());
         }
@@ -142,7 +155,12 @@ impl<'octo, 'b> UserSshSigningKeysOpsBuilder<'octo, 'b> {
     pub async fn delete(&self, ssh_signing_key_id: u64) -> crate::Result<()> {
         let route = format!("/user/ssh_signing_keys/{ssh_signing_key_id}");
 
-        let response = self.handler.crab._delete(route, None::<&()>).await?;
+        let response = self
+            .handler
+            .crab
+            .operation("users.ssh_signing_keys.delete")
+            ._delete(route, None::<&()>)
+            .await?;
         if response.status() != http::StatusCode::NO_CONTENT {
             return Err(crate::map_github_error(response).await.unwrap_err());
         }
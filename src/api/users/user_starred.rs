@@ -0,0 +1,109 @@
+use http::header::ACCEPT;
+
+use crate::api::users::UserHandler;
+use crate::models::{Repository, StarredRepository};
+use crate::Page;
+
+/// A builder pattern struct for listing the repositories a user has starred.
+///
+/// created by [`UserHandler::starred`]
+///
+/// [`UserHandler::starred`]: ./struct.UserHandler.html#method.starred
+#[derive(serde::Serialize)]
+pub struct ListUserStarredReposBuilder<'octo, 'b> {
+    #[serde(skip)]
+    handler: &'b UserHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort: Option<crate::params::users::starred::Sort>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    direction: Option<crate::params::Direction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'b> ListUserStarredReposBuilder<'octo, 'b> {
+    pub(crate) fn new(handler: &'b UserHandler<'octo>) -> Self {
+        Self {
+            handler,
+            sort: None,
+            direction: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// What to sort results by.
+    pub fn sort(mut self, sort: impl Into<crate::params::users::starred::Sort>) -> Self {
+        self.sort = Some(sort.into());
+        self
+    }
+
+    /// The direction of the sort.
+    pub fn direction(mut self, direction: impl Into<crate::params::Direction>) -> Self {
+        self.direction = Some(direction.into());
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Page<Repository>> {
+        let route = format!("/{user}/starred", user = self.handler.user);
+        self.handler
+            .crab
+            .operation("users.starred")
+            .get(route, Some(&self))
+            .await
+    }
+
+    /// Sends the actual request, including when each repository was starred.
+    pub async fn send_with_timestamps(self) -> crate::Result<Page<StarredRepository>> {
+        let route = format!("/{user}/starred", user = self.handler.user);
+
+        let mut headers = http::header::HeaderMap::new();
+        headers.insert(ACCEPT, "application/vnd.github.star+json".parse().unwrap());
+
+        self.handler
+            .crab
+            .operation("users.starred.send_with_timestamps")
+            .get_with_headers(route, Some(&self), Some(headers))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[tokio::test]
+    async fn serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.users("foo");
+        let request = handler
+            .starred()
+            .sort(crate::params::users::starred::Sort::Created)
+            .direction(crate::params::Direction::Descending)
+            .per_page(50)
+            .page(2u32);
+
+        assert_eq!(
+            serde_json::to_value(request).unwrap(),
+            serde_json::json!({
+                "sort": "created",
+                "direction": "desc",
+                "per_page": 50,
+                "page": 2,
+            })
+        )
+    }
+}
@@ -43,7 +43,10 @@ impl<'octo> WorkflowsHandler<'octo> {
             run_id = run_id,
         );
 
-        self.crab.get(route, None::<&()>).await
+        self.crab
+            .operation("workflows.get")
+            .get(route, None::<&()>)
+            .await
     }
 
     /// List runs in the specified workflow.
@@ -159,7 +162,11 @@ impl<'octo, 'b> ListWorkflowsBuilder<'octo, 'b> {
             owner = self.handler.owner,
             repo = self.handler.repo
         );
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("workflows.list")
+            .get(route, Some(&self))
+            .await
     }
 }
 
@@ -265,7 +272,11 @@ impl<'octo, 'b> ListRunsBuilder<'octo, 'b> {
                 workflow_id = workflow_id
             ),
         };
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("workflows.list_runs")
+            .get(route, Some(&self))
+            .await
     }
 }
 
@@ -320,7 +331,11 @@ impl<'octo, 'b> ListJobsBuilder<'octo, 'b> {
             repo = self.handler.repo,
             run_id = self.run_id,
         );
-        self.handler.crab.get(route, Some(&self)).await
+        self.handler
+            .crab
+            .operation("workflows.list_jobs")
+            .get(route, Some(&self))
+            .await
     }
 }
 
@@ -92,6 +92,30 @@ pub enum Error {
         source: jsonwebtoken::errors::Error,
         backtrace: Backtrace,
     },
+    #[snafu(display(
+        "Empty response body from {} can't be deserialized into the requested type\nFound at {}",
+        route,
+        backtrace
+    ))]
+    EmptyBody { route: String, backtrace: Backtrace },
+    #[snafu(display(
+        "Reference `{}` was not found or is protected: {}\nFound at {}",
+        reference,
+        source,
+        backtrace
+    ))]
+    Reference {
+        reference: String,
+        source: GitHubError,
+        backtrace: Backtrace,
+    },
+    #[cfg(feature = "webhooks")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "webhooks")))]
+    #[snafu(display("Webhook Error: {}\n\nFound at {}", message, backtrace))]
+    Webhook {
+        message: String,
+        backtrace: Backtrace,
+    },
     Other {
         source: Box<dyn std::error::Error + Send + Sync>,
         backtrace: Backtrace,
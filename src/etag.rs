@@ -2,6 +2,7 @@
 use std::{
     fmt::{self, Display},
     str::FromStr,
+    time::Duration,
 };
 
 use http::header::{HeaderMap, InvalidHeaderValue};
@@ -9,6 +10,7 @@ use snafu::GenerateImplicitData;
 
 /// Represents resources identified by etags.
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub struct Etagged<T> {
     /// A possible etag.
     ///
@@ -19,6 +21,19 @@ pub struct Etagged<T> {
     ///
     /// This can be `None` if we have already received the data which this etag identifies.
     pub value: Option<T>,
+    /// How long GitHub asks clients to wait before polling the resource again,
+    /// taken from the `X-Poll-Interval` header of the events APIs.
+    pub poll_interval: Option<Duration>,
+}
+
+/// Reads the `X-Poll-Interval` header (in seconds) of `response`.
+pub(crate) fn extract_poll_interval<B>(response: &http::Response<B>) -> Option<Duration> {
+    response
+        .headers()
+        .get("x-poll-interval")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok())
+        .map(Duration::from_secs)
 }
 
 /*
@@ -1,7 +1,130 @@
+//! Mapping HTTP responses into types, see [`FromResponse`].
+
+use std::cell::Cell;
+
 use bytes::Bytes;
 use http_body::Body;
 use http_body_util::BodyExt;
-use snafu::ResultExt;
+use snafu::{OptionExt, ResultExt};
+
+/// The URI of the request a response answers, recorded by
+/// [`crate::Octocrab::execute`] so errors can point at the offending route.
+#[derive(Clone, Debug)]
+pub(crate) struct RequestUri(pub(crate) http::Uri);
+
+/// Marks responses whose unmodelled fields should be captured, see
+/// [`crate::OctocrabBuilder::lenient_deserialization`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct CaptureExtra;
+
+thread_local! {
+    static CAPTURE_EXTRA: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `f`, letting models with an `extra` map capture unmodelled fields
+/// while deserializing if `enabled`.
+pub(crate) fn capture_extra<T>(enabled: bool, f: impl FnOnce() -> T) -> T {
+    struct Reset(bool);
+
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            CAPTURE_EXTRA.with(|capture| capture.set(self.0));
+        }
+    }
+
+    let _reset = Reset(CAPTURE_EXTRA.with(|capture| capture.replace(enabled)));
+    f()
+}
+
+pub(crate) fn capturing_extra() -> bool {
+    CAPTURE_EXTRA.with(Cell::get)
+}
+
+/// A deserializer that only records the field names a derived struct
+/// expects, see [`struct_fields`].
+pub(crate) struct StructFields;
+
+#[derive(Debug)]
+pub(crate) struct StructFieldsError(&'static [&'static str]);
+
+impl std::fmt::Display for StructFieldsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expected a struct")
+    }
+}
+
+impl std::error::Error for StructFieldsError {}
+
+impl serde::de::Error for StructFieldsError {
+    fn custom<T: std::fmt::Display>(_: T) -> Self {
+        Self(&[])
+    }
+}
+
+impl<'de> serde::Deserializer<'de> for StructFields {
+    type Error = StructFieldsError;
+
+    fn deserialize_any<V: serde::de::Visitor<'de>>(self, _: V) -> Result<V::Value, Self::Error> {
+        Err(StructFieldsError(&[]))
+    }
+
+    fn deserialize_struct<V: serde::de::Visitor<'de>>(
+        self,
+        _: &'static str,
+        fields: &'static [&'static str],
+        _: V,
+    ) -> Result<V::Value, Self::Error> {
+        Err(StructFieldsError(fields))
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map enum identifier ignored_any
+    }
+}
+
+/// Returns the names of the fields `deserialize` expects.
+pub(crate) fn struct_fields<T>(
+    deserialize: fn(StructFields) -> Result<T, StructFieldsError>,
+) -> &'static [&'static str] {
+    deserialize(StructFields).err().map_or(&[], |error| error.0)
+}
+
+/// Implements `Serialize` and `Deserialize` for a model deriving them with
+/// `#[serde(remote = "Self")]`. If [`capture_extra`] is enabled, fields the
+/// model doesn't know are collected into its `extra` map.
+macro_rules! impl_capture_extra {
+    ($model:ty) => {
+        impl serde::Serialize for $model {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                <$model>::serialize(self, serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $model {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                use serde::de::Error;
+
+                if !crate::from_response::capturing_extra() {
+                    return <$model>::deserialize(deserializer);
+                }
+
+                let fields = crate::from_response::struct_fields(<$model>::deserialize);
+                let (known, extra): (serde_json::Map<_, _>, serde_json::Map<_, _>) =
+                    serde_json::Map::deserialize(deserializer)?
+                        .into_iter()
+                        .partition(|(key, _)| fields.contains(&key.as_str()));
+                let mut model = <$model>::deserialize(serde_json::Value::Object(known))
+                    .map_err(D::Error::custom)?;
+                model.extra = extra.into_iter().collect();
+                Ok(model)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_capture_extra;
 
 /// A trait for mapping from a `http::Response` to an another type.
 #[async_trait::async_trait]
@@ -11,15 +134,60 @@ pub trait FromResponse: Sized {
         B: Body<Data = Bytes, Error = crate::Error> + Send;
 }
 
+/// Collects the whole body of `response`, e.g. to implement [`FromResponse`]
+/// for a type that isn't deserialized from JSON.
+/// ```no_run
+/// use bytes::Bytes;
+/// use http_body::Body;
+/// use octocrab::{from_response::body_bytes, FromResponse};
+///
+/// struct Plain(String);
+///
+/// #[async_trait::async_trait]
+/// impl FromResponse for Plain {
+///     async fn from_response<B>(response: http::Response<B>) -> octocrab::Result<Self>
+///     where
+///         B: Body<Data = Bytes, Error = octocrab::Error> + Send,
+///     {
+///         let body = body_bytes(response).await?;
+///         Ok(Plain(String::from_utf8_lossy(&body).into_owned()))
+///     }
+/// }
+/// ```
+pub async fn body_bytes<B>(response: http::Response<B>) -> crate::Result<Bytes>
+where
+    B: Body<Data = Bytes, Error = crate::Error>,
+{
+    Ok(response.into_body().collect().await?.to_bytes())
+}
+
 #[async_trait::async_trait]
 impl<T: serde::de::DeserializeOwned> FromResponse for T {
     async fn from_response<B>(response: http::Response<B>) -> crate::Result<Self>
     where
         B: Body<Data = Bytes, Error = crate::Error> + Send,
     {
-        let (_, body) = response.into_parts();
-        let body = body.collect().await?.to_bytes();
+        let capture = response.extensions().get::<CaptureExtra>().is_some();
+        let uri = response.extensions().get::<RequestUri>().cloned();
+        let body = body_bytes(response).await?;
+
+        // Endpoints answering `204 No Content` have no JSON to parse, so treat
+        // the empty body as `null`. That works for `()`, `Option<_>` and
+        // `serde_json::Value`; anything else gets a more helpful error than
+        // serde's "EOF while parsing".
+        if body.is_empty() {
+            return serde_json::from_slice(b"null")
+                .ok()
+                .context(crate::error::EmptyBodySnafu {
+                    route: uri.map_or_else(
+                        || String::from("<unknown route>"),
+                        |uri| uri.0.path().to_owned(),
+                    ),
+                });
+        }
+
         let de = &mut serde_json::Deserializer::from_slice(&body);
-        return serde_path_to_error::deserialize(de).context(crate::error::JsonSnafu);
+        return capture_extra(capture, || serde_path_to_error::deserialize(de))
+            .context(crate::error::JsonSnafu);
     }
 }
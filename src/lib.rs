@@ -160,6 +160,9 @@
 //! **Note**: Webhook support in `octocrab` is still beta, not all known webhook events are
 //! strongly typed.
 //!
+//! With the `webhooks` feature, the `webhooks` module also verifies the
+//! signatures of webhook deliveries and dispatches them to a handler trait.
+//!
 //! ```no_run
 //! # use http::request::Request;
 //! # use tracing::{warn, info};
@@ -184,7 +187,8 @@
 mod api;
 mod body;
 mod error;
-mod from_response;
+pub mod from_response;
+mod operation;
 mod page;
 
 pub mod auth;
@@ -192,10 +196,12 @@ pub mod etag;
 pub mod models;
 pub mod params;
 pub mod service;
+#[cfg(feature = "webhooks")]
+#[cfg_attr(docsrs, doc(cfg(feature = "webhooks")))]
+pub mod webhooks;
 
 use api::repos::RepoRef;
 use api::users::UserRef;
-use body::OctoBody;
 use chrono::{DateTime, Utc};
 use http::{HeaderMap, HeaderValue, Method, Uri};
 use http_body_util::combinators::BoxBody;
@@ -258,10 +264,13 @@ use models::{AppId, InstallationId, InstallationToken, RepositoryId, UserId};
 pub use self::{
     api::{
         actions, activity, apps, checks, commits, current, events, gists, gitignore, hooks, issues,
-        licenses, markdown, orgs, projects, pulls, ratelimit, repos, search, teams, workflows,
+        licenses, markdown, orgs, packages, projects, pulls, ratelimit, repos, search, teams,
+        workflows,
     },
+    body::OctoBody,
     error::{Error, GitHubError},
     from_response::FromResponse,
+    operation::Operation,
     page::Page,
 };
 
@@ -336,6 +345,31 @@ pub async fn map_github_error(
     }
 }
 
+/// Deserializes `response` like the typed HTTP methods do, except that a
+/// `404 Not Found` is returned as `Ok(None)` instead of an error.
+async fn from_response_opt<R: FromResponse>(
+    response: http::Response<BoxBody<Bytes, crate::Error>>,
+) -> Result<Option<R>> {
+    if response.status() == http::StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    R::from_response(map_github_error(response).await?)
+        .await
+        .map(Some)
+}
+
+/// Maps the `errors` of a GraphQL response into an `Err()` variant, since
+/// GitHub reports them with a successful status.
+pub(crate) fn map_graphql_errors(response: &serde_json::Value) -> Result<()> {
+    match response.get("errors") {
+        Some(errors) if !errors.is_null() => Err(Error::Other {
+            source: Box::from(format!("GraphQL query returned errors: {errors}")),
+            backtrace: Backtrace::capture(),
+        }),
+        _ => Ok(()),
+    }
+}
+
 /// Initialises the static instance using the configuration set by
 /// `builder`.
 /// ```
@@ -382,6 +416,7 @@ pub fn instance() -> Arc<Octocrab> {
 
 /// A builder struct for `Octocrab`.
 /// OctocrabBuilder can be extended with a custom config, see [DefaultOctocrabBuilderConfig] for an example
+#[must_use = "an `OctocrabBuilder` does nothing until `build` is called"]
 pub struct OctocrabBuilder<Svc, Config, Auth, LayerReady> {
     service: Svc,
     auth: Auth,
@@ -503,28 +538,32 @@ impl<Svc, Auth, LayerState> OctocrabBuilder<Svc, NoConfig, Auth, LayerState> {
     }
 }
 
-impl<Svc, B, LayerState> OctocrabBuilder<Svc, NoConfig, AuthState, LayerState>
-where
-    Svc: Service<Request<OctoBody>, Response = Response<B>> + Send + 'static,
-    Svc::Future: Send + 'static,
-    Svc::Error: Into<BoxError>,
-    B: http_body::Body<Data = bytes::Bytes> + Send + Sync + 'static,
-    B::Error: Into<BoxError>,
-{
+impl<Svc, LayerState> OctocrabBuilder<Svc, NoConfig, AuthState, LayerState> {
     /// Build a [`Client`] instance with the current [`Service`] stack.
-    pub fn build(self) -> Result<Octocrab, Infallible> {
-        // Transform response body to `BoxBody<Bytes, crate::Error>` and use type erased error to avoid type parameters.
-        let service = MapResponseBodyLayer::new(|b: B| {
-            b.map_err(|e| ServiceSnafu.into_error(e.into())).boxed()
-        })
-        .layer(self.service)
-        .map_err(|e| e.into());
-
+    ///
+    /// Building from a custom service can't fail, hence the [`Infallible`]
+    /// error type. A service must have been provided with
+    /// [`OctocrabBuilder::with_service`] beforehand.
+    pub fn build<B>(self) -> Result<Octocrab, Infallible>
+    where
+        Svc: Service<Request<OctoBody>, Response = Response<B>> + Send + 'static,
+        Svc::Future: Send + 'static,
+        Svc::Error: Into<BoxError>,
+        B: http_body::Body<Data = bytes::Bytes> + Send + Sync + 'static,
+        B::Error: Into<BoxError>,
+    {
         if let Some(executor) = self.executor {
+            // Transform response body to `BoxBody<Bytes, crate::Error>` and use type erased error to avoid type parameters.
+            let service = MapResponseBodyLayer::new(|b: B| {
+                b.map_err(|e| ServiceSnafu.into_error(e.into())).boxed()
+            })
+            .layer(self.service)
+            .map_err(|e| e.into());
+
             return Ok(Octocrab::new_with_executor(service, self.auth, executor));
         }
 
-        Ok(Octocrab::new(service, self.auth))
+        Ok(Octocrab::from_service(self.service, self.auth))
     }
 }
 
@@ -549,6 +588,111 @@ impl OctocrabBuilder<NoSvc, DefaultOctocrabBuilderConfig, NoAuth, NotLayerReady>
         self
     }
 
+    /// Set whether the `rustls` connector may make plaintext HTTP requests
+    /// in addition to HTTPS ones. By default only HTTPS is allowed, unless the
+    /// base or upload URI is an `http://` one, e.g. that of a local mock
+    /// server.
+    /// ```no_run
+    /// # fn run() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::Octocrab::builder()
+    ///     .allow_http(true)
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "rustls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rustls")))]
+    pub fn allow_http(mut self, allow_http: bool) -> Self {
+        self.config.allow_http = Some(allow_http);
+        self
+    }
+
+    /// Set the maximum number of idle connections the default client keeps
+    /// open per host. Defaults to no limit.
+    /// ```no_run
+    /// # fn run() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::Octocrab::builder()
+    ///     .set_pool_max_idle_per_host(8)
+    ///     .set_pool_idle_timeout(std::time::Duration::from_secs(30))
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "default-client")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "default-client")))]
+    pub fn set_pool_max_idle_per_host(mut self, max_idle: usize) -> Self {
+        self.config.pool_max_idle_per_host = Some(max_idle);
+        self
+    }
+
+    /// Set whether responses fill in the `extra` map of models such as
+    /// [`models::Repository`] with the fields octocrab doesn't model yet.
+    /// This is disabled by default as it's slower than plain
+    /// deserialization.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::Octocrab::builder()
+    ///     .lenient_deserialization(true)
+    ///     .build()?;
+    /// let repo = octocrab.repos("owner", "repo").get().await?;
+    /// let signoff = repo.extra.get("web_commit_signoff_required");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn lenient_deserialization(mut self, lenient: bool) -> Self {
+        self.config.lenient_deserialization = lenient;
+        self
+    }
+
+    /// Set how long the default client keeps an idle connection open before
+    /// closing it. Defaults to 90 seconds.
+    #[cfg(feature = "default-client")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "default-client")))]
+    pub fn set_pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.config.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Insert `layer` right before requests are dispatched over the network,
+    /// after the `Authorization`, `User-Agent` and any extra headers have been
+    /// set and the URI has been made absolute. Use it for middleware that needs
+    /// to see the final request, such as a gateway requiring requests to be
+    /// signed.
+    /// ```no_run
+    /// # fn run() -> octocrab::Result<()> {
+    /// use http::{HeaderValue, Request};
+    /// use octocrab::OctoBody;
+    /// use tower::util::MapRequestLayer;
+    ///
+    /// let octocrab = octocrab::Octocrab::builder()
+    ///     .personal_token("token".to_string())
+    ///     .with_post_auth_layer(MapRequestLayer::new(|mut request: Request<OctoBody>| {
+    ///         let signature = HeaderValue::from_static("computed signature");
+    ///         request.headers_mut().insert("x-signature", signature);
+    ///         request
+    ///     }))
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "default-client")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "default-client")))]
+    pub fn with_post_auth_layer<L>(mut self, layer: L) -> Self
+    where
+        L: Layer<service::DispatchService> + Send + 'static,
+        L::Service: Service<Request<OctoBody>, Response = Response<hyper::body::Incoming>>
+            + Clone
+            + Send
+            + 'static,
+        <L::Service as Service<Request<OctoBody>>>::Future: Send + 'static,
+        <L::Service as Service<Request<OctoBody>>>::Error: Into<BoxError>,
+    {
+        self.config.post_auth_layer = Some(Box::new(move |service| {
+            service::DispatchService::new(layer.layer(service).map_err(Into::into))
+        }));
+        self
+    }
+
     /// Set the connect timeout.
     #[cfg(feature = "timeout")]
     #[cfg_attr(docsrs, doc(cfg(feature = "timeout")))]
@@ -639,6 +783,11 @@ impl OctocrabBuilder<NoSvc, DefaultOctocrabBuilderConfig, NoAuth, NotLayerReady>
         Ok(self)
     }
 
+    /// Wraps `connector` in a service retrying requests as configured.
+    ///
+    /// Predicates of a [`RetryConfig::Predicate`] aren't given the error in
+    /// the body of responses at this level; [`OctocrabBuilder::build`] retries
+    /// further up its stack so that they are.
     #[cfg(feature = "retry")]
     #[cfg_attr(docsrs, doc(cfg(feature = "retry")))]
     pub fn set_connector_retry_service<S>(
@@ -686,10 +835,21 @@ impl OctocrabBuilder<NoSvc, DefaultOctocrabBuilderConfig, NoAuth, NotLayerReady>
                     .map_err(Into::into)
                     .context(error::OtherSnafu)?; // enabled the `rustls-native-certs` feature in hyper-rustls
 
-                builder
-                    .https_or_http() //  Disable .https_only() during tests until: https://github.com/LukeMathWalker/wiremock-rs/issues/58 is resolved. Alternatively we can use conditional compilation to only enable this feature in tests, but it becomes rather ugly with integration tests.
-                    .enable_http1()
-                    .build()
+                // Plain HTTP is allowed for `http://` base URIs because wiremock
+                // can't serve HTTPS: https://github.com/LukeMathWalker/wiremock-rs/issues/58
+                let allow_http = self.config.allow_http.unwrap_or_else(|| {
+                    self.config
+                        .base_uri
+                        .iter()
+                        .chain(&self.config.upload_uri)
+                        .any(|uri| uri.scheme() == Some(&http::uri::Scheme::HTTP))
+                });
+                let builder = if allow_http {
+                    builder.https_or_http()
+                } else {
+                    builder.https_only()
+                };
+                builder.enable_http1().build()
             };
 
             #[cfg(all(feature = "opentls", not(feature = "rustls")))]
@@ -698,12 +858,31 @@ impl OctocrabBuilder<NoSvc, DefaultOctocrabBuilderConfig, NoAuth, NotLayerReady>
             #[cfg(feature = "timeout")]
             let connector = self.set_connect_timeout_service(connector);
 
-            hyper_util::client::legacy::Client::builder(hyper_util::rt::TokioExecutor::new())
-                .build(connector)
+            let mut builder =
+                hyper_util::client::legacy::Client::builder(hyper_util::rt::TokioExecutor::new());
+            // Without a timer the pool never closes idle connections.
+            builder.pool_timer(hyper_util::rt::TokioTimer::new());
+            if let Some(max_idle) = self.config.pool_max_idle_per_host {
+                builder.pool_max_idle_per_host(max_idle);
+            }
+            if let Some(timeout) = self.config.pool_idle_timeout {
+                builder.pool_idle_timeout(timeout);
+            }
+            builder.build(connector)
         };
 
-        #[cfg(feature = "retry")]
-        let client = self.set_connector_retry_service(client);
+        // Only box the client when there is a layer to put in front of it.
+        let client = client.map_err(BoxError::from);
+        let client = match self.config.post_auth_layer {
+            Some(layer) => tower::util::Either::Left(layer(service::DispatchService::new(client))),
+            None => tower::util::Either::Right(client),
+        };
+
+        // Layered inside the trace layer so that the request's span is the
+        // current one when its context is propagated.
+        #[cfg(feature = "opentelemetry")]
+        let client =
+            crate::service::middleware::opentelemetry::OpenTelemetryLayer::new().layer(client);
 
         #[cfg(feature = "tracing")]
         let client = TraceLayer::new_for_http()
@@ -808,6 +987,7 @@ impl OctocrabBuilder<NoSvc, DefaultOctocrabBuilderConfig, NoAuth, NotLayerReady>
             ));
         }
 
+        let default_headers = hmap.clone();
         let client = ExtraHeadersLayer::new(Arc::new(hmap)).layer(client);
 
         let client = MapResponseBodyLayer::new(|body| {
@@ -815,6 +995,12 @@ impl OctocrabBuilder<NoSvc, DefaultOctocrabBuilderConfig, NoAuth, NotLayerReady>
         })
         .layer(client);
 
+        // Retried above the body mapping so that predicates can be handed the
+        // error GitHub returned.
+        #[cfg(feature = "retry")]
+        let client = RetryLayer::new(self.config.retry_config.clone())
+            .layer(crate::service::middleware::retry::ReadErrorBodyLayer.layer(client));
+
         let base_uri = self
             .config
             .base_uri
@@ -829,13 +1015,29 @@ impl OctocrabBuilder<NoSvc, DefaultOctocrabBuilderConfig, NoAuth, NotLayerReady>
 
         let client = BaseUriLayer::new(base_uri.clone()).layer(client);
 
+        let trusted_authorities =
+            IntoIterator::into_iter([base_uri.authority(), upload_uri.authority()])
+                .flatten()
+                .cloned()
+                .collect();
+
+        let request_defaults = RequestDefaults {
+            base_uri: Some(base_uri.clone()),
+            headers: default_headers,
+            auth_header: auth_header.clone(),
+        };
         let client = AuthHeaderLayer::new(auth_header, base_uri, upload_uri).layer(client);
 
-        if let Some(executor) = self.executor {
-            return Ok(Octocrab::new_with_executor(client, auth_state, executor));
-        }
+        let octocrab = if let Some(executor) = self.executor {
+            Octocrab::new_with_executor(client, auth_state, executor)
+        } else {
+            Octocrab::new(client, auth_state)
+        };
 
-        Ok(Octocrab::new(client, auth_state))
+        let mut octocrab = octocrab.with_trusted_authorities(trusted_authorities);
+        octocrab.lenient_deserialization = self.config.lenient_deserialization;
+        octocrab.request_defaults = Arc::new(request_defaults);
+        Ok(octocrab)
     }
 }
 
@@ -853,6 +1055,16 @@ pub struct DefaultOctocrabBuilderConfig {
     upload_uri: Option<Uri>,
     #[cfg(feature = "retry")]
     retry_config: RetryConfig,
+    #[cfg(feature = "rustls")]
+    allow_http: Option<bool>,
+    lenient_deserialization: bool,
+    #[cfg(feature = "default-client")]
+    pool_max_idle_per_host: Option<usize>,
+    #[cfg(feature = "default-client")]
+    pool_idle_timeout: Option<Duration>,
+    #[cfg(feature = "default-client")]
+    post_auth_layer:
+        Option<Box<dyn FnOnce(service::DispatchService) -> service::DispatchService + Send>>,
 }
 
 impl Default for DefaultOctocrabBuilderConfig {
@@ -871,6 +1083,15 @@ impl Default for DefaultOctocrabBuilderConfig {
             upload_uri: None,
             #[cfg(feature = "retry")]
             retry_config: RetryConfig::Simple(3),
+            #[cfg(feature = "rustls")]
+            allow_http: None,
+            lenient_deserialization: false,
+            #[cfg(feature = "default-client")]
+            pool_max_idle_per_host: None,
+            #[cfg(feature = "default-client")]
+            pool_idle_timeout: None,
+            #[cfg(feature = "default-client")]
+            post_auth_layer: None,
         }
     }
 }
@@ -995,6 +1216,29 @@ pub type OctocrabService = Buffer<
 pub struct Octocrab {
     client: OctocrabService,
     auth_state: AuthState,
+    /// Hosts besides relative URIs that may receive our credentials, i.e. the
+    /// configured base and upload URIs.
+    trusted_authorities: Arc<Vec<http::uri::Authority>>,
+    /// The most recent `/rate_limit` response, shared between clones so that
+    /// [`ratelimit::RateLimitHandler::cache_for`] can avoid repeated requests.
+    rate_limit_cache: Arc<RwLock<Option<(web_time::Instant, models::RateLimit)>>>,
+    /// Whether responses are marked to capture unmodelled fields, see
+    /// [`OctocrabBuilder::lenient_deserialization`].
+    lenient_deserialization: bool,
+    /// What the default client's middleware adds to requests, so that
+    /// [`Octocrab::request_builder`] can add it up front.
+    request_defaults: Arc<RequestDefaults>,
+}
+
+/// The base URI and headers the default client's middleware applies to every
+/// request.
+#[derive(Debug, Default)]
+struct RequestDefaults {
+    base_uri: Option<Uri>,
+    headers: Vec<(HeaderName, HeaderValue)>,
+    /// The `Authorization` header of personal and user access tokens, which
+    /// leave the client's [`AuthState`] empty.
+    auth_header: Option<HeaderValue>,
 }
 
 impl fmt::Debug for Octocrab {
@@ -1025,6 +1269,50 @@ impl Octocrab {
         OctocrabBuilder::new_empty().with_config(DefaultOctocrabBuilderConfig::default())
     }
 
+    /// Creates a new `Octocrab` that sends every request through `service`.
+    ///
+    /// No middleware is added, so requests reach `service` with a path-only
+    /// URI and without the default headers. This makes it easy to test code
+    /// built on octocrab against canned responses, e.g. with
+    /// [`tower::service_fn`].
+    /// ```
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use std::convert::Infallible;
+    /// use bytes::Bytes;
+    /// use http_body_util::Full;
+    /// use octocrab::{AuthState, Octocrab};
+    ///
+    /// let service = tower::service_fn(|request: http::Request<octocrab::OctoBody>| async move {
+    ///     assert_eq!(request.uri().path(), "/repos/owner/repo/branches/main/protection");
+    ///     Ok::<_, Infallible>(
+    ///         http::Response::builder()
+    ///             .status(204)
+    ///             .body(Full::new(Bytes::new()))
+    ///             .unwrap(),
+    ///     )
+    /// });
+    /// let octocrab = Octocrab::from_service(service, AuthState::None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_service<S, B>(service: S, auth_state: AuthState) -> Self
+    where
+        S: Service<Request<OctoBody>, Response = Response<B>> + Send + 'static,
+        S::Future: Send + 'static,
+        S::Error: Into<BoxError>,
+        B: http_body::Body<Data = bytes::Bytes> + Send + Sync + 'static,
+        B::Error: Into<BoxError>,
+    {
+        // Transform response body to `BoxBody<Bytes, crate::Error>` and use type erased error to avoid type parameters.
+        let service = MapResponseBodyLayer::new(|b: B| {
+            b.map_err(|e| ServiceSnafu.into_error(e.into())).boxed()
+        })
+        .layer(service)
+        .map_err(|e| e.into());
+
+        Self::new(service, auth_state)
+    }
+
     /// Creates a new `Octocrab`.
     fn new<S>(service: S, auth_state: AuthState) -> Self
     where
@@ -1039,6 +1327,10 @@ impl Octocrab {
         Self {
             client: service,
             auth_state,
+            trusted_authorities: Arc::default(),
+            rate_limit_cache: Arc::default(),
+            lenient_deserialization: false,
+            request_defaults: Arc::default(),
         }
     }
 
@@ -1064,9 +1356,19 @@ impl Octocrab {
         Self {
             client: service,
             auth_state,
+            trusted_authorities: Arc::default(),
+            rate_limit_cache: Arc::default(),
+            lenient_deserialization: false,
+            request_defaults: Arc::default(),
         }
     }
 
+    #[cfg(feature = "default-client")]
+    fn with_trusted_authorities(mut self, authorities: Vec<http::uri::Authority>) -> Self {
+        self.trusted_authorities = Arc::new(authorities);
+        self
+    }
+
     /// Returns a new `Octocrab` based on the current builder but
     /// authorizing via a specific installation ID.
     /// Typically you will first construct an `Octocrab` using
@@ -1089,6 +1391,10 @@ impl Octocrab {
                 installation: id,
                 token: CachedToken::default(),
             },
+            trusted_authorities: self.trusted_authorities.clone(),
+            rate_limit_cache: Arc::default(),
+            lenient_deserialization: self.lenient_deserialization,
+            request_defaults: self.request_defaults.clone(),
         })
     }
 
@@ -1214,6 +1520,33 @@ impl Octocrab {
         repos::RepoHandler::new(self, RepoRef::ByOwnerAndName(owner.into(), repo.into()))
     }
 
+    /// Creates a [`repos::RepoHandler`] for a [`models::Repository`], e.g. one
+    /// received in a webhook payload. The owner and name are taken from the
+    /// repository's `full_name`, falling back to its owner's login and `name`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::instance();
+    /// let repository = octocrab.repos("owner", "repo").get().await?;
+    /// let license = octocrab.repos_from(&repository)?.license().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn repos_from(&self, repository: &models::Repository) -> Result<repos::RepoHandler<'_>> {
+        let (owner, repo) = match repository.full_name_parts() {
+            Some(parts) => parts,
+            None => match repository.owner_login() {
+                Some(owner) => (owner, repository.name.as_str()),
+                None => {
+                    return Err(Error::Other {
+                        source: "repository has neither a `full_name` nor an `owner`".into(),
+                        backtrace: Backtrace::capture(),
+                    })
+                }
+            },
+        };
+        Ok(self.repos(owner, repo))
+    }
+
     /// Creates a [`repos::RepoHandler`] for the repo specified at repository ID,
     /// that allows you to access GitHub's repository API.
     pub fn repos_by_id(&self, id: impl Into<RepositoryId>) -> repos::RepoHandler {
@@ -1309,6 +1642,183 @@ impl Octocrab {
         self.post("/graphql", Some(&serde_json::json!(payload)))
             .await
     }
+
+    /// Sends a paginated graphql query to GitHub, following the connection's
+    /// `endCursor` until `hasNextPage` is `false`, and returns every node.
+    ///
+    /// - `query`: a query taking the cursor as a variable named
+    ///   `cursor_variable` (e.g. `$cursor: String`). The cursor is `null` for
+    ///   the first request.
+    /// - `variables`: any other variables the query requires.
+    /// - `connection_path`: the path from `data` to the connection, which
+    ///   must select `nodes` and `pageInfo { hasNextPage endCursor }`.
+    ///
+    /// ```no_run
+    ///# async fn run() -> octocrab::Result<()> {
+    /// let query = r#"
+    ///     query($owner: String!, $name: String!, $cursor: String) {
+    ///         repository(owner: $owner, name: $name) {
+    ///             issues(first: 100, after: $cursor) {
+    ///                 nodes { number title }
+    ///                 pageInfo { hasNextPage endCursor }
+    ///             }
+    ///         }
+    ///     }
+    /// "#;
+    /// let issues: Vec<serde_json::Value> = octocrab::instance()
+    ///     .graphql_paginate(
+    ///         query,
+    ///         Some(serde_json::json!({ "owner": "XAMPPRocky", "name": "octocrab" })),
+    ///         "cursor",
+    ///         &["repository", "issues"],
+    ///     )
+    ///     .await?;
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub async fn graphql_paginate<T: serde::de::DeserializeOwned>(
+        &self,
+        query: impl AsRef<str>,
+        variables: Option<serde_json::Value>,
+        cursor_variable: impl AsRef<str>,
+        connection_path: &[&str],
+    ) -> crate::Result<Vec<T>> {
+        let mut variables = match variables {
+            Some(serde_json::Value::Object(variables)) => variables,
+            _ => serde_json::Map::new(),
+        };
+        let mut cursor: Option<String> = None;
+        let mut nodes = Vec::new();
+
+        loop {
+            variables.insert(
+                cursor_variable.as_ref().to_owned(),
+                serde_json::json!(cursor),
+            );
+            let response: serde_json::Value = self
+                .graphql(&serde_json::json!({
+                    "query": query.as_ref(),
+                    "variables": variables,
+                }))
+                .await?;
+            map_graphql_errors(&response)?;
+
+            let connection = connection_path
+                .iter()
+                .try_fold(&response["data"], |value, key| value.get(key))
+                .filter(|connection| !connection.is_null())
+                .ok_or_else(|| {
+                    Box::from(format!(
+                        "GraphQL response has no connection at `{}`",
+                        connection_path.join(".")
+                    ))
+                })
+                .context(error::OtherSnafu)?;
+
+            let models::graphql::GraphQLConnection {
+                nodes: mut page,
+                page_info,
+                ..
+            } = serde_json::from_value(connection.clone()).context(SerdeSnafu)?;
+            nodes.append(&mut page);
+
+            match page_info.end_cursor {
+                Some(end_cursor) if page_info.has_next_page => cursor = Some(end_cursor),
+                _ => break,
+            }
+        }
+
+        Ok(nodes)
+    }
+
+    /// Looks up the object with the global node ID `node_id`, such as the
+    /// `node_id` of most REST models, as a `T`. Returns `None` if no such
+    /// object exists, and an error if it isn't a `T`.
+    /// ```no_run
+    ///# async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::graphql::GraphQLNode;
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// struct Issue {
+    ///     number: u64,
+    ///     title: String,
+    /// }
+    ///
+    /// impl GraphQLNode for Issue {
+    ///     const TYPE_NAME: &'static str = "Issue";
+    ///     const FIELDS: &'static str = "number title";
+    /// }
+    ///
+    /// let issue: Option<Issue> = octocrab::instance().node("I_kwDOD4Ga3s5zGq5L").await?;
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub async fn node<T: models::graphql::GraphQLNode>(
+        &self,
+        node_id: impl AsRef<str>,
+    ) -> crate::Result<Option<T>> {
+        let query = format!(
+            "query($id: ID!) {{ node(id: $id) {{ __typename ... on {} {{ {} }} }} }}",
+            T::TYPE_NAME,
+            T::FIELDS
+        );
+        let response: serde_json::Value = self
+            .graphql(&serde_json::json!({
+                "query": query,
+                "variables": { "id": node_id.as_ref() },
+            }))
+            .await?;
+        let node = &response["data"]["node"];
+        if node.is_null() {
+            // GitHub reports unknown IDs with a `NOT_FOUND` error next to the
+            // `null` node.
+            let not_found = response["errors"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .all(|error| error["type"] == "NOT_FOUND");
+            if not_found {
+                return Ok(None);
+            }
+        }
+        map_graphql_errors(&response)?;
+        match node["__typename"].as_str() {
+            Some(type_name) if type_name == T::TYPE_NAME => serde_json::from_value(node.clone())
+                .map(Some)
+                .context(SerdeSnafu),
+            type_name => Err(Error::Other {
+                source: Box::from(format!(
+                    "node `{}` is a `{}`, not a `{}`",
+                    node_id.as_ref(),
+                    type_name.unwrap_or("unknown type"),
+                    T::TYPE_NAME
+                )),
+                backtrace: Backtrace::capture(),
+            }),
+        }
+    }
+}
+
+impl Octocrab {
+    /// Returns a handle sending requests labelled with the operation `name`,
+    /// e.g. `issues.list`, so traces and metrics can tell API calls apart.
+    ///
+    /// Every typed handler labels its requests with the handler and method
+    /// it was sent from, e.g. `repos.releases.get` or `pulls.merge`; requests
+    /// sent through [`Octocrab::get`] and friends are unlabelled. Use this
+    /// handle to label your own calls.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let repo: octocrab::models::Repository = octocrab::instance()
+    ///     .operation("repos.get")
+    ///     .get("/repos/owner/repo", None::<&()>)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn operation(&self, name: &'static str) -> Operation<'_> {
+        Operation::new(self, name)
+    }
 }
 
 /// # HTTP Methods
@@ -1362,6 +1872,29 @@ impl Octocrab {
         self.get_with_headers(route, parameters, None).await
     }
 
+    /// Send a `GET` request to `route` with optional query parameters,
+    /// returning the body of the response, or `None` if GitHub responded with
+    /// `404 Not Found`. Any other error is returned as with [`Octocrab::get`].
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let repo: Option<octocrab::models::Repository> = octocrab::instance()
+    ///     .get_opt("/repos/owner/repo", None::<&()>)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_opt<R, A, P>(&self, route: A, parameters: Option<&P>) -> Result<Option<R>>
+    where
+        A: AsRef<str>,
+        P: Serialize + ?Sized,
+        R: FromResponse,
+    {
+        let response = self
+            ._get(self.parameterized_uri(route, parameters)?)
+            .await?;
+        from_response_opt(response).await
+    }
+
     /// Send a `GET` request with no additional post-processing.
     pub async fn _get(
         &self,
@@ -1457,6 +1990,21 @@ impl Octocrab {
         R::from_response(crate::map_github_error(response).await?).await
     }
 
+    /// Send a `PATCH` request to `route` with an optional body, returning the
+    /// body of the response, or `None` if GitHub responded with
+    /// `404 Not Found`.
+    pub async fn patch_opt<R, A, B>(&self, route: A, body: Option<&B>) -> Result<Option<R>>
+    where
+        A: AsRef<str>,
+        B: Serialize + ?Sized,
+        R: FromResponse,
+    {
+        let response = self
+            ._patch(self.parameterized_uri(route, None::<&()>)?, body)
+            .await?;
+        from_response_opt(response).await
+    }
+
     /// Send a `PATCH` request with no additional post-processing.
     pub async fn _patch<B: Serialize + ?Sized>(
         &self,
@@ -1486,6 +2034,21 @@ impl Octocrab {
         R::from_response(crate::map_github_error(response).await?).await
     }
 
+    /// Send a `PUT` request to `route` with an optional body, returning the
+    /// body of the response, or `None` if GitHub responded with
+    /// `404 Not Found`.
+    pub async fn put_opt<R, A, B>(&self, route: A, body: Option<&B>) -> Result<Option<R>>
+    where
+        A: AsRef<str>,
+        B: Serialize + ?Sized,
+        R: FromResponse,
+    {
+        let response = self
+            ._put(self.parameterized_uri(route, None::<&()>)?, body)
+            .await?;
+        from_response_opt(response).await
+    }
+
     /// Send a `PATCH` request with no additional post-processing.
     pub async fn _put<B: Serialize + ?Sized>(
         &self,
@@ -1637,6 +2200,72 @@ impl Octocrab {
         // })?;
     }
 
+    /// Builds the request that [`Octocrab::execute`] would send for `method`
+    /// and `route`, without sending it. The returned request has the client's
+    /// base URI, default headers such as the user agent, the JSON `body` with
+    /// its content type, and the `Authorization` header, so it can be
+    /// inspected, modified, signed or recorded before being passed to
+    /// [`Octocrab::execute`], which leaves an `Authorization` header that is
+    /// already set alone.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::instance();
+    /// let mut request = octocrab
+    ///     .request_builder(
+    ///         http::Method::POST,
+    ///         "/repos/owner/repo/issues",
+    ///         Some(&serde_json::json!({ "title": "Found a bug" })),
+    ///     )
+    ///     .await?;
+    /// request
+    ///     .headers_mut()
+    ///     .insert("x-request-id", http::HeaderValue::from_static("abc123"));
+    /// let response = octocrab.execute(request).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn request_builder<A, B>(
+        &self,
+        method: Method,
+        route: A,
+        body: Option<&B>,
+    ) -> Result<http::Request<String>>
+    where
+        A: AsRef<str>,
+        B: Serialize + ?Sized,
+    {
+        let mut builder = Builder::new()
+            .method(method)
+            .uri(self.parameterized_uri(route, None::<&()>)?);
+        let body = match body {
+            Some(body) => {
+                builder = builder.header(http::header::CONTENT_TYPE, "application/json");
+                serde_json::to_string(body).context(SerdeSnafu)?
+            }
+            None => {
+                builder = builder.header(http::header::CONTENT_LENGTH, "0");
+                String::new()
+            }
+        };
+        let (mut parts, body) = builder.body(body).context(HttpSnafu)?.into_parts();
+        let defaults = &self.request_defaults;
+        if let Some(base_uri) = &defaults.base_uri {
+            parts.uri = service::middleware::base_uri::overwrite_base_uri(
+                base_uri,
+                std::mem::take(&mut parts.uri),
+            );
+        }
+        if !defaults.headers.is_empty() {
+            parts.headers.extend(defaults.headers.iter().cloned());
+            parts
+                .extensions
+                .insert(service::middleware::extra_headers::HeadersApplied);
+        }
+        self.authorize(&mut parts).await?;
+
+        Ok(http::Request::from_parts(parts, body))
+    }
+
     /// Execute the given `request` using octocrab's Client.
     pub async fn execute(
         &self,
@@ -1644,9 +2273,35 @@ impl Octocrab {
     ) -> Result<http::Response<BoxBody<Bytes, crate::Error>>> {
         let (mut parts, body) = request.into_parts();
         let body: OctoBody = body.into();
-        // Saved request that we can retry later if necessary
+        if !parts.headers.contains_key(http::header::AUTHORIZATION) {
+            self.authorize(&mut parts).await?;
+        }
+        let uri = from_response::RequestUri(parts.uri.clone());
+
+        let request = http::Request::from_parts(parts, body);
+
+        let mut response = self.send(request).await?;
+        response.extensions_mut().insert(uri);
+        if self.lenient_deserialization {
+            response
+                .extensions_mut()
+                .insert(from_response::CaptureExtra);
+        }
+
+        let status = response.status();
+        if StatusCode::UNAUTHORIZED == status {
+            if let AuthState::Installation { ref token, .. } = self.auth_state {
+                token.clear();
+            }
+        }
+        Ok(response)
+    }
+
+    /// Adds the `Authorization` header for the current authentication state
+    /// to `parts`, unless the request is destined for an untrusted host.
+    async fn authorize(&self, parts: &mut http::request::Parts) -> Result<()> {
         let auth_header: Option<HeaderValue> = match self.auth_state {
-            AuthState::None => None,
+            AuthState::None => self.request_defaults.auth_header.clone(),
             AuthState::App(ref app) => Some(
                 HeaderValue::from_str(format!("Bearer {}", app.generate_bearer_token()?).as_str())
                     .map_err(http::Error::from)
@@ -1684,11 +2339,16 @@ impl Octocrab {
         };
 
         if let Some(mut auth_header) = auth_header {
-            // Only set the auth_header if the authority (host) is empty (destined for
-            // GitHub). Otherwise, leave it off as we could have been redirected
+            // Only set the auth_header if the authority (host) is empty or one of
+            // the configured base URIs (destined for GitHub or a GitHub Enterprise
+            // Server). Otherwise, leave it off as we could have been redirected
             // away from GitHub (via follow_location_to_data()), and we don't
             // want to give our credentials to third-party services.
-            if parts.uri.authority().is_none() {
+            let trusted = match parts.uri.authority() {
+                None => true,
+                Some(authority) => self.trusted_authorities.contains(authority),
+            };
+            if trusted {
                 auth_header.set_sensitive(true);
                 parts
                     .headers
@@ -1696,17 +2356,7 @@ impl Octocrab {
             }
         }
 
-        let request = http::Request::from_parts(parts, body);
-
-        let response = self.send(request).await?;
-
-        let status = response.status();
-        if StatusCode::UNAUTHORIZED == status {
-            if let AuthState::Installation { ref token, .. } = self.auth_state {
-                token.clear();
-            }
-        }
-        Ok(response)
+        Ok(())
     }
 
     pub async fn follow_location_to_data(
@@ -1736,6 +2386,29 @@ impl Octocrab {
         }
     }
 
+    /// Fetches the page of results at `url`, such as a [`Page::next`] URL
+    /// persisted earlier, allowing pagination to be resumed later, e.g. after
+    /// a restart. The returned page's `next`/`prev` links work as usual.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::issues::Issue;
+    ///
+    /// let octocrab = octocrab::instance();
+    /// let saved: http::Uri = "https://api.github.com/repositories/1300192/issues?page=4"
+    ///     .parse()
+    ///     .unwrap();
+    /// let page = octocrab.page_from_url::<Issue>(saved).await?;
+    /// let rest = octocrab.all_pages(page).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn page_from_url<R: serde::de::DeserializeOwned>(
+        &self,
+        url: Uri,
+    ) -> crate::Result<Page<R>> {
+        self.get(url.to_string(), None::<&()>).await
+    }
+
     /// A convenience method to get all the results starting at a given
     /// page.
     pub async fn all_pages<R: serde::de::DeserializeOwned>(
@@ -1749,6 +2422,94 @@ impl Octocrab {
         }
         Ok(ret)
     }
+
+    /// Like [`Octocrab::all_pages`], but if fetching a page fails, the results
+    /// gathered up to that point are returned together with the error instead
+    /// of being discarded.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::instance();
+    /// let first_page = octocrab.issues("owner", "repo").list().send().await?;
+    /// let (issues, error) = octocrab.all_pages_lenient(first_page).await;
+    /// if let Some(error) = error {
+    ///     eprintln!("only fetched {} issues: {error}", issues.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn all_pages_lenient<R: serde::de::DeserializeOwned>(
+        &self,
+        mut page: Page<R>,
+    ) -> (Vec<R>, Option<crate::Error>) {
+        let mut ret = page.take_items();
+        loop {
+            match self.get_page(&page.next).await {
+                Ok(Some(mut next_page)) => {
+                    ret.append(&mut next_page.take_items());
+                    page = next_page;
+                }
+                Ok(None) => return (ret, None),
+                Err(error) => return (ret, Some(error)),
+            }
+        }
+    }
+
+    /// Like [`Octocrab::all_pages`], but collects the results into a map keyed
+    /// by `key_fn`. If several results share a key, the last one is kept.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::instance();
+    /// let first_page = octocrab.issues("owner", "repo").list().send().await?;
+    /// let issues_by_number = octocrab
+    ///     .all_pages_map(first_page, |issue| issue.number)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn all_pages_map<K, R, F>(
+        &self,
+        mut page: Page<R>,
+        key_fn: F,
+    ) -> crate::Result<std::collections::HashMap<K, R>>
+    where
+        K: Eq + std::hash::Hash,
+        R: serde::de::DeserializeOwned,
+        F: Fn(&R) -> K,
+    {
+        let mut ret = std::collections::HashMap::new();
+        loop {
+            ret.extend(
+                page.take_items()
+                    .into_iter()
+                    .map(|item| (key_fn(&item), item)),
+            );
+            match self.get_page(&page.next).await? {
+                Some(next_page) => page = next_page,
+                None => return Ok(ret),
+            }
+        }
+    }
+
+    /// Detects the version of the GitHub Enterprise Server instance the client
+    /// is talking to, by reading the `X-GitHub-Enterprise-Version` header of a
+    /// `GET /meta` request. Returns `None` on github.com.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::instance();
+    /// if let Some(version) = octocrab.detect_enterprise_version().await? {
+    ///     println!("GitHub Enterprise Server {version}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn detect_enterprise_version(&self) -> crate::Result<Option<String>> {
+        let response = crate::map_github_error(self._get("/meta").await?).await?;
+        Ok(response
+            .headers()
+            .get("x-github-enterprise-version")
+            .and_then(|version| version.to_str().ok())
+            .map(String::from))
+    }
 }
 
 #[cfg(test)]
@@ -19,9 +19,12 @@ pub mod code_scannings;
 pub mod commits;
 pub mod events;
 pub mod gists;
+pub mod graphql;
 pub mod hooks;
+pub mod interactions;
 pub mod issues;
 pub mod orgs;
+pub mod packages;
 pub mod pulls;
 pub mod reactions;
 pub mod repos;
@@ -103,15 +106,20 @@ id_type!(
     AppId,
     ArtifactId,
     AssetId,
+    AutolinkId,
     BranchProtectionRuleId,
     CardId,
     CheckSuiteId,
     CheckRunId,
     CodeScanningId,
     CommentId,
+    DeploymentId,
+    DeploymentStatusId,
+    DeployKeyId,
     InstallationId,
     IssueEventId,
     IssueId,
+    IssueTypeId,
     JobId,
     HookId,
     HookDeliveryId,
@@ -119,6 +127,8 @@ id_type!(
     MilestoneId,
     NotificationId,
     OrgId,
+    PackageVersionId,
+    PatRequestId,
     ProjectId,
     ProjectColumnId,
     PullRequestId,
@@ -131,6 +141,7 @@ id_type!(
     RunnerId,
     RunnerGroupId,
     RunnerLabelId,
+    RulesetId,
     StatusId,
     TeamId,
     TimelineEventId,
@@ -427,6 +438,30 @@ pub struct Author {
     pub email: Option<String>,
 }
 
+/// Deserialize a repository's primary language, which is usually a string or
+/// null but may also be a map of languages to bytes of code, as returned by
+/// `/languages`. In the latter case the language with the most code is used.
+fn deserialize_language<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Language {
+        Name(String),
+        Breakdown(HashMap<String, i64>),
+    }
+
+    Ok(match Option::<Language>::deserialize(deserializer)? {
+        Some(Language::Name(name)) => Some(name),
+        Some(Language::Breakdown(languages)) => languages
+            .into_iter()
+            .max_by_key(|(_, bytes)| *bytes)
+            .map(|(name, _)| name),
+        None => None,
+    })
+}
+
 /// If a string is empty then deserialize it as none
 fn empty_string_is_none<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
 where
@@ -600,6 +635,14 @@ pub struct StarGazer {
     pub user: Option<Author>,
 }
 
+/// A repository starred by a user, along with when it was starred.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct StarredRepository {
+    pub starred_at: Option<DateTime<Utc>>,
+    pub repo: Repository,
+}
+
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub struct Label {
@@ -644,6 +687,7 @@ pub struct Milestone {
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
 #[non_exhaustive]
 pub struct Repository {
     pub id: RepositoryId,
@@ -747,8 +791,12 @@ pub struct Repository {
     pub svn_url: Option<Url>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub homepage: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub language: Option<::serde_json::Value>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "deserialize_language"
+    )]
+    pub language: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub forks_count: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -827,6 +875,43 @@ pub struct Repository {
     pub parent: Option<Box<Repository>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source: Option<Box<Repository>>,
+    /// Fields returned by GitHub that aren't modelled by this struct (yet).
+    /// Only filled in by clients built with
+    /// [`lenient_deserialization`](crate::OctocrabBuilder::lenient_deserialization).
+    #[serde(skip)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+crate::from_response::impl_capture_extra!(Repository);
+
+impl Repository {
+    /// The login of the repository's owner, if present.
+    pub fn owner_login(&self) -> Option<&str> {
+        self.owner.as_ref().map(|owner| owner.login.as_str())
+    }
+
+    /// The owner and name parts of [`Repository::full_name`], e.g.
+    /// `("rust-lang", "rust")` for `rust-lang/rust`.
+    pub fn full_name_parts(&self) -> Option<(&str, &str)> {
+        self.full_name.as_deref()?.split_once('/')
+    }
+
+    /// The merge methods this repository allows for pull requests, in the
+    /// order merge, squash, rebase. GitHub only reports these settings to
+    /// users with push access; a missing setting is treated as allowed, which
+    /// is GitHub's default.
+    pub fn allowed_merge_methods(&self) -> Vec<crate::params::pulls::MergeMethod> {
+        use crate::params::pulls::MergeMethod;
+
+        IntoIterator::into_iter([
+            (self.allow_merge_commit, MergeMethod::Merge),
+            (self.allow_squash_merge, MergeMethod::Squash),
+            (self.allow_rebase_merge, MergeMethod::Rebase),
+        ])
+        .filter(|(allowed, _)| allowed.unwrap_or(true))
+        .map(|(_, method)| method)
+        .collect()
+    }
 }
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
@@ -866,6 +951,25 @@ pub struct License {
     pub featured: Option<bool>,
 }
 
+/// A topic returned by [`SearchHandler::topics`].
+///
+/// [`SearchHandler::topics`]: crate::search::SearchHandler::topics
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Topic {
+    pub name: String,
+    pub display_name: Option<String>,
+    pub short_description: Option<String>,
+    pub description: Option<String>,
+    pub created_by: Option<String>,
+    pub released: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub featured: bool,
+    pub curated: bool,
+    pub score: Option<f64>,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub struct Code {
@@ -876,6 +980,33 @@ pub struct Code {
     pub git_url: Url,
     pub html_url: Url,
     pub repository: Repository,
+    /// The fragments of the file that matched the search query, only present
+    /// when requested with [`QueryHandler::text_matches`].
+    ///
+    /// [`QueryHandler::text_matches`]: crate::search::QueryHandler::text_matches
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub text_matches: Vec<TextMatch>,
+}
+
+/// A fragment of a search result that matched the search query.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct TextMatch {
+    pub object_url: Option<Url>,
+    pub object_type: Option<String>,
+    /// The property of the object the fragment is taken from, e.g. `content`.
+    pub property: String,
+    pub fragment: String,
+    pub matches: Vec<TextMatchHighlight>,
+}
+
+/// A term within a [`TextMatch`] fragment matching the search query.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct TextMatchHighlight {
+    pub text: String,
+    /// The start and end offset of `text` within the fragment.
+    pub indices: Vec<u32>,
 }
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
@@ -1040,19 +1171,58 @@ pub struct Installation {
 #[non_exhaustive]
 pub struct InstallationPermissions {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub actions: Option<String>,
+    pub actions: Option<PermissionLevel>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub checks: Option<String>,
+    pub checks: Option<PermissionLevel>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub contents: Option<String>,
+    pub contents: Option<PermissionLevel>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub issues: Option<String>,
+    pub issues: Option<PermissionLevel>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub metadata: Option<String>,
+    pub metadata: Option<PermissionLevel>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub single_file: Option<String>,
+    pub single_file: Option<PermissionLevel>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub statuses: Option<String>,
+    pub statuses: Option<PermissionLevel>,
+    /// Permissions for any other area, e.g. `pull_requests` or `administration`.
+    #[serde(flatten)]
+    pub other: std::collections::BTreeMap<String, PermissionLevel>,
+}
+
+impl InstallationPermissions {
+    /// Returns the level granted for `area` (e.g. `"contents"`), if any.
+    pub fn get(&self, area: &str) -> Option<&PermissionLevel> {
+        match area {
+            "actions" => self.actions.as_ref(),
+            "checks" => self.checks.as_ref(),
+            "contents" => self.contents.as_ref(),
+            "issues" => self.issues.as_ref(),
+            "metadata" => self.metadata.as_ref(),
+            "single_file" => self.single_file.as_ref(),
+            "statuses" => self.statuses.as_ref(),
+            _ => self.other.get(area),
+        }
+    }
+
+    /// Whether write (or admin) access was granted for `area`.
+    pub fn has_write(&self, area: &str) -> bool {
+        matches!(
+            self.get(area),
+            Some(PermissionLevel::Write | PermissionLevel::Admin)
+        )
+    }
+}
+
+/// The level of access granted to an installation for a permission area.
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum PermissionLevel {
+    Read,
+    Write,
+    Admin,
+    #[serde(untagged)]
+    Other(String),
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -1093,6 +1263,39 @@ pub struct Resources {
     pub actions_runner_registration: Option<Rate>,
 }
 
+impl Resources {
+    /// Returns the rate for the given `resource`, if GitHub reported one.
+    pub fn get(&self, resource: RateLimitResource) -> Option<&Rate> {
+        match resource {
+            RateLimitResource::Core => Some(&self.core),
+            RateLimitResource::Search => Some(&self.search),
+            RateLimitResource::Graphql => self.graphql.as_ref(),
+            RateLimitResource::IntegrationManifest => self.integration_manifest.as_ref(),
+            RateLimitResource::Scim => self.scim.as_ref(),
+            RateLimitResource::SourceImport => self.source_import.as_ref(),
+            RateLimitResource::CodeScanningUpload => self.code_scanning_upload.as_ref(),
+            RateLimitResource::ActionsRunnerRegistration => {
+                self.actions_runner_registration.as_ref()
+            }
+        }
+    }
+}
+
+/// The named rate limit buckets returned by the `/rate_limit` endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum RateLimitResource {
+    Core,
+    Search,
+    Graphql,
+    IntegrationManifest,
+    Scim,
+    SourceImport,
+    CodeScanningUpload,
+    ActionsRunnerRegistration,
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Rate {
     pub limit: usize,
@@ -1106,7 +1309,8 @@ pub struct UserEmailInfo {
     pub email: String,
     pub primary: bool,
     pub verified: bool,
-    pub visibility: EmailVisibilityState,
+    /// Only set for the primary address.
+    pub visibility: Option<EmailVisibilityState>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
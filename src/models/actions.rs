@@ -42,3 +42,33 @@ pub struct SelfHostedRunnerToken {
     pub token: String,
     pub expires_at: DateTime<Utc>,
 }
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct RunnerGroup {
+    pub id: RunnerGroupId,
+    pub name: String,
+    pub visibility: RunnerGroupVisibility,
+    pub default: bool,
+    pub inherited: bool,
+    pub allows_public_repositories: bool,
+    pub runners_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_repositories_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restricted_to_workflows: Option<bool>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub selected_workflows: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum RunnerGroupVisibility {
+    /// Available to all repositories in the organization.
+    All,
+    /// Available only to the selected repositories.
+    Selected,
+    /// Available only to private repositories.
+    Private,
+}
@@ -28,6 +28,9 @@ pub struct CheckRun {
     pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
     pub name: String,
     pub pull_requests: Vec<PullRequest>,
+    /// The app that created the check run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app: Option<App>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -152,3 +152,26 @@ pub struct Location {
     pub start_column: i64,
     pub end_column: i64,
 }
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct CodeScanningAnalysis {
+    pub id: u64,
+    #[serde(rename = "ref")]
+    pub ref_field: String,
+    pub commit_sha: String,
+    pub analysis_key: String,
+    pub environment: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    pub error: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub results_count: u64,
+    pub rules_count: u64,
+    pub url: Url,
+    pub sarif_id: String,
+    pub tool: Tool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deletable: Option<bool>,
+    pub warning: String,
+}
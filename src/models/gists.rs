@@ -16,7 +16,14 @@ pub struct Gist {
     pub html_url: Url,
     pub id: String,
     pub node_id: String,
+    pub owner: Option<Author>,
     pub public: bool,
+    /// Whether the listing of `files` was truncated. Files are truncated
+    /// individually, see [`GistFile::truncated`].
+    pub truncated: Option<bool>,
+    /// The revisions of the gist, newest first. Only included when fetching a
+    /// single gist.
+    pub history: Option<Vec<GistCommit>>,
     pub updated_at: DateTime<Utc>,
     pub url: Url,
 }
@@ -43,6 +50,19 @@ pub struct GistCommit {
     pub url: Url,
 }
 
+#[non_exhaustive]
+#[derive(Debug, Deserialize)]
+pub struct GistComment {
+    pub id: CommentId,
+    pub node_id: String,
+    pub url: Url,
+    pub body: String,
+    pub user: Option<Author>,
+    pub author_association: AuthorAssociation,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
 #[non_exhaustive]
 #[derive(Debug, Deserialize)]
 pub struct GistChangeStatus {
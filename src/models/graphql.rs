@@ -0,0 +1,38 @@
+use super::*;
+
+/// A GraphQL [connection](https://docs.github.com/en/graphql/guides/using-pagination-in-the-graphql-api),
+/// i.e. a single page of `nodes` along with the information needed to fetch
+/// the next page.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GraphQLConnection<T> {
+    #[serde(default = "Vec::new")]
+    pub nodes: Vec<T>,
+    pub page_info: PageInfo,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_count: Option<u64>,
+}
+
+/// The `pageInfo` object of a GraphQL connection.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct PageInfo {
+    pub has_next_page: bool,
+    pub end_cursor: Option<String>,
+    #[serde(default)]
+    pub has_previous_page: bool,
+    #[serde(default)]
+    pub start_cursor: Option<String>,
+}
+
+/// A GraphQL object type that can be looked up by its global node ID with
+/// [`Octocrab::node`](crate::Octocrab::node).
+pub trait GraphQLNode: serde::de::DeserializeOwned {
+    /// The name of the GraphQL type, e.g. `Issue`.
+    const TYPE_NAME: &'static str;
+    /// The selection set to query on the type, e.g. `number title`. The
+    /// response's fields are camel-cased.
+    const FIELDS: &'static str;
+}
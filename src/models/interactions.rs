@@ -0,0 +1,55 @@
+use super::*;
+
+/// The group of GitHub users an interaction limit applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum InteractionGroup {
+    /// Limits interactions to users who have had an account for over 24
+    /// hours, or who have contributed before.
+    ExistingUsers,
+    /// Limits interactions to users who have contributed before.
+    ContributorsOnly,
+    /// Limits interactions to collaborators.
+    CollaboratorsOnly,
+}
+
+/// How long an interaction limit stays in place.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum InteractionExpiry {
+    OneDay,
+    ThreeDays,
+    OneWeek,
+    OneMonth,
+    SixMonths,
+}
+
+/// An interaction limit in place for a user, an organization or a repository.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct InteractionLimit {
+    pub limit: InteractionGroup,
+    /// Where the limit was set, e.g. `organization` for a repository limited
+    /// by its organization.
+    pub origin: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// The interaction limit to set, defaulting to an expiry of one day.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SetInteractionLimit {
+    pub limit: InteractionGroup,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiry: Option<InteractionExpiry>,
+}
+
+impl SetInteractionLimit {
+    pub fn new(limit: InteractionGroup) -> Self {
+        Self {
+            limit,
+            expiry: None,
+        }
+    }
+}
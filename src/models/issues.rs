@@ -1,6 +1,7 @@
 use super::*;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
 #[non_exhaustive]
 pub struct Issue {
     pub id: IssueId,
@@ -40,6 +41,33 @@ pub struct Issue {
     pub closed_by: Option<Author>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
+    /// The organization-level type of the issue, if any.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub issue_type: Option<IssueType>,
+    /// See [`Repository::extra`].
+    #[serde(skip)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+crate::from_response::impl_capture_extra!(Issue);
+
+/// An issue type configured for an organization.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct IssueType {
+    pub id: IssueTypeId,
+    pub node_id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -81,3 +109,117 @@ pub struct PullRequestLink {
     pub diff_url: Url,
     pub patch_url: Url,
 }
+
+/// An issue template from a repository's `.github/ISSUE_TEMPLATE` directory,
+/// either a Markdown template or a YAML issue form.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct IssueTemplate {
+    /// The path of the template file in the repository.
+    pub path: String,
+    pub name: String,
+    /// The template's `about` text, or an issue form's `description`.
+    pub about: Option<String>,
+    /// The default title of issues created from this template.
+    pub title: Option<String>,
+    pub labels: Vec<String>,
+    pub assignees: Vec<String>,
+    pub body: IssueTemplateBody,
+}
+
+/// The body of an [`IssueTemplate`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum IssueTemplateBody {
+    /// The Markdown following a template's front matter.
+    Markdown(String),
+    /// The elements of an issue form, e.g. `markdown`, `input` or `textarea`,
+    /// including their `validations` such as `required`.
+    Form(Vec<serde_json::Value>),
+}
+
+#[cfg(feature = "issue-templates")]
+impl IssueTemplate {
+    /// Parses the `contents` of the template file at `path`. Files ending in
+    /// `.yml` or `.yaml` are parsed as issue forms, everything else as
+    /// Markdown with YAML front matter.
+    pub fn parse(path: impl Into<String>, contents: &str) -> crate::Result<Self> {
+        #[derive(Deserialize)]
+        struct Header {
+            name: String,
+            #[serde(alias = "description")]
+            about: Option<String>,
+            title: Option<String>,
+            #[serde(default, deserialize_with = "comma_separated_or_list")]
+            labels: Vec<String>,
+            #[serde(default, deserialize_with = "comma_separated_or_list")]
+            assignees: Vec<String>,
+            #[serde(default)]
+            body: Vec<serde_json::Value>,
+        }
+
+        fn comma_separated_or_list<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            #[derive(Deserialize)]
+            #[serde(untagged)]
+            enum Values {
+                List(Vec<String>),
+                CommaSeparated(String),
+            }
+
+            Ok(match Option::<Values>::deserialize(deserializer)? {
+                Some(Values::List(values)) => values,
+                Some(Values::CommaSeparated(values)) => values
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|value| !value.is_empty())
+                    .map(String::from)
+                    .collect(),
+                None => Vec::new(),
+            })
+        }
+
+        let invalid = |message: &str| crate::Error::Other {
+            source: message.into(),
+            backtrace: snafu::Backtrace::capture(),
+        };
+        let path = path.into();
+        let is_form = path.ends_with(".yml") || path.ends_with(".yaml");
+
+        let (front_matter, markdown) = if is_form {
+            (contents, None)
+        } else {
+            let rest = contents
+                .trim_start()
+                .strip_prefix("---")
+                .ok_or_else(|| invalid("issue template has no front matter"))?;
+            let (front_matter, markdown) = rest
+                .split_once("\n---")
+                .ok_or_else(|| invalid("issue template front matter is not terminated"))?;
+            let markdown = markdown.split_once('\n').map_or("", |(_, body)| body);
+            (front_matter, Some(markdown))
+        };
+
+        let header: Header =
+            serde_norway::from_str(front_matter).map_err(|e| crate::Error::Other {
+                source: Box::new(e),
+                backtrace: snafu::Backtrace::capture(),
+            })?;
+
+        Ok(Self {
+            path,
+            name: header.name,
+            about: header.about,
+            title: header.title,
+            labels: header.labels,
+            assignees: header.assignees,
+            body: match markdown {
+                Some(markdown) => IssueTemplateBody::Markdown(markdown.to_string()),
+                None => IssueTemplateBody::Form(header.body),
+            },
+        })
+    }
+}
@@ -1,7 +1,11 @@
 use super::*;
+pub mod billing;
+pub mod pat_requests;
+pub mod rulesets;
 pub mod secrets;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
 #[non_exhaustive]
 pub struct Organization {
     pub login: String,
@@ -61,8 +65,14 @@ pub struct Organization {
     pub billing_email: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub plan: Option<Plan>,
+    /// Not returned by GitHub; see
+    /// [`default_repository_permission`](Self::default_repository_permission).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_repository_settings: Option<String>,
+    /// The base permission members have on the organization's repositories,
+    /// e.g. `read`, `write`, `admin` or `none`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_repository_permission: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub members_can_create_repositories: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -75,8 +85,13 @@ pub struct Organization {
     pub members_can_create_private_repositories: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub members_can_create_internal_repositories: Option<bool>,
+    /// See [`Repository::extra`].
+    #[serde(skip)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
+crate::from_response::impl_capture_extra!(Organization);
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MembershipInvitation {
     pub url: Url,
@@ -87,10 +102,28 @@ pub struct MembershipInvitation {
     pub user: Author,
 }
 
+/// A user's membership of an organization.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct OrgMembership {
+    pub url: Url,
+    /// `active`, or `pending` until the user accepts their invitation.
+    pub state: String,
+    /// `admin`, `member` or `billing_manager`.
+    pub role: String,
+    pub organization_url: Url,
+    pub organization: Organization,
+    pub user: Option<Author>,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub struct Plan {
     pub name: String,
     pub space: i64,
     pub private_repos: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filled_seats: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seats: Option<i64>,
 }
@@ -0,0 +1,33 @@
+use super::*;
+
+/// The GitHub Actions minutes used by an organization in the current billing
+/// cycle.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ActionsBillingUsage {
+    pub total_minutes_used: u64,
+    pub total_paid_minutes_used: f64,
+    pub included_minutes: u64,
+    /// Minutes used per runner operating system, e.g. `UBUNTU` or `MACOS`.
+    pub minutes_used_breakdown: HashMap<String, u64>,
+}
+
+/// The GitHub Packages bandwidth used by an organization in the current
+/// billing cycle.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PackagesBillingUsage {
+    pub total_gigabytes_bandwidth_used: u64,
+    pub total_paid_gigabytes_bandwidth_used: u64,
+    pub included_gigabytes_bandwidth: u64,
+}
+
+/// The storage used by an organization's GitHub Actions artifacts and GitHub
+/// Packages in the current billing cycle.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct SharedStorageBillingUsage {
+    pub days_left_in_billing_cycle: u64,
+    pub estimated_paid_storage_for_month: f64,
+    pub estimated_storage_for_month: u64,
+}
@@ -0,0 +1,45 @@
+use super::*;
+
+/// A request to access an organization's resources with a fine-grained
+/// personal access token.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PatRequest {
+    pub id: PatRequestId,
+    /// The reason the requester gave for the access.
+    pub reason: Option<String>,
+    pub owner: SimpleUser,
+    pub permissions: PatRequestPermissions,
+    /// Whether the token accesses `none`, `all` or a `subset` of the
+    /// organization's repositories.
+    pub repository_selection: String,
+    pub repositories_url: Url,
+    pub created_at: DateTime<Utc>,
+    pub token_id: u64,
+    pub token_name: String,
+    pub token_expired: bool,
+    pub token_expires_at: Option<DateTime<Utc>>,
+    pub token_last_used_at: Option<DateTime<Utc>>,
+}
+
+/// The permissions requested, each mapping a permission name to its access
+/// level, e.g. `contents` to `read`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PatRequestPermissions {
+    #[serde(default)]
+    pub organization: HashMap<String, String>,
+    #[serde(default)]
+    pub repository: HashMap<String, String>,
+    #[serde(default)]
+    pub other: HashMap<String, String>,
+}
+
+/// How to respond to a personal access token request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum PatRequestAction {
+    Approve,
+    Deny,
+}
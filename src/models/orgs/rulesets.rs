@@ -0,0 +1,147 @@
+use super::super::*;
+
+/// What a ruleset applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum RulesetTarget {
+    Branch,
+    Tag,
+    Push,
+}
+
+/// Whether a ruleset is enforced. `Evaluate` only reports would-be
+/// violations and is available to GitHub Enterprise organizations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum RulesetEnforcement {
+    Disabled,
+    Active,
+    Evaluate,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum BypassActorType {
+    Integration,
+    OrganizationAdmin,
+    RepositoryRole,
+    Team,
+    DeployKey,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum BypassMode {
+    Always,
+    PullRequest,
+}
+
+/// An actor allowed to bypass a ruleset.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BypassActor {
+    /// The ID of the actor. Ignored for `OrganizationAdmin`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor_id: Option<u64>,
+    pub actor_type: BypassActorType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bypass_mode: Option<BypassMode>,
+}
+
+/// The conditions selecting which refs and repositories a ruleset applies to.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RulesetConditions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ref_name: Option<RefNameCondition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repository_name: Option<RepositoryNameCondition>,
+}
+
+/// Patterns of ref names to include or exclude, e.g. `refs/heads/main` or
+/// `~DEFAULT_BRANCH`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RefNameCondition {
+    #[serde(default)]
+    pub include: Vec<String>,
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+/// Patterns of repository names to include or exclude, e.g. `service-*` or
+/// `~ALL`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RepositoryNameCondition {
+    #[serde(default)]
+    pub include: Vec<String>,
+    #[serde(default)]
+    pub exclude: Vec<String>,
+    /// Whether renaming a targeted repository is prevented.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protected: Option<bool>,
+}
+
+/// A single rule of a ruleset, e.g. `{"type": "deletion"}` or
+/// `{"type": "pull_request", "parameters": {...}}`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RulesetRule {
+    pub r#type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Ruleset {
+    pub id: RulesetId,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<RulesetTarget>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_type: Option<String>,
+    pub source: String,
+    pub enforcement: RulesetEnforcement,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bypass_actors: Option<Vec<BypassActor>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<RulesetConditions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rules: Option<Vec<RulesetRule>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// The body of a request creating or updating an organization ruleset.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OrgRulesetRequest {
+    pub name: String,
+    pub enforcement: RulesetEnforcement,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<RulesetTarget>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bypass_actors: Option<Vec<BypassActor>>,
+    /// Which refs and repositories the ruleset applies to. Organization
+    /// rulesets need a `repository_name` condition.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<RulesetConditions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rules: Option<Vec<RulesetRule>>,
+}
+
+impl OrgRulesetRequest {
+    pub fn new(name: impl Into<String>, enforcement: RulesetEnforcement) -> Self {
+        Self {
+            name: name.into(),
+            enforcement,
+            target: None,
+            bypass_actors: None,
+            conditions: None,
+            rules: None,
+        }
+    }
+}
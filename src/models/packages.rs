@@ -0,0 +1,72 @@
+use super::*;
+
+/// The registry a package is published to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum PackageType {
+    Npm,
+    Maven,
+    Rubygems,
+    Docker,
+    Nuget,
+    Container,
+}
+
+impl std::fmt::Display for PackageType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Npm => "npm",
+            Self::Maven => "maven",
+            Self::Rubygems => "rubygems",
+            Self::Docker => "docker",
+            Self::Nuget => "nuget",
+            Self::Container => "container",
+        })
+    }
+}
+
+/// A published version of a package.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PackageVersion {
+    pub id: PackageVersionId,
+    /// The version's name, e.g. a semantic version or a container digest.
+    pub name: String,
+    pub url: Url,
+    pub package_html_url: Url,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_url: Option<Url>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<PackageVersionMetadata>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PackageVersionMetadata {
+    pub package_type: PackageType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container: Option<ContainerMetadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub docker: Option<DockerMetadata>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ContainerMetadata {
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct DockerMetadata {
+    pub tag: Vec<String>,
+}
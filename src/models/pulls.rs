@@ -2,6 +2,7 @@ use super::*;
 use crate::models::commits::CommentReactions;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
 #[non_exhaustive]
 pub struct PullRequest {
     pub url: String,
@@ -102,8 +103,13 @@ pub struct PullRequest {
     pub review_comments: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub comments: Option<u64>,
+    /// See [`Repository::extra`].
+    #[serde(skip)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
+crate::from_response::impl_capture_extra!(PullRequest);
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub struct Head {
@@ -458,6 +464,15 @@ pub struct Merge {
     pub merged: bool,
 }
 
+/// Whether a pull request can be merged, as computed by GitHub.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Mergeability {
+    /// `None` if GitHub hasn't finished computing it yet.
+    pub mergeable: Option<bool>,
+    pub mergeable_state: Option<MergeableState>,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
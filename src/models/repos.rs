@@ -8,8 +8,11 @@ use snafu::ResultExt;
 use url::Url;
 
 pub mod dependabot;
+pub mod deployments;
+pub mod git;
 pub mod secret_scanning_alert;
 pub mod secrets;
+pub mod stats;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -192,11 +195,40 @@ pub struct Content {
     pub git_url: Option<String>,
     pub download_url: Option<String>,
     pub r#type: String,
+    /// The path a symlink points to. Only set when `type` is `symlink`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    /// The URL of a submodule's repository. Only set when `type` is
+    /// `submodule`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submodule_git_url: Option<String>,
     #[serde(rename = "_links")]
     pub links: ContentLinks,
     pub license: Option<License>,
 }
 
+/// The kind of entry a [`Content`] describes, along with the fields specific
+/// to that kind.
+///
+/// Created with [`Content::item`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ContentsItem {
+    File,
+    Dir,
+    Symlink {
+        target: String,
+    },
+    /// A submodule. Directory listings describe submodules as files, without
+    /// their repository's URL, which is only known when the submodule itself
+    /// is requested.
+    Submodule {
+        submodule_git_url: Option<String>,
+    },
+    /// A type this version of octocrab doesn't know about.
+    Other(String),
+}
+
 #[derive(Debug, Clone)]
 pub struct ContentItems {
     pub items: Vec<Content>,
@@ -210,6 +242,48 @@ impl ContentItems {
 }
 
 impl Content {
+    /// The kind of entry this is, e.g. to tell a submodule apart from a
+    /// directory.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::repos::ContentsItem;
+    ///
+    /// let mut content = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .get_content()
+    ///     .send()
+    ///     .await?;
+    /// for item in content.take_items() {
+    ///     if let ContentsItem::Submodule { .. } = item.item() {
+    ///         println!("{} is a submodule", item.path);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn item(&self) -> ContentsItem {
+        // For backwards compatibility, directory listings give submodules the
+        // type `file`. Unlike files, their `git_url` points to a tree.
+        let is_tree = self
+            .git_url
+            .as_deref()
+            .is_some_and(|url| url.contains("/git/trees/"));
+        match (self.r#type.as_str(), &self.target) {
+            ("file", _) if is_tree => ContentsItem::Submodule {
+                submodule_git_url: self.submodule_git_url.clone(),
+            },
+            ("file", _) => ContentsItem::File,
+            ("dir", _) => ContentsItem::Dir,
+            ("symlink", Some(target)) => ContentsItem::Symlink {
+                target: target.clone(),
+            },
+            ("submodule", _) => ContentsItem::Submodule {
+                submodule_git_url: self.submodule_git_url.clone(),
+            },
+            (other, _) => ContentsItem::Other(other.to_owned()),
+        }
+    }
+
     /// Get content of a file from a repository.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
@@ -320,6 +394,19 @@ pub struct Release {
     pub assets: Vec<Asset>,
 }
 
+impl Release {
+    /// The asset named exactly `name`, if any.
+    pub fn asset_by_name(&self, name: &str) -> Option<&Asset> {
+        self.assets.iter().find(|asset| asset.name == name)
+    }
+
+    /// The first asset whose name satisfies `predicate`, e.g. a regex's
+    /// `is_match` to pick the archive for the current target.
+    pub fn asset_matching(&self, mut predicate: impl FnMut(&str) -> bool) -> Option<&Asset> {
+        self.assets.iter().find(|asset| predicate(&asset.name))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub struct ReleaseNotes {
@@ -353,7 +440,8 @@ pub struct Uploader {
     pub email: Option<String>,
     pub login: String,
     pub id: UploaderId,
-    pub node_id: String,
+    /// Missing for uploaders on older GitHub Enterprise Server versions.
+    pub node_id: Option<String>,
     pub avatar_url: Url,
     pub gravatar_id: Option<String>,
     pub url: Url,
@@ -368,6 +456,7 @@ pub struct Uploader {
     pub events_url: Url,
     pub received_events_url: Url,
     pub r#type: String,
+    #[serde(default)]
     pub site_admin: bool,
     pub starred_at: Option<String>,
 }
@@ -382,6 +471,10 @@ pub struct GitTag {
     pub sha: String,
     pub url: Url,
     pub message: String,
+    #[serde(default)]
+    pub tagger: Option<CommitAuthor>,
+    #[serde(default)]
+    pub object: Option<git::TagObject>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -475,3 +568,195 @@ mod maybe_empty {
         }
     }
 }
+
+/// A deploy key granting SSH access to a single repository.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct DeployKey {
+    pub id: DeployKeyId,
+    pub key: String,
+    pub url: Url,
+    pub title: String,
+    pub verified: bool,
+    pub created_at: DateTime<Utc>,
+    pub read_only: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub added_by: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_used: Option<DateTime<Utc>>,
+}
+
+/// A reference to an external resource, e.g. `JIRA-123`, which GitHub turns
+/// into a link wherever it's mentioned in the repository.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Autolink {
+    pub id: AutolinkId,
+    /// The prefix identifying a reference, e.g. `JIRA-`.
+    pub key_prefix: String,
+    /// The URL a reference links to, with `<num>` standing in for the
+    /// reference's identifier.
+    pub url_template: String,
+    /// Whether the identifier may contain letters as well as digits.
+    pub is_alphanumeric: bool,
+}
+
+/// A user's permission level on a repository.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct CollaboratorPermission {
+    /// The legacy base permission: one of `admin`, `write`, `read` or `none`.
+    pub permission: String,
+    /// The name of the user's role, which may be a custom repository role.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role_name: Option<String>,
+    pub user: Author,
+}
+
+/// The status checks required to pass before merging into a protected branch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct RequiredStatusChecks {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<Url>,
+    /// Whether branches must be up to date before merging.
+    pub strict: bool,
+    pub contexts: Vec<String>,
+    #[serde(default)]
+    pub checks: Vec<RequiredStatusCheck>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contexts_url: Option<Url>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct RequiredStatusCheck {
+    pub context: String,
+    /// The app that must set the status, if any.
+    pub app_id: Option<AppId>,
+}
+
+/// The required status check contexts of a protected branch, grouped by the
+/// state of their latest status on a commit.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct RequiredStatusChecksStatus {
+    /// Contexts that are pending or haven't reported a status yet.
+    pub pending: Vec<String>,
+    /// Contexts whose latest status is a failure or an error.
+    pub failing: Vec<String>,
+    pub passing: Vec<String>,
+}
+
+impl RequiredStatusChecksStatus {
+    /// Whether every required context has passed.
+    pub fn all_passing(&self) -> bool {
+        self.pending.is_empty() && self.failing.is_empty()
+    }
+}
+
+/// Whether a commit is ready to merge, judged by both its legacy commit
+/// statuses and its check runs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct MergeReadiness {
+    /// The combined state of the commit statuses.
+    pub combined_status: StatusState,
+    pub check_runs: Vec<checks::CheckRun>,
+    /// Whether the commit statuses succeeded (or none were reported) and every
+    /// check run completed with a `success`, `neutral` or `skipped` conclusion.
+    pub all_passing: bool,
+}
+
+/// A repository's software bill of materials, as exported from its
+/// dependency graph.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Sbom {
+    /// The SBOM as an SPDX JSON document.
+    pub sbom: serde_json::Value,
+}
+
+impl Sbom {
+    /// The version of the SPDX specification the document conforms to, e.g.
+    /// `SPDX-2.3`.
+    pub fn spdx_version(&self) -> Option<&str> {
+        self.sbom.get("spdxVersion").and_then(|v| v.as_str())
+    }
+
+    /// The name of the SPDX document.
+    pub fn name(&self) -> Option<&str> {
+        self.sbom.get("name").and_then(|v| v.as_str())
+    }
+
+    /// The number of packages listed in the document.
+    pub fn package_count(&self) -> usize {
+        self.sbom
+            .get("packages")
+            .and_then(|v| v.as_array())
+            .map_or(0, Vec::len)
+    }
+}
+
+/// The GitHub Pages site of a repository.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Pages {
+    pub url: Url,
+    /// The status of the most recent build, e.g. `built` or `building`.
+    pub status: Option<String>,
+    pub cname: Option<String>,
+    #[serde(default)]
+    pub custom_404: bool,
+    pub html_url: Option<Url>,
+    pub build_type: Option<String>,
+    pub source: Option<PagesSource>,
+    pub public: Option<bool>,
+    pub https_enforced: Option<bool>,
+}
+
+/// The branch and directory a GitHub Pages site is published from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PagesSource {
+    pub branch: String,
+    pub path: String,
+}
+
+/// A build of a GitHub Pages site.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PageBuild {
+    pub url: Url,
+    pub status: String,
+    pub error: PageBuildError,
+    pub pusher: Option<Author>,
+    pub commit: String,
+    /// The duration of the build in milliseconds.
+    pub duration: u64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PageBuildError {
+    pub message: Option<String>,
+}
+
+/// The status of a requested GitHub Pages build.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PageBuildStatus {
+    pub url: Url,
+    pub status: String,
+}
+
+/// Whether Dependabot security updates are enabled for a repository.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct AutomatedSecurityFixes {
+    pub enabled: bool,
+    /// Whether security updates are paused, e.g. due to inactivity.
+    pub paused: bool,
+}
@@ -0,0 +1,42 @@
+use super::super::*;
+
+/// A status reported for a deployment, as returned by
+/// [`RepoDeploymentsHandler::list_statuses`](crate::repos::RepoDeploymentsHandler::list_statuses).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct DeploymentStatus {
+    pub id: DeploymentStatusId,
+    pub node_id: String,
+    pub url: Url,
+    pub state: DeploymentState,
+    pub creator: Option<Author>,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The environment deployed to.
+    #[serde(default)]
+    pub environment: Option<String>,
+    /// The URL of the deployed environment.
+    #[serde(default)]
+    pub environment_url: Option<String>,
+    /// The URL of the deployment's output.
+    #[serde(default)]
+    pub log_url: Option<String>,
+    pub deployment_url: Url,
+    pub repository_url: Url,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// The state of a deployment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum DeploymentState {
+    Error,
+    Failure,
+    Inactive,
+    Pending,
+    Success,
+    Queued,
+    InProgress,
+}
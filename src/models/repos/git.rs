@@ -0,0 +1,233 @@
+use super::super::*;
+
+/// A blob in the Git database.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Blob {
+    pub sha: String,
+    pub url: Url,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<BlobEncoding>,
+}
+
+/// The encoding of a blob's content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum BlobEncoding {
+    #[serde(rename = "utf-8")]
+    Utf8,
+    #[serde(rename = "base64")]
+    Base64,
+}
+
+/// A tree in the Git database.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Tree {
+    pub sha: String,
+    pub url: Url,
+    pub tree: Vec<TreeEntry>,
+    /// Whether GitHub truncated `tree` because it exceeded the maximum number
+    /// of entries returned in a single response.
+    #[serde(default)]
+    pub truncated: bool,
+}
+
+/// An entry of a [`Tree`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct TreeEntry {
+    pub path: String,
+    pub mode: FileMode,
+    pub r#type: TreeEntryType,
+    pub sha: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<Url>,
+}
+
+/// The file mode of a tree entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum FileMode {
+    /// A regular file.
+    #[serde(rename = "100644")]
+    File,
+    /// An executable file.
+    #[serde(rename = "100755")]
+    Executable,
+    /// A subdirectory.
+    #[serde(rename = "040000")]
+    Subdirectory,
+    /// A submodule.
+    #[serde(rename = "160000")]
+    Submodule,
+    /// A symbolic link.
+    #[serde(rename = "120000")]
+    Symlink,
+}
+
+/// The type of object a tree entry points to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum TreeEntryType {
+    Blob,
+    Tree,
+    Commit,
+}
+
+/// The type of object an annotated tag points to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum TagObjectType {
+    Commit,
+    Tree,
+    Blob,
+    Tag,
+}
+
+/// The object an annotated tag points to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct TagObject {
+    pub sha: String,
+    pub r#type: TagObjectType,
+    pub url: Url,
+}
+
+/// An entry used to create a new tree with
+/// [`RepoGitHandler::create_tree`](crate::repos::RepoGitHandler::create_tree).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CreateTreeEntry {
+    pub path: String,
+    pub mode: FileMode,
+    pub r#type: TreeEntryType,
+    #[serde(flatten)]
+    pub source: CreateTreeEntrySource,
+}
+
+/// Where the contents of a [`CreateTreeEntry`] come from.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CreateTreeEntrySource {
+    /// The SHA of an existing object. `None` deletes the entry at `path`
+    /// from the base tree.
+    Sha(Option<String>),
+    /// UTF-8 content from which GitHub creates a new blob.
+    Content(String),
+}
+
+impl CreateTreeEntry {
+    /// An entry pointing to an existing blob with the given `sha`.
+    pub fn blob(path: impl Into<String>, mode: FileMode, sha: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            mode,
+            r#type: TreeEntryType::Blob,
+            source: CreateTreeEntrySource::Sha(Some(sha.into())),
+        }
+    }
+
+    /// A regular file with the given UTF-8 `content`.
+    pub fn content(path: impl Into<String>, content: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            mode: FileMode::File,
+            r#type: TreeEntryType::Blob,
+            source: CreateTreeEntrySource::Content(content.into()),
+        }
+    }
+
+    /// Removes the file at `path` from the base tree.
+    pub fn delete(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            mode: FileMode::File,
+            r#type: TreeEntryType::Blob,
+            source: CreateTreeEntrySource::Sha(None),
+        }
+    }
+}
+
+/// A single change to a file, used with
+/// [`RepoHandler::commit_files`](crate::repos::RepoHandler::commit_files).
+#[derive(Debug, Clone, PartialEq)]
+pub enum FileChange {
+    /// Creates or replaces the file at `path` with the given UTF-8 `content`.
+    Upsert { path: String, content: String },
+    /// Removes the file at `path`.
+    Delete { path: String },
+}
+
+impl FileChange {
+    pub fn upsert(path: impl Into<String>, content: impl Into<String>) -> Self {
+        Self::Upsert {
+            path: path.into(),
+            content: content.into(),
+        }
+    }
+
+    pub fn delete(path: impl Into<String>) -> Self {
+        Self::Delete { path: path.into() }
+    }
+}
+
+impl From<FileChange> for CreateTreeEntry {
+    fn from(change: FileChange) -> Self {
+        match change {
+            FileChange::Upsert { path, content } => CreateTreeEntry::content(path, content),
+            FileChange::Delete { path } => CreateTreeEntry::delete(path),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_create_tree_entries() {
+        let entries = vec![
+            CreateTreeEntry::blob(
+                "bin/run",
+                FileMode::Executable,
+                "44b4fc6d56897b048c772eb4087f854f46256132",
+            ),
+            CreateTreeEntry::content("README.md", "# Hello"),
+            CreateTreeEntry::delete("old.txt"),
+        ];
+
+        assert_eq!(
+            serde_json::to_value(entries).unwrap(),
+            serde_json::json!([
+                {
+                    "path": "bin/run",
+                    "mode": "100755",
+                    "type": "blob",
+                    "sha": "44b4fc6d56897b048c772eb4087f854f46256132"
+                },
+                {
+                    "path": "README.md",
+                    "mode": "100644",
+                    "type": "blob",
+                    "content": "# Hello"
+                },
+                {
+                    "path": "old.txt",
+                    "mode": "100644",
+                    "type": "blob",
+                    "sha": null
+                }
+            ])
+        );
+    }
+}
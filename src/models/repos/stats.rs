@@ -0,0 +1,75 @@
+use super::super::*;
+
+/// A contributor's commit activity, as returned by
+/// [`RepoStatsHandler::contributors`](crate::repos::RepoStatsHandler::contributors).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ContributorStats {
+    pub author: Option<Author>,
+    /// The total number of commits authored by the contributor.
+    pub total: u64,
+    pub weeks: Vec<ContributorWeek>,
+}
+
+/// A contributor's activity during a single week.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ContributorWeek {
+    /// The start of the week, as a Unix timestamp.
+    pub w: i64,
+    /// The number of additions.
+    pub a: u64,
+    /// The number of deletions.
+    pub d: u64,
+    /// The number of commits.
+    pub c: u64,
+}
+
+/// The commits made to a repository during a single week.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct WeeklyCommitActivity {
+    /// The number of commits per day, starting on Sunday.
+    pub days: Vec<u64>,
+    pub total: u64,
+    /// The start of the week, as a Unix timestamp.
+    pub week: i64,
+}
+
+/// The lines added and deleted in a repository during a single week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "(i64, i64, i64)", into = "(i64, i64, i64)")]
+#[non_exhaustive]
+pub struct WeeklyCodeFrequency {
+    /// The start of the week, as a Unix timestamp.
+    pub week: i64,
+    pub additions: i64,
+    /// The number of deleted lines, as a negative number.
+    pub deletions: i64,
+}
+
+impl From<(i64, i64, i64)> for WeeklyCodeFrequency {
+    fn from((week, additions, deletions): (i64, i64, i64)) -> Self {
+        Self {
+            week,
+            additions,
+            deletions,
+        }
+    }
+}
+
+impl From<WeeklyCodeFrequency> for (i64, i64, i64) {
+    fn from(frequency: WeeklyCodeFrequency) -> Self {
+        (frequency.week, frequency.additions, frequency.deletions)
+    }
+}
+
+/// The weekly commit counts of the last 52 weeks, oldest first.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Participation {
+    /// Commits by everyone.
+    pub all: Vec<u64>,
+    /// Commits by the repository's owner.
+    pub owner: Vec<u64>,
+}
@@ -1024,6 +1024,24 @@ mod tests {
         assert_eq!(install_event.repositories.unwrap()[2].name, "octocrab");
     }
 
+    #[test]
+    fn deserialize_installation_permissions() {
+        let json = include_str!("../../tests/resources/installation_created_webhook_event.json");
+        let event = WebhookEvent::try_from_header_and_body("installation", json).unwrap();
+        let Some(EventInstallation::Full(installation)) = event.installation else {
+            panic!(" event has no full installation {:?}", event)
+        };
+        let permissions = installation.permissions;
+        assert_eq!(
+            permissions.metadata,
+            Some(crate::models::PermissionLevel::Read)
+        );
+        assert!(permissions.has_write("issues"));
+        assert!(permissions.has_write("pull_requests"));
+        assert!(!permissions.has_write("metadata"));
+        assert!(!permissions.has_write("contents"));
+    }
+
     #[test]
     fn deserialize_installation_deleted() {
         let json = include_str!("../../tests/resources/installation_deleted_webhook_event.json");
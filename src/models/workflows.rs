@@ -98,6 +98,15 @@ pub struct Job {
     pub runner_group_name: Option<String>,
 }
 
+impl Job {
+    /// The first step of the job that concluded with a failure, if any.
+    pub fn failed_step(&self) -> Option<&Step> {
+        self.steps
+            .iter()
+            .find(|step| step.conclusion == Some(Conclusion::Failure))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
@@ -120,6 +129,8 @@ pub enum Status {
     InProgress,
     Completed,
     Failed,
+    Waiting,
+    Requested,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
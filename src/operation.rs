@@ -0,0 +1,207 @@
+//! Labelling requests with the name of the operation they perform.
+use bytes::Bytes;
+use http::header::HeaderMap;
+use http::request::Builder;
+use http::{Method, Uri};
+use http_body_util::combinators::BoxBody;
+use serde::Serialize;
+use snafu::ResultExt;
+use std::convert::TryInto;
+
+use crate::error::{UriParseError, UriParseSnafu};
+use crate::{FromResponse, OctoBody, Octocrab, Result};
+
+/// Sends requests labelled with the name of the API operation they perform,
+/// e.g. `issues.list`. The name is stored in the request's extensions as a
+/// `&'static str`, where the `tracing` feature's span uses it as its
+/// `otel.name`, and custom middleware can read it to label metrics.
+///
+/// Created with [`Octocrab::operation`].
+pub struct Operation<'octo> {
+    crab: &'octo Octocrab,
+    name: &'static str,
+}
+
+impl<'octo> Operation<'octo> {
+    pub(crate) fn new(crab: &'octo Octocrab, name: &'static str) -> Self {
+        Self { crab, name }
+    }
+
+    /// The name requests are labelled with.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Like [`Octocrab::get`], labelling the request.
+    pub async fn get<R, A, P>(&self, route: A, parameters: Option<&P>) -> Result<R>
+    where
+        A: AsRef<str>,
+        P: Serialize + ?Sized,
+        R: FromResponse,
+    {
+        self.get_with_headers(route, parameters, None).await
+    }
+
+    /// Like [`Octocrab::get_with_headers`], labelling the request.
+    pub async fn get_with_headers<R, A, P>(
+        &self,
+        route: A,
+        parameters: Option<&P>,
+        headers: Option<HeaderMap>,
+    ) -> Result<R>
+    where
+        A: AsRef<str>,
+        P: Serialize + ?Sized,
+        R: FromResponse,
+    {
+        let response = self
+            ._get_with_headers(self.crab.parameterized_uri(route, parameters)?, headers)
+            .await?;
+        R::from_response(crate::map_github_error(response).await?).await
+    }
+
+    /// Like [`Octocrab::graphql`], labelling the request.
+    pub async fn graphql<R: FromResponse>(&self, payload: &(impl Serialize + ?Sized)) -> Result<R> {
+        self.post("/graphql", Some(&serde_json::json!(payload)))
+            .await
+    }
+
+    /// Like [`Octocrab::post`], labelling the request.
+    pub async fn post<P: Serialize + ?Sized, R: FromResponse>(
+        &self,
+        route: impl AsRef<str>,
+        body: Option<&P>,
+    ) -> Result<R> {
+        let uri = self.crab.parameterized_uri(route, None::<&()>)?;
+        self.send(Method::POST, uri, body).await
+    }
+
+    /// Like [`Octocrab::patch`], labelling the request.
+    pub async fn patch<R, A, B>(&self, route: A, body: Option<&B>) -> Result<R>
+    where
+        A: AsRef<str>,
+        B: Serialize + ?Sized,
+        R: FromResponse,
+    {
+        let uri = self.crab.parameterized_uri(route, None::<&()>)?;
+        self.send(Method::PATCH, uri, body).await
+    }
+
+    /// Like [`Octocrab::put`], labelling the request.
+    pub async fn put<R, A, B>(&self, route: A, body: Option<&B>) -> Result<R>
+    where
+        A: AsRef<str>,
+        B: Serialize + ?Sized,
+        R: FromResponse,
+    {
+        let uri = self.crab.parameterized_uri(route, None::<&()>)?;
+        self.send(Method::PUT, uri, body).await
+    }
+
+    /// Like [`Octocrab::delete`], labelling the request.
+    pub async fn delete<R, A, B>(&self, route: A, body: Option<&B>) -> Result<R>
+    where
+        A: AsRef<str>,
+        B: Serialize + ?Sized,
+        R: FromResponse,
+    {
+        let uri = self.crab.parameterized_uri(route, None::<&()>)?;
+        self.send(Method::DELETE, uri, body).await
+    }
+
+    /// Like [`Octocrab::_get`], labelling the request.
+    pub async fn _get(&self, uri: impl TryInto<Uri>) -> Result<Response> {
+        self._get_with_headers(uri, None).await
+    }
+
+    /// Like [`Octocrab::_get_with_headers`], labelling the request.
+    pub async fn _get_with_headers(
+        &self,
+        uri: impl TryInto<Uri>,
+        headers: Option<HeaderMap>,
+    ) -> Result<Response> {
+        let mut builder = Builder::new().method(Method::GET).uri(parse_uri(uri)?);
+        if let Some(headers) = headers {
+            for (key, value) in headers.iter() {
+                builder = builder.header(key, value);
+            }
+        }
+        let request = self.build_request(builder, None::<&()>)?;
+        self.crab.execute(request).await
+    }
+
+    /// Like [`Octocrab::_post`], labelling the request.
+    pub async fn _post<B: Serialize + ?Sized>(
+        &self,
+        uri: impl TryInto<Uri>,
+        body: Option<&B>,
+    ) -> Result<Response> {
+        self.execute(Method::POST, parse_uri(uri)?, body).await
+    }
+
+    /// Like [`Octocrab::_patch`], labelling the request.
+    pub async fn _patch<B: Serialize + ?Sized>(
+        &self,
+        uri: impl TryInto<Uri>,
+        body: Option<&B>,
+    ) -> Result<Response> {
+        self.execute(Method::PATCH, parse_uri(uri)?, body).await
+    }
+
+    /// Like [`Octocrab::_put`], labelling the request.
+    pub async fn _put<B: Serialize + ?Sized>(
+        &self,
+        uri: impl TryInto<Uri>,
+        body: Option<&B>,
+    ) -> Result<Response> {
+        self.execute(Method::PUT, parse_uri(uri)?, body).await
+    }
+
+    /// Like [`Octocrab::_delete`], labelling the request.
+    pub async fn _delete<B: Serialize + ?Sized>(
+        &self,
+        uri: impl TryInto<Uri>,
+        body: Option<&B>,
+    ) -> Result<Response> {
+        self.execute(Method::DELETE, parse_uri(uri)?, body).await
+    }
+
+    /// Like [`Octocrab::build_request`], labelling the request.
+    pub fn build_request<B: Serialize + ?Sized>(
+        &self,
+        builder: Builder,
+        body: Option<&B>,
+    ) -> Result<http::Request<OctoBody>> {
+        self.crab.build_request(self.label(builder), body)
+    }
+
+    /// Labels a request being built by hand.
+    pub fn label(&self, builder: Builder) -> Builder {
+        builder.extension(self.name)
+    }
+
+    async fn execute<B>(&self, method: Method, uri: Uri, body: Option<&B>) -> Result<Response>
+    where
+        B: Serialize + ?Sized,
+    {
+        let request = self.build_request(Builder::new().method(method).uri(uri), body)?;
+        self.crab.execute(request).await
+    }
+
+    async fn send<B, R>(&self, method: Method, uri: Uri, body: Option<&B>) -> Result<R>
+    where
+        B: Serialize + ?Sized,
+        R: FromResponse,
+    {
+        let response = self.execute(method, uri, body).await?;
+        R::from_response(crate::map_github_error(response).await?).await
+    }
+}
+
+type Response = http::Response<BoxBody<Bytes, crate::Error>>;
+
+fn parse_uri(uri: impl TryInto<Uri>) -> Result<Uri> {
+    uri.try_into()
+        .map_err(|_| UriParseError {})
+        .context(UriParseSnafu)
+}
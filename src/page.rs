@@ -73,6 +73,41 @@ impl<T> Page<T> {
         })
     }
 
+    /// Fetches this and all following pages, keeping only the items for which
+    /// `predicate` returns `true`. Non-matching items are dropped as each page
+    /// arrives, so only the matches are held in memory.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let crab = octocrab::instance();
+    /// let pulls_into_main = crab
+    ///     .pulls("owner", "repo")
+    ///     .list()
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?
+    ///     .filter_all(&crab, |pull| pull.base.ref_field == "main")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn filter_all(
+        mut self,
+        crab: &crate::Octocrab,
+        predicate: impl Fn(&T) -> bool,
+    ) -> crate::Result<Vec<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let mut ret = self.take_items();
+        ret.retain(&predicate);
+        let mut next = self.next;
+        while let Some(page) = crab.get_page::<T>(&next).await? {
+            ret.extend(page.items.into_iter().filter(|item| predicate(item)));
+            next = page.next;
+        }
+        Ok(ret)
+    }
+
     /// Convert Page into a stream of results
     ///
     /// This will fetch new pages using the next link with in the page so that
@@ -177,6 +212,10 @@ impl<T: serde::de::DeserializeOwned> crate::FromResponse for Page<T> {
             next,
             last,
         } = get_links(response.headers())?;
+        let capture = response
+            .extensions()
+            .get::<crate::from_response::CaptureExtra>()
+            .is_some();
 
         let json: serde_json::Value =
             serde_json::from_slice(response.into_body().collect().await?.to_bytes().as_ref())
@@ -184,7 +223,10 @@ impl<T: serde::de::DeserializeOwned> crate::FromResponse for Page<T> {
 
         if json.is_array() {
             Ok(Self {
-                items: serde_json::from_value(json).context(crate::error::SerdeSnafu)?,
+                items: crate::from_response::capture_extra(capture, || {
+                    serde_json::from_value(json)
+                })
+                .context(crate::error::SerdeSnafu)?,
                 incomplete_results: None,
                 total_count: None,
                 next,
@@ -202,6 +244,8 @@ impl<T: serde::de::DeserializeOwned> crate::FromResponse for Page<T> {
                 "repositories",
                 "installations",
                 "runners",
+                "runner_groups",
+                "check_runs",
             ]
             .into_iter()
             .find(|v| json.get(v).is_some())
@@ -211,8 +255,10 @@ impl<T: serde::de::DeserializeOwned> crate::FromResponse for Page<T> {
             .context(crate::error::OtherSnafu)?;
 
             Ok(Self {
-                items: serde_json::from_value(json.get(attr).cloned().unwrap())
-                    .context(crate::error::SerdeSnafu)?,
+                items: crate::from_response::capture_extra(capture, || {
+                    serde_json::from_value(json.get(attr).cloned().unwrap())
+                })
+                .context(crate::error::SerdeSnafu)?,
                 incomplete_results: json
                     .get("incomplete_results")
                     .and_then(serde_json::Value::as_bool),
@@ -284,6 +284,28 @@ pub mod issues {
         Comments,
     }
 
+    /// Which issues to list, based on how the authenticated user is involved
+    /// with them.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum IssueFilter {
+        /// Issues assigned to the user.
+        Assigned,
+        /// Issues created by the user.
+        Created,
+        /// Issues mentioning the user.
+        Mentioned,
+        /// Issues the user is subscribed to.
+        Subscribed,
+        /// Issues in the repositories the user can access. GitHub treats
+        /// this the same as [`IssueFilter::All`].
+        Repos,
+        /// All issues the user can see, regardless of participation or
+        /// creation.
+        All,
+    }
+
     /// A generic filter type that allows you to filter either by exact match,
     /// any match, or no matches.
     #[derive(Debug, Clone, Copy)]
@@ -357,9 +379,7 @@ pub mod markdown {
 pub mod orgs {
     //! Parameter types for the organization API.
 
-    /// What to sort results by. Can be either `created`, `updated`, `popularity`
-    /// (comment count) or `long-running` (age, filtering by pulls updated in the
-    /// last month).
+    /// The role of an organization member.
     #[derive(Debug, Clone, Copy, serde::Serialize)]
     #[serde(rename_all = "snake_case")]
     #[non_exhaustive]
@@ -367,6 +387,18 @@ pub mod orgs {
         Member,
         Admin,
     }
+
+    /// Which organization members to list.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum MemberFilter {
+        /// Members without two-factor authentication enabled. Only available
+        /// to organization owners.
+        #[serde(rename = "2fa_disabled")]
+        TwoFaDisabled,
+        All,
+    }
 }
 
 pub mod pulls {
@@ -477,29 +509,89 @@ pub mod repos {
         FullName,
     }
 
-    /// A Git reference, either a branch, tag, or rev.
+    /// The visibility of a repository.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum Visibility {
+        Public,
+        Private,
+        /// Only available for organizations associated with an enterprise
+        /// account.
+        Internal,
+    }
+
+    /// The characters percent-encoded when a reference is used as part of a
+    /// URL path. `/` is kept, as GitHub expects e.g. `heads/feature/x`.
+    const REF_PATH: &percent_encoding::AsciiSet = &percent_encoding::NON_ALPHANUMERIC
+        .remove(b'/')
+        .remove(b'-')
+        .remove(b'_')
+        .remove(b'.')
+        .remove(b'~');
+
+    /// Percent-encodes `reference` for use as part of a URL path.
+    pub(crate) fn encode_ref_path(reference: &str) -> String {
+        percent_encoding::utf8_percent_encode(reference, REF_PATH).to_string()
+    }
+
+    /// A Git reference, either a branch, tag, or commit SHA.
     #[derive(Debug, Clone)]
     pub enum Reference {
         Branch(String),
         Tag(String),
+        Commit(String),
     }
 
     impl Reference {
+        /// The short form of the reference, e.g. `heads/main`, `tags/v1.0`,
+        /// or the SHA of a commit.
         pub fn ref_url(&self) -> String {
             match self {
                 Self::Branch(branch) => format!("heads/{branch}"),
                 Self::Tag(tag) => format!("tags/{tag}"),
+                Self::Commit(sha) => sha.clone(),
+            }
+        }
+
+        /// The fully qualified reference, e.g. `refs/heads/main`,
+        /// `refs/tags/v1.0`, or the SHA of a commit.
+        pub fn full_ref(&self) -> String {
+            match self {
+                Self::Commit(sha) => sha.clone(),
+                _ => format!("refs/{}", self.ref_url()),
             }
         }
 
+        #[deprecated(note = "use `Reference::full_ref` instead")]
         pub fn full_ref_url(&self) -> String {
-            format!("refs/{}", self.ref_url())
+            self.full_ref()
+        }
+
+        /// [`Reference::ref_url`], percent-encoded for use in a URL path.
+        pub fn ref_url_path(&self) -> String {
+            encode_ref_path(&self.ref_url())
+        }
+
+        /// [`Reference::ref_url_path`] for the git refs endpoints, which can
+        /// only address branches and tags.
+        pub(crate) fn git_ref_path(&self) -> crate::Result<String> {
+            match self {
+                Self::Commit(sha) => Err(crate::Error::Other {
+                    source: format!(
+                        "`{sha}` is a commit, but the git refs API only accepts branches and tags"
+                    )
+                    .into(),
+                    backtrace: snafu::Backtrace::capture(),
+                }),
+                _ => Ok(self.ref_url_path()),
+            }
         }
     }
 
     impl std::fmt::Display for Reference {
         fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-            f.write_str(&self.full_ref_url())
+            f.write_str(&self.full_ref())
         }
     }
 
@@ -511,6 +603,13 @@ pub mod repos {
     #[derive(Debug, Clone)]
     pub struct Commitish(pub String);
 
+    impl Commitish {
+        /// The reference, percent-encoded for use in a URL path.
+        pub fn ref_url_path(&self) -> String {
+            encode_ref_path(&self.0)
+        }
+    }
+
     impl From<Reference> for Commitish {
         fn from(r: Reference) -> Commitish {
             // Convert to `heads/` or `tags/` to avoid
@@ -519,12 +618,30 @@ pub mod repos {
         }
     }
 
+    impl From<&Reference> for Commitish {
+        fn from(r: &Reference) -> Commitish {
+            Commitish(r.ref_url())
+        }
+    }
+
     impl From<String> for Commitish {
         fn from(s: String) -> Commitish {
             Commitish(s)
         }
     }
 
+    impl From<&str> for Commitish {
+        fn from(s: &str) -> Commitish {
+            Commitish(s.to_owned())
+        }
+    }
+
+    impl From<&String> for Commitish {
+        fn from(s: &String) -> Commitish {
+            Commitish(s.clone())
+        }
+    }
+
     impl std::fmt::Display for Commitish {
         fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
             f.write_str(&self.0)
@@ -543,6 +660,18 @@ pub mod repos {
         }
     }
 
+    pub mod releases {
+        /// Whether prereleases count as the latest release.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[non_exhaustive]
+        pub enum Prereleases {
+            /// Only consider full releases, like GitHub's `/releases/latest`.
+            Exclude,
+            /// Consider prereleases as well.
+            Include,
+        }
+    }
+
     pub mod release_assets {
 
         #[derive(Debug, Clone, Copy, serde::Serialize)]
@@ -604,6 +733,21 @@ pub mod users {
         }
     }
 
+    pub mod starred {
+        /// What to sort a user's starred repositories by.
+        ///
+        /// See https://docs.github.com/en/rest/activity/starring?apiVersion=2022-11-28#list-repositories-starred-by-a-user
+        #[derive(Debug, Clone, Copy, serde::Serialize)]
+        #[serde(rename_all = "snake_case")]
+        #[non_exhaustive]
+        pub enum Sort {
+            /// When the repository was starred.
+            Created,
+            /// When the repository was last pushed to.
+            Updated,
+        }
+    }
+
     pub mod emails {
         use serde::{Deserialize, Serialize};
 
@@ -65,7 +65,10 @@ where
         // want to give our credentials to third-party services.
         let authority = req.uri().authority();
         let allowed_authorities = [self.base_uri.authority(), self.upload_uri.authority()];
-        if authority.is_none() || allowed_authorities.contains(&authority) {
+        // Requests built with `Octocrab::request_builder()` may already have
+        // one, possibly changed by the caller.
+        let authorized = req.headers().contains_key(AUTHORIZATION);
+        if !authorized && (authority.is_none() || allowed_authorities.contains(&authority)) {
             if let Some(auth_header) = &*self.auth_header {
                 req.headers_mut().append(AUTHORIZATION, auth_header.clone());
             }
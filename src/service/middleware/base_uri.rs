@@ -64,7 +64,7 @@ where
 }
 
 // Join base URI and Path+Query, preserving any path in the base.
-fn overwrite_base_uri(base_uri: &http::Uri, current_uri: Uri) -> http::Uri {
+pub(crate) fn overwrite_base_uri(base_uri: &http::Uri, current_uri: Uri) -> http::Uri {
     let req_pandq = current_uri.path_and_query();
     let mut builder = uri::Builder::new();
     match current_uri.scheme() {
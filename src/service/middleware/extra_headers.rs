@@ -3,6 +3,11 @@ use std::sync::Arc;
 use http::{header::HeaderName, request::Request, HeaderValue};
 use tower::{Layer, Service};
 
+/// Marks requests that already carry the extra headers, because they were
+/// built with [`crate::Octocrab::request_builder`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct HeadersApplied;
+
 #[derive(Clone)]
 /// Layer that adds a static set of extra headers to each request
 pub struct ExtraHeadersLayer {
@@ -49,7 +54,9 @@ where
     }
 
     fn call(&mut self, mut req: Request<ReqBody>) -> Self::Future {
-        req.headers_mut().extend(self.headers.iter().cloned());
+        if req.extensions().get::<HeadersApplied>().is_none() {
+            req.headers_mut().extend(self.headers.iter().cloned());
+        }
         self.inner.call(req)
     }
 }
@@ -0,0 +1,72 @@
+use http::{header::HeaderName, request::Request, HeaderMap, HeaderValue};
+use opentelemetry::propagation::Injector;
+use tower::{Layer, Service};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+#[derive(Clone, Default)]
+/// Layer that propagates the OpenTelemetry context of the current span into
+/// each request, e.g. as a `traceparent` header.
+///
+/// The headers are written by the globally registered text map propagator, see
+/// [`opentelemetry::global::set_text_map_propagator`]. Spans are only exported
+/// if the application's `tracing` subscriber includes a
+/// [`tracing_opentelemetry`] layer.
+pub struct OpenTelemetryLayer;
+
+impl OpenTelemetryLayer {
+    pub fn new() -> Self {
+        OpenTelemetryLayer
+    }
+}
+
+impl<S> Layer<S> for OpenTelemetryLayer {
+    type Service = OpenTelemetry<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        OpenTelemetry { inner }
+    }
+}
+
+#[derive(Clone)]
+/// Service that propagates the OpenTelemetry context of the current span into
+/// each request
+pub struct OpenTelemetry<S> {
+    inner: S,
+}
+
+impl<S, ReqBody> Service<Request<ReqBody>> for OpenTelemetry<S>
+where
+    S: Service<Request<ReqBody>>,
+{
+    type Error = S::Error;
+    type Future = S::Future;
+    type Response = S::Response;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: Request<ReqBody>) -> Self::Future {
+        let context = tracing::Span::current().context();
+        opentelemetry::global::get_text_map_propagator(|propagator| {
+            propagator.inject_context(&context, &mut HeaderInjector(req.headers_mut()))
+        });
+        self.inner.call(req)
+    }
+}
+
+struct HeaderInjector<'a>(&'a mut HeaderMap);
+
+impl Injector for HeaderInjector<'_> {
+    fn set(&mut self, key: &str, value: String) {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::from_bytes(key.as_bytes()),
+            HeaderValue::from_str(&value),
+        ) {
+            self.0.insert(name, value);
+        }
+    }
+}
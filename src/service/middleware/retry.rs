@@ -1,47 +1,97 @@
-use futures_util::future;
-use http::{Request, Response};
-use hyper_util::client::legacy::Error;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use futures_util::future::{self, BoxFuture};
+use http::{HeaderMap, Request, Response, StatusCode};
+use http_body_util::{combinators::BoxBody, BodyExt, Full};
 use tower::retry::Policy;
+use tower::{BoxError, Layer, Service};
 
 use crate::body::OctoBody;
+use crate::GitHubError;
+
+/// Decides whether a response should be retried from its status, its headers
+/// and, for error responses, the [`GitHubError`] in its body.
+pub type RetryPredicate =
+    Arc<dyn Fn(StatusCode, &HeaderMap, Option<&GitHubError>) -> bool + Send + Sync>;
 
 #[derive(Clone)]
+#[non_exhaustive]
 pub enum RetryConfig {
     None,
     Simple(usize),
+    /// Retries connection errors and any response for which the predicate
+    /// returns `true`.
+    Predicate(usize, RetryPredicate),
+}
+
+impl RetryConfig {
+    /// Retries connection errors, as well as any response matching
+    /// `predicate`, up to `count` times. Unlike [`RetryConfig::Simple`], server
+    /// errors and rate limited responses are only retried if `predicate`
+    /// returns `true` for them.
+    ///
+    /// Error responses are read before `predicate` is called, so it can match
+    /// on the message of the [`GitHubError`] GitHub returned.
+    /// ```
+    /// use octocrab::service::middleware::retry::RetryConfig;
+    ///
+    /// // Only retry when GitHub's load balancers fail to reach a backend.
+    /// let config = RetryConfig::with_predicate(3, |status, _headers, _error| {
+    ///     status == 502 || status == 503
+    /// });
+    /// ```
+    pub fn with_predicate(
+        count: usize,
+        predicate: impl Fn(StatusCode, &HeaderMap, Option<&GitHubError>) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        RetryConfig::Predicate(count, Arc::new(predicate))
+    }
+}
+
+fn consume_attempt(count: &mut usize) -> Option<future::Ready<()>> {
+    if *count > 0 {
+        *count -= 1;
+        Some(future::ready(()))
+    } else {
+        None
+    }
 }
 
-impl<B> Policy<Request<OctoBody>, Response<B>, Error> for RetryConfig {
+impl<B, E> Policy<Request<OctoBody>, Response<B>, E> for RetryConfig {
     type Future = futures_util::future::Ready<()>;
 
     fn retry(
         &mut self,
         _req: &mut Request<OctoBody>,
-        result: &mut Result<Response<B>, Error>,
+        result: &mut Result<Response<B>, E>,
     ) -> Option<Self::Future> {
         match self {
             RetryConfig::None => None,
             RetryConfig::Simple(count) => match result {
                 Ok(response) => {
                     if response.status().is_server_error() || response.status() == 429 {
-                        if *count > 0 {
-                            *count -= 1;
-                            Some(future::ready(()))
-                        } else {
-                            None
-                        }
+                        consume_attempt(count)
                     } else {
                         None
                     }
                 }
-                Err(_) => {
-                    if *count > 0 {
-                        *count -= 1;
-                        Some(future::ready(()))
+                Err(_) => consume_attempt(count),
+            },
+            RetryConfig::Predicate(count, predicate) => match result {
+                Ok(response) => {
+                    if predicate(
+                        response.status(),
+                        response.headers(),
+                        response.extensions().get::<GitHubError>(),
+                    ) {
+                        consume_attempt(count)
                     } else {
                         None
                     }
                 }
+                Err(_) => consume_attempt(count),
             },
         }
     }
@@ -68,3 +118,64 @@ impl<B> Policy<Request<OctoBody>, Response<B>, Error> for RetryConfig {
         }
     }
 }
+
+/// Layer that reads the body of error responses into a [`GitHubError`],
+/// which is stored in the response's extensions for
+/// [`RetryConfig::Predicate`] to inspect.
+#[derive(Clone, Default)]
+pub struct ReadErrorBodyLayer;
+
+impl<S> Layer<S> for ReadErrorBodyLayer {
+    type Service = ReadErrorBody<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ReadErrorBody { inner }
+    }
+}
+
+/// Service that reads the body of error responses into a [`GitHubError`].
+///
+/// The body is buffered and handed on unchanged, so the response can still be
+/// turned into an [`Error::GitHub`](crate::Error::GitHub) later on.
+#[derive(Clone)]
+pub struct ReadErrorBody<S> {
+    inner: S,
+}
+
+impl<S> Service<Request<OctoBody>> for ReadErrorBody<S>
+where
+    S: Service<Request<OctoBody>, Response = Response<BoxBody<Bytes, crate::Error>>>,
+    S::Future: Send + 'static,
+    S::Error: Into<BoxError>,
+{
+    type Response = Response<BoxBody<Bytes, crate::Error>>;
+    type Error = BoxError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, req: Request<OctoBody>) -> Self::Future {
+        let response = self.inner.call(req);
+        Box::pin(async move {
+            let response = response.await.map_err(Into::into)?;
+            if !response.status().is_client_error() && !response.status().is_server_error() {
+                return Ok(response);
+            }
+
+            let (mut parts, body) = response.into_parts();
+            let body = body.collect().await?.to_bytes();
+            if let Ok(error) = serde_json::from_slice::<crate::GitHubErrorBody>(&body) {
+                parts.extensions.insert(GitHubError {
+                    status_code: parts.status,
+                    documentation_url: error.documentation_url,
+                    errors: error.errors,
+                    message: error.message,
+                });
+            }
+            let body = Full::new(body).map_err(|never| match never {}).boxed();
+            Ok(Response::from_parts(parts, body))
+        })
+    }
+}
@@ -1 +1,14 @@
 pub mod middleware;
+
+use crate::OctoBody;
+use http::{Request, Response};
+use tower::{util::BoxCloneService, BoxError};
+
+/// The service sending requests of the default client over the network,
+/// wrapped by layers passed to
+/// [`OctocrabBuilder::with_post_auth_layer`](crate::OctocrabBuilder::with_post_auth_layer).
+///
+/// Requests reaching it are final: they have an absolute URI and carry every
+/// header octocrab sets, including `Authorization`.
+pub type DispatchService =
+    BoxCloneService<Request<OctoBody>, Response<hyper::body::Incoming>, BoxError>;
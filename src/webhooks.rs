@@ -0,0 +1,330 @@
+//! Verifying and dispatching GitHub webhook deliveries.
+//!
+//! GitHub signs every delivery with the webhook secret and sends the
+//! signature in the `X-Hub-Signature-256` header. [`verify_signature`] checks
+//! that signature, and [`WebhookHandler::dispatch`] additionally parses the
+//! payload into a [`WebhookEvent`] and calls the handler method matching its
+//! kind.
+//!
+//! ```no_run
+//! use octocrab::models::webhook_events::{payload::PullRequestWebhookEventPayload, WebhookEvent};
+//! use octocrab::webhooks::WebhookHandler;
+//!
+//! struct Bot;
+//!
+//! #[async_trait::async_trait]
+//! impl WebhookHandler for Bot {
+//!     async fn on_pull_request(
+//!         &self,
+//!         _event: &WebhookEvent,
+//!         payload: &PullRequestWebhookEventPayload,
+//!     ) -> octocrab::Result<()> {
+//!         println!("pull request #{} was {:?}", payload.number, payload.action);
+//!         Ok(())
+//!     }
+//! }
+//!
+//! # async fn run(headers: http::HeaderMap, body: bytes::Bytes) -> octocrab::Result<()> {
+//! Bot.dispatch(&headers, &body, "my webhook secret").await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use hmac::{Hmac, Mac};
+use http::HeaderMap;
+use sha2::Sha256;
+use snafu::{OptionExt, ResultExt};
+
+use crate::error::{SerdeSnafu, WebhookSnafu};
+use crate::models::webhook_events::payload::{
+    CheckRunWebhookEventPayload, CheckSuiteWebhookEventPayload, InstallationWebhookEventPayload,
+    IssueCommentWebhookEventPayload, IssuesWebhookEventPayload, PingWebhookEventPayload,
+    PullRequestReviewWebhookEventPayload, PullRequestWebhookEventPayload, PushWebhookEventPayload,
+    ReleaseWebhookEventPayload, WorkflowJobWebhookEventPayload, WorkflowRunWebhookEventPayload,
+};
+use crate::models::webhook_events::{WebhookEvent, WebhookEventPayload};
+
+/// The header carrying the HMAC-SHA256 signature of the delivery.
+pub const SIGNATURE_HEADER: &str = "x-hub-signature-256";
+/// The header naming the kind of event that was delivered.
+pub const EVENT_HEADER: &str = "x-github-event";
+
+/// Checks that `signature` (the value of the `X-Hub-Signature-256` header,
+/// e.g. `sha256=757107ea…`) is the HMAC-SHA256 of `body` keyed with `secret`.
+/// The comparison is done in constant time.
+/// ```
+/// use octocrab::webhooks::verify_signature;
+///
+/// verify_signature(
+///     "It's a Secret to Everybody",
+///     "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17",
+///     "Hello, World!",
+/// )?;
+/// # Ok::<(), octocrab::Error>(())
+/// ```
+pub fn verify_signature(
+    secret: impl AsRef<[u8]>,
+    signature: &str,
+    body: impl AsRef<[u8]>,
+) -> crate::Result<()> {
+    let expected = signature
+        .strip_prefix("sha256=")
+        .and_then(decode_hex)
+        .context(WebhookSnafu {
+            message: "signature is not of the form `sha256=<hex digest>`",
+        })?;
+
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_ref()).expect("HMAC can take a key of any size");
+    mac.update(body.as_ref());
+    mac.verify_slice(&expected).ok().context(WebhookSnafu {
+        message: "signature does not match the payload",
+    })
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    let pairs = hex.as_bytes().chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return None;
+    }
+    pairs
+        .map(|pair| u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok())
+        .collect()
+}
+
+/// Handles verified webhook deliveries, one method per event kind.
+///
+/// Every method defaults to doing nothing, so implementors only override the
+/// events they care about. Events without a dedicated method are passed to
+/// [`WebhookHandler::on_other`].
+#[async_trait::async_trait]
+pub trait WebhookHandler: Send + Sync {
+    async fn on_ping(
+        &self,
+        _event: &WebhookEvent,
+        _payload: &PingWebhookEventPayload,
+    ) -> crate::Result<()> {
+        Ok(())
+    }
+
+    async fn on_push(
+        &self,
+        _event: &WebhookEvent,
+        _payload: &PushWebhookEventPayload,
+    ) -> crate::Result<()> {
+        Ok(())
+    }
+
+    async fn on_pull_request(
+        &self,
+        _event: &WebhookEvent,
+        _payload: &PullRequestWebhookEventPayload,
+    ) -> crate::Result<()> {
+        Ok(())
+    }
+
+    async fn on_pull_request_review(
+        &self,
+        _event: &WebhookEvent,
+        _payload: &PullRequestReviewWebhookEventPayload,
+    ) -> crate::Result<()> {
+        Ok(())
+    }
+
+    async fn on_issues(
+        &self,
+        _event: &WebhookEvent,
+        _payload: &IssuesWebhookEventPayload,
+    ) -> crate::Result<()> {
+        Ok(())
+    }
+
+    async fn on_issue_comment(
+        &self,
+        _event: &WebhookEvent,
+        _payload: &IssueCommentWebhookEventPayload,
+    ) -> crate::Result<()> {
+        Ok(())
+    }
+
+    async fn on_check_run(
+        &self,
+        _event: &WebhookEvent,
+        _payload: &CheckRunWebhookEventPayload,
+    ) -> crate::Result<()> {
+        Ok(())
+    }
+
+    async fn on_check_suite(
+        &self,
+        _event: &WebhookEvent,
+        _payload: &CheckSuiteWebhookEventPayload,
+    ) -> crate::Result<()> {
+        Ok(())
+    }
+
+    async fn on_workflow_run(
+        &self,
+        _event: &WebhookEvent,
+        _payload: &WorkflowRunWebhookEventPayload,
+    ) -> crate::Result<()> {
+        Ok(())
+    }
+
+    async fn on_workflow_job(
+        &self,
+        _event: &WebhookEvent,
+        _payload: &WorkflowJobWebhookEventPayload,
+    ) -> crate::Result<()> {
+        Ok(())
+    }
+
+    async fn on_release(
+        &self,
+        _event: &WebhookEvent,
+        _payload: &ReleaseWebhookEventPayload,
+    ) -> crate::Result<()> {
+        Ok(())
+    }
+
+    async fn on_installation(
+        &self,
+        _event: &WebhookEvent,
+        _payload: &InstallationWebhookEventPayload,
+    ) -> crate::Result<()> {
+        Ok(())
+    }
+
+    /// Called for every event kind without a dedicated method.
+    async fn on_other(&self, _event: &WebhookEvent) -> crate::Result<()> {
+        Ok(())
+    }
+
+    /// Verifies the signature of a delivery, parses it and calls the method
+    /// matching its event kind.
+    async fn dispatch(&self, headers: &HeaderMap, body: &[u8], secret: &str) -> crate::Result<()> {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .with_context(|| WebhookSnafu {
+                    message: format!("missing or invalid `{name}` header"),
+                })
+        };
+
+        verify_signature(secret, header(SIGNATURE_HEADER)?, body)?;
+        let event = WebhookEvent::try_from_header_and_body(header(EVENT_HEADER)?, body)
+            .context(SerdeSnafu)?;
+
+        match &event.specific {
+            WebhookEventPayload::Ping(payload) => self.on_ping(&event, payload).await,
+            WebhookEventPayload::Push(payload) => self.on_push(&event, payload).await,
+            WebhookEventPayload::PullRequest(payload) => {
+                self.on_pull_request(&event, payload).await
+            }
+            WebhookEventPayload::PullRequestReview(payload) => {
+                self.on_pull_request_review(&event, payload).await
+            }
+            WebhookEventPayload::Issues(payload) => self.on_issues(&event, payload).await,
+            WebhookEventPayload::IssueComment(payload) => {
+                self.on_issue_comment(&event, payload).await
+            }
+            WebhookEventPayload::CheckRun(payload) => self.on_check_run(&event, payload).await,
+            WebhookEventPayload::CheckSuite(payload) => self.on_check_suite(&event, payload).await,
+            WebhookEventPayload::WorkflowRun(payload) => {
+                self.on_workflow_run(&event, payload).await
+            }
+            WebhookEventPayload::WorkflowJob(payload) => {
+                self.on_workflow_job(&event, payload).await
+            }
+            WebhookEventPayload::Release(payload) => self.on_release(&event, payload).await,
+            WebhookEventPayload::Installation(payload) => {
+                self.on_installation(&event, payload).await
+            }
+            _ => self.on_other(&event).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const SECRET: &str = "It's a Secret to Everybody";
+
+    fn sign(body: &[u8]) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(SECRET.as_bytes()).unwrap();
+        mac.update(body);
+        let digest = mac.finalize().into_bytes();
+        let hex: String = digest.iter().map(|byte| format!("{byte:02x}")).collect();
+        format!("sha256={hex}")
+    }
+
+    #[test]
+    fn verifies_github_example_signature() {
+        let signature = "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+
+        assert!(verify_signature(SECRET, signature, "Hello, World!").is_ok());
+        assert!(verify_signature(SECRET, signature, "Hello, World?").is_err());
+        assert!(verify_signature("wrong secret", signature, "Hello, World!").is_err());
+        assert!(verify_signature(SECRET, "sha1=757107ea", "Hello, World!").is_err());
+    }
+
+    #[derive(Default)]
+    struct Counter {
+        pings: AtomicUsize,
+        others: AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl WebhookHandler for Counter {
+        async fn on_ping(
+            &self,
+            _event: &WebhookEvent,
+            _payload: &PingWebhookEventPayload,
+        ) -> crate::Result<()> {
+            self.pings.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+
+        async fn on_other(&self, _event: &WebhookEvent) -> crate::Result<()> {
+            self.others.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    fn headers(event: &str, signature: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(EVENT_HEADER, event.parse().unwrap());
+        headers.insert(SIGNATURE_HEADER, signature.parse().unwrap());
+        headers
+    }
+
+    #[tokio::test]
+    async fn dispatches_to_matching_method() {
+        let body = include_bytes!("../tests/resources/ping_webhook_event.json");
+        let handler = Counter::default();
+
+        handler
+            .dispatch(&headers("ping", &sign(body)), body, SECRET)
+            .await
+            .unwrap();
+
+        assert_eq!(handler.pings.load(Ordering::SeqCst), 1);
+        assert_eq!(handler.others.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn rejects_unsigned_delivery() {
+        let body = include_bytes!("../tests/resources/ping_webhook_event.json");
+        let handler = Counter::default();
+
+        let result = handler
+            .dispatch(&headers("ping", &sign(b"tampered")), body, SECRET)
+            .await;
+
+        assert!(matches!(result, Err(crate::Error::Webhook { .. })));
+        assert_eq!(handler.pings.load(Ordering::SeqCst), 0);
+    }
+}
@@ -0,0 +1,82 @@
+// Tests for downloading artifacts through the Actions API.
+mod mock_error;
+
+use http_body_util::BodyExt;
+use mock_error::setup_error_handler;
+use octocrab::params::actions::ArchiveFormat;
+use octocrab::Octocrab;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const ARTIFACT_ID: u64 = 1234;
+
+async fn setup_artifact_api(body: &[u8]) -> MockServer {
+    let mock_server = MockServer::start().await;
+    let artifact_path = format!("/repos/{OWNER}/{REPO}/actions/artifacts/{ARTIFACT_ID}/zip");
+
+    Mock::given(method("GET"))
+        .and(path(&artifact_path))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(body))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on {artifact_path} was not received"),
+    )
+    .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_stream_artifact() {
+    let data = vec![7u8; 64 * 1024];
+    let mock_server = setup_artifact_api(&data).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .actions()
+        .stream_artifact(OWNER, REPO, ARTIFACT_ID.into(), ArchiveFormat::Zip)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let bytes = result.unwrap().collect().await.unwrap().to_bytes();
+    assert_eq!(bytes.as_ref(), data.as_slice());
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn should_download_artifact_to_file() {
+    let data = b"PK\x03\x04 not really a zip".to_vec();
+    let mock_server = setup_artifact_api(&data).await;
+    let client = setup_octocrab(&mock_server.uri());
+    let file = std::env::temp_dir().join(format!(
+        "octocrab-artifact-{}-{ARTIFACT_ID}.zip",
+        std::process::id()
+    ));
+
+    let result = client
+        .actions()
+        .download_artifact_to(OWNER, REPO, ARTIFACT_ID.into(), ArchiveFormat::Zip, &file)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap(), data.len() as u64);
+    assert_eq!(std::fs::read(&file).unwrap(), data);
+    std::fs::remove_file(&file).unwrap();
+}
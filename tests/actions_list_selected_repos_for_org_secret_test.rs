@@ -0,0 +1,62 @@
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::Repository;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ORG: &str = "org";
+const SECRET_NAME: &str = "some_secret";
+
+async fn setup_get_api(template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/orgs/{ORG}/actions/secrets/{SECRET_NAME}/repositories"
+        )))
+        .respond_with(template)
+        .mount(&mock_server)
+        .await;
+
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /orgs/{ORG}/actions/secrets/{SECRET_NAME}/repositories was not received"),
+    )
+    .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_list_selected_repos() {
+    let repositories: Vec<Repository> =
+        serde_json::from_str(include_str!("resources/user_repositories.json")).unwrap();
+    let template = ResponseTemplate::new(200).set_body_json(json!({
+        "total_count": repositories.len(),
+        "repositories": repositories,
+    }));
+    let mock_server = setup_get_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .actions()
+        .list_selected_repos_for_org_secret(ORG, SECRET_NAME)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let page = result.unwrap();
+    assert_eq!(page.total_count, Some(repositories.len() as u64));
+    assert_eq!(page.items, repositories);
+}
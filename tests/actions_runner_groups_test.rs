@@ -0,0 +1,204 @@
+// Tests for calls to the /orgs/{org}/actions/runner-groups API.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::actions::{RunnerGroup, RunnerGroupVisibility};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ORG: &str = "org";
+const GROUP_ID: u64 = 39;
+
+fn runner_group_json(name: &str, visibility: &str) -> serde_json::Value {
+    json!({
+        "id": GROUP_ID,
+        "name": name,
+        "visibility": visibility,
+        "default": false,
+        "inherited": false,
+        "allows_public_repositories": true,
+        "runners_url": format!("https://api.github.com/orgs/{ORG}/actions/runner-groups/{GROUP_ID}/runners"),
+        "selected_repositories_url": format!("https://api.github.com/orgs/{ORG}/actions/runner-groups/{GROUP_ID}/repositories"),
+        "restricted_to_workflows": false,
+        "selected_workflows": []
+    })
+}
+
+async fn setup_api(
+    http_method: &str,
+    api_path: String,
+    body: Option<serde_json::Value>,
+    template: ResponseTemplate,
+) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    let mut mock = Mock::given(method(http_method)).and(path(&api_path));
+    if let Some(body) = body {
+        mock = mock.and(body_json(body));
+    }
+    mock.respond_with(template).mount(&mock_server).await;
+    setup_error_handler(
+        &mock_server,
+        &format!("{http_method} on {api_path} was not received"),
+    )
+    .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_list_runner_groups() {
+    let template = ResponseTemplate::new(200).set_body_json(json!({
+        "total_count": 1,
+        "runner_groups": [runner_group_json("Default", "all")]
+    }));
+    let mock_server = setup_api(
+        "GET",
+        format!("/orgs/{ORG}/actions/runner-groups"),
+        None,
+        template,
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.actions().runner_groups(ORG).list().send().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let page = result.unwrap();
+    assert_eq!(page.total_count, Some(1));
+    assert_eq!(page.items[0].visibility, RunnerGroupVisibility::All);
+}
+
+#[tokio::test]
+async fn should_create_runner_group() {
+    let template =
+        ResponseTemplate::new(201).set_body_json(runner_group_json("Expensive", "selected"));
+    let mock_server = setup_api(
+        "POST",
+        format!("/orgs/{ORG}/actions/runner-groups"),
+        Some(json!({ "name": "Expensive", "visibility": "selected", "runners": [9, 2] })),
+        template,
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .actions()
+        .runner_groups(ORG)
+        .create(
+            "Expensive",
+            RunnerGroupVisibility::Selected,
+            vec![9.into(), 2.into()],
+        )
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let group: RunnerGroup = result.unwrap();
+    assert_eq!(group.name, "Expensive");
+}
+
+#[tokio::test]
+async fn should_update_runner_group() {
+    let template = ResponseTemplate::new(200).set_body_json(runner_group_json("Cheap", "private"));
+    let mock_server = setup_api(
+        "PATCH",
+        format!("/orgs/{ORG}/actions/runner-groups/{GROUP_ID}"),
+        Some(json!({ "name": "Cheap", "visibility": "private" })),
+        template,
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .actions()
+        .runner_groups(ORG)
+        .update(GROUP_ID.into())
+        .name("Cheap")
+        .visibility(RunnerGroupVisibility::Private)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().visibility, RunnerGroupVisibility::Private);
+}
+
+#[tokio::test]
+async fn should_delete_runner_group() {
+    let mock_server = setup_api(
+        "DELETE",
+        format!("/orgs/{ORG}/actions/runner-groups/{GROUP_ID}"),
+        None,
+        ResponseTemplate::new(204),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .actions()
+        .runner_groups(ORG)
+        .delete(GROUP_ID.into())
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_list_runners_in_group() {
+    let template = ResponseTemplate::new(200).set_body_json(json!({
+        "total_count": 1,
+        "runners": [{
+            "id": 23,
+            "runner_group_id": GROUP_ID,
+            "name": "MBP",
+            "os": "macos",
+            "status": "online",
+            "busy": true,
+            "labels": [{ "id": 5, "name": "self-hosted", "type": "read-only" }]
+        }]
+    }));
+    let mock_server = setup_api(
+        "GET",
+        format!("/orgs/{ORG}/actions/runner-groups/{GROUP_ID}/runners"),
+        None,
+        template,
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .actions()
+        .runner_groups(ORG)
+        .list_runners_in_group(GROUP_ID.into())
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().items[0].name, "MBP");
+}
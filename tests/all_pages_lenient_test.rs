@@ -0,0 +1,71 @@
+// Tests for collecting all pages while keeping partial results on failure.
+use octocrab::{Octocrab, Page};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param, query_param_is_missing},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn page_response(uri: &str, items: serde_json::Value, next: u32) -> ResponseTemplate {
+    ResponseTemplate::new(200)
+        .insert_header("Link", format!("<{uri}/numbers?page={next}>; rel=\"next\""))
+        .set_body_json(items)
+}
+
+#[tokio::test]
+async fn should_keep_items_fetched_before_an_error() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/numbers"))
+        .and(query_param_is_missing("page"))
+        .respond_with(page_response(&mock_server.uri(), json!([1, 2]), 2))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/numbers"))
+        .and(query_param("page", "2"))
+        .respond_with(page_response(&mock_server.uri(), json!([3, 4]), 3))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/numbers"))
+        .and(query_param("page", "3"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "message": "Not Found",
+            "documentation_url": "https://docs.github.com/rest"
+        })))
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let first_page: Page<u32> = client.get("/numbers", None::<&()>).await.unwrap();
+    let (items, error) = client.all_pages_lenient(first_page).await;
+
+    assert_eq!(items, vec![1, 2, 3, 4]);
+    assert!(
+        matches!(error, Some(octocrab::Error::GitHub { .. })),
+        "expected a GitHub error, got: {:#?}",
+        error
+    );
+}
+
+#[tokio::test]
+async fn should_return_no_error_when_exhausted() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/numbers"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([1, 2])))
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let first_page: Page<u32> = client.get("/numbers", None::<&()>).await.unwrap();
+    let (items, error) = client.all_pages_lenient(first_page).await;
+
+    assert_eq!(items, vec![1, 2]);
+    assert!(error.is_none(), "expected no error, got: {:#?}", error);
+}
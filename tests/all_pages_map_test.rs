@@ -0,0 +1,51 @@
+// Tests for collecting all pages into a map keyed by an extractor.
+use octocrab::{Octocrab, Page};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param, query_param_is_missing},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_index_items_of_all_pages() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/labels"))
+        .and(query_param_is_missing("page"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header(
+                    "Link",
+                    format!("<{}/labels?page=2>; rel=\"next\"", mock_server.uri()),
+                )
+                .set_body_json(json!([
+                    { "id": 1, "name": "bug" },
+                    { "id": 2, "name": "docs" }
+                ])),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/labels"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            { "id": 3, "name": "feature" }
+        ])))
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let first_page: Page<serde_json::Value> = client.get("/labels", None::<&()>).await.unwrap();
+    let labels = client
+        .all_pages_map(first_page, |label| label["id"].as_u64().unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(labels.len(), 3);
+    assert_eq!(labels[&1]["name"], "bug");
+    assert_eq!(labels[&3]["name"], "feature");
+}
@@ -0,0 +1,88 @@
+// Tests for the builder's control over plaintext HTTP requests.
+#![cfg(feature = "rustls")]
+
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+async fn setup_api() -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/zen"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!("Keep it logically awesome.")))
+        .mount(&mock_server)
+        .await;
+    mock_server
+}
+
+#[tokio::test]
+async fn should_reject_http_by_default() {
+    let mock_server = setup_api().await;
+    let client = Octocrab::builder().build().unwrap();
+
+    let result = client
+        .get::<String, _, ()>(format!("{}/zen", mock_server.uri()), None)
+        .await;
+
+    assert!(
+        result.is_err(),
+        "expected error result, got success somehow: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_allow_http_when_enabled() {
+    let mock_server = setup_api().await;
+    let client = Octocrab::builder().allow_http(true).build().unwrap();
+
+    let result = client
+        .get::<String, _, ()>(format!("{}/zen", mock_server.uri()), None)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_allow_http_for_http_base_uri() {
+    let mock_server = setup_api().await;
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let result = client.get::<String, _, ()>("/zen", None).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_reject_http_when_disallowed() {
+    let mock_server = setup_api().await;
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .allow_http(false)
+        .build()
+        .unwrap();
+
+    let result = client.get::<String, _, ()>("/zen", None).await;
+
+    assert!(
+        result.is_err(),
+        "expected error result, got success somehow: {:#?}",
+        result
+    );
+}
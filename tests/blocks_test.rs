@@ -0,0 +1,158 @@
+// Tests for blocking users as the authenticated user and as an organization.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::Octocrab;
+use serde_json::{json, Value};
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ORG: &str = "org";
+const USERNAME: &str = "spammer";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+async fn setup_api(verb: &str, route: &str, template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method(verb))
+        .and(path(route))
+        .respond_with(template)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, &format!("{verb} on {route} was not received")).await;
+    mock_server
+}
+
+fn blocked_users() -> Value {
+    serde_json::from_str(include_str!("resources/user_blocks.json")).unwrap()
+}
+
+#[tokio::test]
+async fn should_list_users_blocked_by_authenticated_user() {
+    let template = ResponseTemplate::new(200).set_body_json(blocked_users());
+    let mock_server = setup_api("GET", "/user/blocks", template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.current().list_blocked_users().send().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().items[0].login, "octocat");
+}
+
+#[tokio::test]
+async fn should_check_whether_authenticated_user_is_blocking() {
+    let route = format!("/user/blocks/{USERNAME}");
+    let mock_server = setup_api("GET", &route, ResponseTemplate::new(204)).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.current().is_blocking(USERNAME).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert!(result.unwrap());
+}
+
+#[tokio::test]
+async fn should_block_and_unblock_as_authenticated_user() {
+    let route = format!("/user/blocks/{USERNAME}");
+    let mock_server = MockServer::start().await;
+    for verb in ["PUT", "DELETE"] {
+        Mock::given(method(verb))
+            .and(path(&route))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+    let client = setup_octocrab(&mock_server.uri());
+
+    let blocked = client.current().block_user(USERNAME).await;
+    let unblocked = client.current().unblock_user(USERNAME).await;
+
+    assert!(
+        blocked.is_ok(),
+        "expected successful result, got error: {:#?}",
+        blocked
+    );
+    assert!(
+        unblocked.is_ok(),
+        "expected successful result, got error: {:#?}",
+        unblocked
+    );
+}
+
+#[tokio::test]
+async fn should_list_users_blocked_by_org() {
+    let template = ResponseTemplate::new(200).set_body_json(blocked_users());
+    let mock_server = setup_api("GET", &format!("/orgs/{ORG}/blocks"), template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.orgs(ORG).list_blocked_users().send().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().items.len(), 1);
+}
+
+#[tokio::test]
+async fn should_report_org_not_blocking() {
+    let route = format!("/orgs/{ORG}/blocks/{USERNAME}");
+    let template = ResponseTemplate::new(404).set_body_json(json!({
+        "message": "Not Found",
+        "documentation_url": "https://docs.github.com/rest"
+    }));
+    let mock_server = setup_api("GET", &route, template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.orgs(ORG).is_blocking(USERNAME).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert!(!result.unwrap());
+}
+
+#[tokio::test]
+async fn should_block_and_unblock_as_org() {
+    let route = format!("/orgs/{ORG}/blocks/{USERNAME}");
+    let mock_server = MockServer::start().await;
+    for verb in ["PUT", "DELETE"] {
+        Mock::given(method(verb))
+            .and(path(&route))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+    let client = setup_octocrab(&mock_server.uri());
+
+    let blocked = client.orgs(ORG).block_user(USERNAME).await;
+    let unblocked = client.orgs(ORG).unblock_user(USERNAME).await;
+
+    assert!(
+        blocked.is_ok(),
+        "expected successful result, got error: {:#?}",
+        blocked
+    );
+    assert!(
+        unblocked.is_ok(),
+        "expected successful result, got error: {:#?}",
+        unblocked
+    );
+}
@@ -213,7 +213,12 @@ async fn should_list_annotations() {
         result
     );
     let list_annotations_result = result.unwrap();
-    assert_eq!(list_annotations_result.len(), 1);
+    assert_eq!(list_annotations_result.items.len(), 1);
+    let annotation = &list_annotations_result.items[0];
+    assert_eq!(annotation.path, "README.md");
+    assert_eq!(annotation.start_line, 2);
+    assert_eq!(annotation.annotation_level.as_deref(), Some("warning"));
+    assert_eq!(annotation.title.as_deref(), Some("Spell Checker"));
 }
 
 #[tokio::test]
@@ -0,0 +1,115 @@
+// Tests for listing code scanning analyses and filtering code scanning alerts.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::code_scannings::{CodeScanningAlert, CodeScanningAnalysis};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_list_analyses() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/code-scanning/analyses"
+        )))
+        .and(query_param("tool_name", "CodeQL"))
+        .and(query_param("ref", "refs/heads/main"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([{
+            "ref": "refs/heads/main",
+            "commit_sha": "d99612c3e1f2970085cfbaeadf8f010ef69bad83",
+            "analysis_key": ".github/workflows/codeql-analysis.yml:analyze",
+            "environment": "{\"language\":\"python\"}",
+            "error": "",
+            "category": ".github/workflows/codeql-analysis.yml:analyze/language:python",
+            "created_at": "2020-08-27T15:05:21Z",
+            "results_count": 17,
+            "rules_count": 49,
+            "id": 201,
+            "url": "https://api.github.com/repos/octocat/hello-world/code-scanning/analyses/201",
+            "sarif_id": "6c81cd8e-b078-4ac3-a3be-1dad7dbd0b53",
+            "tool": {
+                "name": "CodeQL",
+                "guid": null,
+                "version": "2.4.0"
+            },
+            "deletable": true,
+            "warning": ""
+        }])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/code-scanning/analyses was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .code_scannings(OWNER, REPO)
+        .list_analyses()
+        .tool_name("CodeQL")
+        .reference("refs/heads/main")
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let analyses: Vec<CodeScanningAnalysis> = result.unwrap().items;
+    assert_eq!(analyses.len(), 1);
+    assert_eq!(analyses[0].id, 201);
+    assert_eq!(analyses[0].ref_field, "refs/heads/main");
+    assert_eq!(analyses[0].tool.name, "CodeQL");
+    assert_eq!(analyses[0].results_count, 17);
+}
+
+#[tokio::test]
+async fn should_filter_alerts_by_tool_and_ref() {
+    let s = include_str!("resources/codescanning_alerts_multiple.json");
+    let alerts: Vec<CodeScanningAlert> = serde_json::from_str(s).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/code-scanning/alerts")))
+        .and(query_param("tool_name", "CodeQL"))
+        .and(query_param("ref", "refs/pull/42/merge"))
+        .and(query_param("state", "open"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&alerts))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/code-scanning/alerts was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .code_scannings(OWNER, REPO)
+        .list()
+        .tool_name("CodeQL")
+        .reference("refs/pull/42/merge")
+        .state(octocrab::params::State::Open)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().items.len(), alerts.len());
+}
@@ -0,0 +1,53 @@
+// Tests for listing the pull requests associated with a commit.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::Octocrab;
+use serde_json::Value;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "owner";
+const REPO: &str = "repo";
+const SHA: &str = "6dcb09b5b57875f334f61aebed695e2e4193db5e";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_list_pull_requests_of_commit() {
+    let event: Value = serde_json::from_str(include_str!(
+        "resources/pull_request_opened_webhook_event.json"
+    ))
+    .unwrap();
+    let pull_request = event["pull_request"].clone();
+    let mock_server = MockServer::start().await;
+    let route = format!("/repos/{OWNER}/{REPO}/commits/{SHA}/pulls");
+    Mock::given(method("GET"))
+        .and(path(&route))
+        .and(query_param("per_page", "10"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(vec![pull_request.clone()]))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, &format!("GET on {route} was not received")).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .commits(OWNER, REPO)
+        .list_pull_requests(SHA)
+        .per_page(10u8)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let pulls = result.unwrap().items;
+    assert_eq!(pulls.len(), 1);
+    assert_eq!(Some(pulls[0].number), pull_request["number"].as_u64());
+}
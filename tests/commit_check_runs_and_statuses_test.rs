@@ -0,0 +1,101 @@
+// Tests for listing the check runs and statuses of a commit via the commits handler.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::StatusState;
+use octocrab::Octocrab;
+use serde_json::{json, Value};
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "some-owner";
+const REPO: &str = "some-repo";
+const SHA: &str = "6dcb09b5b57875f334f61aebed695e2e4193db5e";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_list_commit_check_runs() {
+    let check_runs: Value =
+        serde_json::from_str(include_str!("resources/commit_check_runs.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/commits/{SHA}/check-runs"
+        )))
+        .and(query_param("check_name", "test"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(check_runs))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/commits/{SHA}/check-runs was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .commits(OWNER, REPO)
+        .list_check_runs(SHA)
+        .check_name("test")
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let check_runs = result.unwrap();
+    assert_eq!(check_runs.total_count, 2);
+    assert_eq!(check_runs.check_runs.len(), 2);
+}
+
+#[tokio::test]
+async fn should_list_commit_statuses() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/commits/{SHA}/statuses"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {
+                "id": 1,
+                "state": "success",
+                "context": "continuous-integration/jenkins",
+                "description": "Build has completed successfully"
+            },
+            {
+                "id": 2,
+                "state": "pending",
+                "context": "security/brakeman"
+            }
+        ])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/commits/{SHA}/statuses was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.commits(OWNER, REPO).list_statuses(SHA).send().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let states: Vec<_> = result
+        .unwrap()
+        .items
+        .into_iter()
+        .map(|status| status.state)
+        .collect();
+    assert_eq!(states, [StatusState::Success, StatusState::Pending]);
+}
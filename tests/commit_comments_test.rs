@@ -0,0 +1,84 @@
+// Tests for calls to the /repos/{owner}/{repo}/commits/{commit_sha}/comments API.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::commits::Comment;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "octocat";
+const REPO: &str = "Hello-World";
+const SHA: &str = "6dcb09b5b57875f334f61aebed695e2e4193db5e";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_list_commit_comments() {
+    let comment: Comment =
+        serde_json::from_str(include_str!("resources/commit_comment.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/commits/{SHA}/comments"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(vec![&comment]))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/commits/{SHA}/comments was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.commits(OWNER, REPO).list_comments(SHA).send().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let page = result.unwrap();
+    assert_eq!(page.items, vec![comment]);
+}
+
+#[tokio::test]
+async fn should_create_commit_comment() {
+    let comment: Comment =
+        serde_json::from_str(include_str!("resources/commit_comment.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/commits/{SHA}/comments"
+        )))
+        .and(body_partial_json(json!({
+            "body": "Great stuff",
+            "path": "file1.txt",
+            "position": 4,
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(&comment))
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .commits(OWNER, REPO)
+        .create_comment(SHA, "Great stuff")
+        .path("file1.txt")
+        .position(4)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().body.as_deref(), Some("Great stuff"));
+}
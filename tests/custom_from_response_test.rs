@@ -0,0 +1,49 @@
+// Tests for implementing `FromResponse` on top of `from_response::body_bytes`.
+mod mock_error;
+
+use bytes::Bytes;
+use http_body::Body;
+use mock_error::setup_error_handler;
+use octocrab::{from_response::body_bytes, FromResponse, Octocrab};
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+struct Plain(String);
+
+#[async_trait::async_trait]
+impl FromResponse for Plain {
+    async fn from_response<B>(response: http::Response<B>) -> octocrab::Result<Self>
+    where
+        B: Body<Data = Bytes, Error = octocrab::Error> + Send,
+    {
+        let body = body_bytes(response).await?;
+        Ok(Plain(String::from_utf8_lossy(&body).into_owned()))
+    }
+}
+
+#[tokio::test]
+async fn should_read_raw_body() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/octocat"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("MMM. Octocat."))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /octocat was not received").await;
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let result = client.get::<Plain, _, ()>("/octocat", None).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result.err()
+    );
+    assert_eq!(result.unwrap().0, "MMM. Octocat.");
+}
@@ -1,10 +1,10 @@
 use wiremock::{
-    matchers::{method, path},
+    matchers::{body_partial_json, method, path, query_param},
     Mock, MockServer, ResponseTemplate,
 };
 
 use mock_error::setup_error_handler;
-use octocrab::models::repos::dependabot::DependabotAlert;
+use octocrab::models::repos::dependabot::{DependabotAlert, UpdateDependabotAlert};
 use octocrab::Octocrab;
 
 mod mock_error;
@@ -73,3 +73,94 @@ async fn check_dependabot_alerts_list_200() {
         assert_eq!(octocrab::models::repos::dependabot::State::Open, item.state);
     }
 }
+
+#[tokio::test]
+async fn should_join_list_filters_with_commas() {
+    let s = include_str!("resources/check_dependabot_alerts.json");
+    let alerts: Vec<DependabotAlert> = serde_json::from_str(s).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/dependabot/alerts")))
+        .and(query_param("state", "open,dismissed"))
+        .and(query_param("severity", "high,critical"))
+        .and(query_param("ecosystem", "cargo"))
+        .and(query_param("scope", "runtime"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&alerts))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/dependabot/alerts was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .dependabot()
+        .state(vec!["open".to_owned(), "dismissed".to_owned()])
+        .severity(vec!["high".to_owned(), "critical".to_owned()])
+        .ecosystem(vec!["cargo".to_owned()])
+        .scope("runtime")
+        .get_alerts()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().items.len(), 5);
+}
+
+#[tokio::test]
+async fn should_get_and_dismiss_an_alert() {
+    let s = include_str!("resources/check_dependabot_alerts.json");
+    let alerts: Vec<DependabotAlert> = serde_json::from_str(s).unwrap();
+    let alert = &alerts[0];
+    let route = format!("/repos/{OWNER}/{REPO}/dependabot/alerts/{}", alert.number);
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(&route))
+        .respond_with(ResponseTemplate::new(200).set_body_json(alert))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path(&route))
+        .and(body_partial_json(serde_json::json!({
+            "state": "dismissed",
+            "dismissed_reason": "tolerable_risk",
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(alert))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, &format!("GET on {route} was not received")).await;
+    let client = setup_octocrab(&mock_server.uri());
+    let repo = client.repos(OWNER, REPO);
+
+    let result = repo.dependabot().get_alert(alert.number as u32).await;
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().number, alert.number);
+
+    let result = repo
+        .dependabot()
+        .update_alert(
+            alert.number as u32,
+            Some(&UpdateDependabotAlert {
+                state: "dismissed",
+                dismissed_reason: Some("tolerable_risk"),
+                dismissed_comment: None,
+            }),
+        )
+        .await;
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
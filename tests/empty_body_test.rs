@@ -0,0 +1,94 @@
+// Tests for deserializing empty (e.g. `204 No Content`) response bodies.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::Repository;
+use octocrab::Octocrab;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+async fn setup_api(verb: &str, route: &str) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method(verb))
+        .and(path(route))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, &format!("{verb} on {route} was not received")).await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_deserialize_empty_body_into_unit() {
+    let mock_server = setup_api("DELETE", "/user/starred/owner/repo").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .delete::<(), _, ()>("/user/starred/owner/repo", None)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_deserialize_empty_body_into_none() {
+    let mock_server = setup_api("GET", "/repos/owner/repo").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .get::<Option<Repository>, _, ()>("/repos/owner/repo", None)
+        .await;
+
+    assert!(
+        matches!(result, Ok(None)),
+        "expected Ok(None), got: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_report_route_of_empty_body() {
+    let mock_server = setup_api("GET", "/repos/owner/repo").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .get::<Repository, _, ()>("/repos/owner/repo", None)
+        .await;
+
+    match result {
+        Err(octocrab::Error::EmptyBody { route, .. }) => assert_eq!(route, "/repos/owner/repo"),
+        other => panic!("expected an EmptyBody error, got: {:#?}", other),
+    }
+}
+
+#[tokio::test]
+async fn should_not_treat_whitespace_body_as_empty() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("\n"))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /repos/owner/repo was not received").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .get::<Option<Repository>, _, ()>("/repos/owner/repo", None)
+        .await;
+
+    assert!(
+        matches!(result, Err(octocrab::Error::Json { .. })),
+        "expected a Json error, got: {:#?}",
+        result
+    );
+}
@@ -0,0 +1,68 @@
+// Tests that credentials are only sent to the configured base URI, e.g. a
+// GitHub Enterprise Server host, when following absolute URIs.
+use http::Uri;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, Request, ResponseTemplate,
+};
+
+// "user:pass" encoded as base64.
+const BASIC_AUTH: &str = "Basic dXNlcjpwYXNz";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder()
+        .base_uri(uri)
+        .unwrap()
+        .basic_auth("user".to_string(), "pass".to_string())
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn should_send_credentials_to_absolute_base_uri() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/user/repos"))
+        .and(header("authorization", BASIC_AUTH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let next: Uri = format!("{}/user/repos?page=2", mock_server.uri())
+        .parse()
+        .unwrap();
+    let result = client.get_page::<serde_json::Value>(&Some(next)).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_not_send_credentials_to_other_hosts() {
+    let github = MockServer::start().await;
+    let third_party = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/download"))
+        .and(|request: &Request| !request.headers.contains_key("authorization"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(1)
+        .mount(&third_party)
+        .await;
+    let client = setup_octocrab(&github.uri());
+
+    let uri: Uri = format!("{}/download", third_party.uri()).parse().unwrap();
+    let result = client.get_page::<serde_json::Value>(&Some(uri)).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
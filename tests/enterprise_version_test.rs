@@ -0,0 +1,60 @@
+// Tests for detecting the GitHub Enterprise Server version from /meta.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+async fn setup_api(template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/meta"))
+        .respond_with(template)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /meta was not received").await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_detect_enterprise_version() {
+    let template = ResponseTemplate::new(200)
+        .insert_header("X-GitHub-Enterprise-Version", "3.12.4")
+        .set_body_json(json!({ "verifiable_password_authentication": true }));
+    let mock_server = setup_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.detect_enterprise_version().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().as_deref(), Some("3.12.4"));
+}
+
+#[tokio::test]
+async fn should_return_none_without_enterprise_header() {
+    let template = ResponseTemplate::new(200)
+        .set_body_json(json!({ "verifiable_password_authentication": true }));
+    let mock_server = setup_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.detect_enterprise_version().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap(), None);
+}
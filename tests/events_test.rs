@@ -54,6 +54,7 @@ async fn should_return_page_with_events_and_etag() {
         Etagged {
             etag: Some(etag),
             value: Some(mut page),
+            ..
         } => {
             assert_eq!(page.take_items(), page_response.items);
             assert_eq!(etag, EntityTag::strong(expected_etag.replace('\"', "")));
@@ -78,6 +79,7 @@ async fn should_return_no_page_with_events_and_etag_when_response_is_304() {
         Etagged {
             etag: Some(etag),
             value: None,
+            ..
         } => {
             assert_eq!(etag, EntityTag::strong(expected_etag.replace('\"', "")));
         }
@@ -103,9 +105,49 @@ async fn should_return_no_etag_if_response_contains_none() {
         Etagged {
             etag: None,
             value: Some(mut page),
+            ..
         } => {
             assert_eq!(page.take_items(), page_response.items);
         }
         unexpected => panic!("expected a page with no etag, got {:#?}", unexpected),
     }
 }
+
+#[tokio::test]
+async fn should_list_scoped_events() {
+    let event: events::Event =
+        serde_json::from_str(include_str!("resources/create_event.json")).unwrap();
+    let page_response = FakePage { items: vec![event] };
+    let mock_server = MockServer::start().await;
+    for scoped_path in [
+        "/repos/owner/repo/events",
+        "/orgs/org/events",
+        "/users/octocat/events",
+        "/users/octocat/received_events",
+    ] {
+        Mock::given(method("GET"))
+            .and(path(scoped_path))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&page_response))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+    setup_error_handler(&mock_server, "GET on scoped events was not received").await;
+    let octo = setup_octocrab(&mock_server.uri());
+
+    let results = [
+        octo.events().for_repo("owner", "repo").send().await,
+        octo.events().for_org("org").send().await,
+        octo.events().for_user("octocat").send().await,
+        octo.events().received_by_user("octocat").send().await,
+    ];
+
+    for result in results {
+        assert!(
+            result.is_ok(),
+            "expected successful result, got error: {:#?}",
+            result
+        );
+        assert!(result.unwrap().value.is_some());
+    }
+}
@@ -0,0 +1,46 @@
+// Tests for building a client directly from a `tower::Service`.
+use std::convert::Infallible;
+
+use bytes::Bytes;
+use http_body_util::Full;
+use octocrab::models::Repository;
+use octocrab::{AuthState, OctoBody, Octocrab};
+
+#[tokio::test]
+async fn should_send_requests_through_service() {
+    let service = tower::service_fn(|request: http::Request<OctoBody>| async move {
+        let response = if request.uri().path() == "/repos/owner/repo" {
+            let repositories: Vec<Repository> =
+                serde_json::from_str(include_str!("resources/user_repositories.json")).unwrap();
+            http::Response::builder()
+                .status(200)
+                .body(Full::new(Bytes::from(
+                    serde_json::to_vec(&repositories[0]).unwrap(),
+                )))
+                .unwrap()
+        } else {
+            http::Response::builder()
+                .status(404)
+                .body(Full::new(Bytes::from_static(
+                    br#"{"message": "Not Found", "documentation_url": "https://docs.github.com/rest"}"#,
+                )))
+                .unwrap()
+        };
+        Ok::<_, Infallible>(response)
+    });
+    let client = Octocrab::from_service(service, AuthState::None);
+
+    let result = client.repos("owner", "repo").get().await;
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+
+    let result = client.repos("owner", "missing").get().await;
+    assert!(
+        result.is_err(),
+        "expected error result, got success somehow: {:#?}",
+        result
+    );
+}
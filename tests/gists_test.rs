@@ -1,9 +1,11 @@
 mod mock_error;
 
 use mock_error::setup_error_handler;
+use octocrab::gists::UpdateGistFile;
 use octocrab::Octocrab;
+use std::collections::HashMap;
 use wiremock::{
-    matchers::{method, path},
+    matchers::{body_json, method, path},
     Mock, MockServer, ResponseTemplate,
 };
 
@@ -302,3 +304,223 @@ async fn test_delete_gist_500() {
         result
     );
 }
+
+#[tokio::test]
+async fn test_get_gist() {
+    let gist_id: &str = "aa5a315d61ae9438b18d";
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/gists/{gist_id}")))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(include_str!("resources/gist.json"))
+                .insert_header("content-type", "application/json"),
+        )
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /gists/{gist_id} was not received"),
+    )
+    .await;
+
+    let crab = setup_octocrab(&mock_server.uri());
+    let result = crab.gists().get(gist_id).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let gist = result.unwrap();
+    assert_eq!(gist.owner.unwrap().login, "iamjpotts");
+    assert_eq!(gist.truncated, Some(false));
+    let file = &gist.files["hello_world.rb"];
+    assert_eq!(file.language.as_deref(), Some("Ruby"));
+    assert_eq!(file.size, 167);
+    assert!(file
+        .content
+        .as_deref()
+        .unwrap()
+        .starts_with("class HelloWorld"));
+    let history = gist.history.unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(
+        history[0].version,
+        "57a7f021a713b1c5a6a199b54cc514735d2d462f"
+    );
+}
+
+#[tokio::test]
+async fn test_update_gist_files() {
+    let gist_id: &str = "aa5a315d61ae9438b18d";
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PATCH"))
+        .and(path(format!("/gists/{gist_id}")))
+        .and(body_json(serde_json::json!({
+            "description": "Hello World Examples",
+            "files": {
+                "hello_world.rb": { "content": "puts 'hello'" },
+                "hello_world.py": { "filename": "hello.py" },
+                "hello_world.js": null
+            }
+        })))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(include_str!("resources/gist.json"))
+                .insert_header("content-type", "application/json"),
+        )
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("PATCH on /gists/{gist_id} was not received"),
+    )
+    .await;
+
+    let mut files = HashMap::new();
+    files.insert(
+        "hello_world.rb",
+        Some(UpdateGistFile::default().content("puts 'hello'")),
+    );
+    files.insert(
+        "hello_world.py",
+        Some(UpdateGistFile::default().filename("hello.py")),
+    );
+    files.insert("hello_world.js", None);
+
+    let crab = setup_octocrab(&mock_server.uri());
+    let result = crab
+        .gists()
+        .update(gist_id)
+        .description("Hello World Examples")
+        .files(files)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+fn gist_comment() -> serde_json::Value {
+    serde_json::json!({
+        "id": 1,
+        "node_id": "MDExOkdpc3RDb21tZW50MQ==",
+        "url": "https://api.github.com/gists/a6db0bec360bb87e9418/comments/1",
+        "body": "Just commenting for the sake of commenting",
+        "user": serde_json::from_str::<serde_json::Value>(include_str!("resources/user_blocks.json"))
+            .unwrap()[0],
+        "created_at": "2011-04-18T23:23:56Z",
+        "updated_at": "2011-04-18T23:23:56Z",
+        "author_association": "COLLABORATOR"
+    })
+}
+
+#[tokio::test]
+async fn test_list_gist_comments() {
+    let gist_id: &str = "aa5a315d61ae9438b18d";
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/gists/{gist_id}/comments")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([gist_comment()])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /gists/{gist_id}/comments was not received"),
+    )
+    .await;
+
+    let crab = setup_octocrab(&mock_server.uri());
+    let result = crab
+        .gists()
+        .list_comments(gist_id)
+        .per_page(100)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let comments = result.unwrap().items;
+    assert_eq!(comments[0].id, 1u64.into());
+    assert_eq!(comments[0].user.as_ref().unwrap().login, "octocat");
+}
+
+#[tokio::test]
+async fn test_gist_comment_crud() {
+    let gist_id: &str = "aa5a315d61ae9438b18d";
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/gists/{gist_id}/comments")))
+        .and(body_json(serde_json::json!({ "body": "Nice gist!" })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(gist_comment()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path(format!("/gists/{gist_id}/comments/1")))
+        .and(body_json(serde_json::json!({ "body": "Nicer gist!" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(gist_comment()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path(format!("/gists/{gist_id}/comments/1")))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let crab = setup_octocrab(&mock_server.uri());
+    let created = crab.gists().create_comment(gist_id, "Nice gist!").await;
+    assert!(
+        created.is_ok(),
+        "expected successful result, got error: {:#?}",
+        created
+    );
+    let comment_id = created.unwrap().id;
+
+    let updated = crab
+        .gists()
+        .update_comment(gist_id, comment_id, "Nicer gist!")
+        .await;
+    assert!(
+        updated.is_ok(),
+        "expected successful result, got error: {:#?}",
+        updated
+    );
+
+    let deleted = crab.gists().delete_comment(gist_id, comment_id).await;
+    assert!(
+        deleted.is_ok(),
+        "expected successful result, got error: {:#?}",
+        deleted
+    );
+}
+
+#[tokio::test]
+async fn test_list_starred_gists() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/gists/starred"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /gists/starred was not received").await;
+
+    let crab = setup_octocrab(&mock_server.uri());
+    let result = crab.gists().list_starred().per_page(100).send().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert!(result.unwrap().items.is_empty());
+}
@@ -0,0 +1,131 @@
+// Tests for looking up objects by global node ID with Octocrab::node.
+use octocrab::models::graphql::GraphQLNode;
+use octocrab::Octocrab;
+use serde::Deserialize;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const NODE_ID: &str = "I_kwDOD4Ga3s5zGq5L";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Issue {
+    number: u64,
+    title: String,
+    author_association: String,
+}
+
+impl GraphQLNode for Issue {
+    const TYPE_NAME: &'static str = "Issue";
+    const FIELDS: &'static str = "number title authorAssociation";
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+async fn setup_api(node: serde_json::Value) -> MockServer {
+    setup_api_with_response(json!({ "data": { "node": node } })).await
+}
+
+async fn setup_api_with_response(response: serde_json::Value) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_partial_json(json!({
+            "query": "query($id: ID!) { node(id: $id) { __typename ... on Issue { number title authorAssociation } } }",
+            "variables": { "id": NODE_ID },
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    mock_server
+}
+
+#[tokio::test]
+async fn should_look_up_node_of_expected_type() {
+    let mock_server = setup_api(json!({
+        "__typename": "Issue",
+        "number": 42,
+        "title": "Support node lookups",
+        "authorAssociation": "OWNER",
+    }))
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.node::<Issue>(NODE_ID).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let issue = result.unwrap().unwrap();
+    assert_eq!(issue.number, 42);
+    assert_eq!(issue.title, "Support node lookups");
+    assert_eq!(issue.author_association, "OWNER");
+}
+
+#[tokio::test]
+async fn should_return_none_for_missing_node() {
+    let mock_server = setup_api_with_response(json!({
+        "data": { "node": null },
+        "errors": [{
+            "type": "NOT_FOUND",
+            "path": ["node"],
+            "locations": [{ "line": 1, "column": 18 }],
+            "message": "Could not resolve to a node with the global id of 'I_kwDOD4Ga3s5zGq5L'"
+        }]
+    }))
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.node::<Issue>(NODE_ID).await;
+
+    assert!(
+        matches!(result, Ok(None)),
+        "expected no node, got: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_fail_for_node_of_other_type() {
+    let mock_server = setup_api(json!({ "__typename": "PullRequest" })).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.node::<Issue>(NODE_ID).await;
+
+    assert!(
+        matches!(result, Err(octocrab::Error::Other { .. })),
+        "expected an error, got: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_fail_for_other_errors() {
+    let mock_server = setup_api_with_response(json!({
+        "data": { "node": null },
+        "errors": [{
+            "type": "FORBIDDEN",
+            "path": ["node"],
+            "locations": [{ "line": 1, "column": 18 }],
+            "message": "Resource not accessible by integration"
+        }]
+    }))
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.node::<Issue>(NODE_ID).await;
+
+    assert!(
+        matches!(result, Err(octocrab::Error::Other { .. })),
+        "expected an error, got: {:#?}",
+        result
+    );
+}
@@ -0,0 +1,108 @@
+// Tests for following GraphQL connection cursors with Octocrab::graphql_paginate.
+use octocrab::Octocrab;
+use serde::Deserialize;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[derive(Debug, Deserialize)]
+struct Issue {
+    number: u64,
+}
+
+const QUERY: &str = "query($cursor: String) { repository(owner: \"owner\", name: \"repo\") { issues(first: 2, after: $cursor) { nodes { number } pageInfo { hasNextPage endCursor } } } }";
+
+fn connection_page(numbers: &[u64], end_cursor: Option<&str>) -> serde_json::Value {
+    let nodes: Vec<_> = numbers
+        .iter()
+        .map(|number| json!({ "number": number }))
+        .collect();
+    json!({
+        "data": {
+            "repository": {
+                "issues": {
+                    "nodes": nodes,
+                    "pageInfo": {
+                        "hasNextPage": end_cursor.is_some(),
+                        "endCursor": end_cursor,
+                    }
+                }
+            }
+        }
+    })
+}
+
+async fn setup_api() -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_partial_json(
+            json!({ "variables": { "cursor": null } }),
+        ))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(connection_page(&[1, 2], Some("Y3Vyc29yOjI="))),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_partial_json(
+            json!({ "variables": { "cursor": "Y3Vyc29yOjI=" } }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(connection_page(&[3], None)))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_follow_end_cursor_until_last_page() {
+    let mock_server = setup_api().await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .graphql_paginate::<Issue>(QUERY, None, "cursor", &["repository", "issues"])
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let numbers: Vec<u64> = result.unwrap().iter().map(|issue| issue.number).collect();
+    assert_eq!(numbers, vec![1, 2, 3]);
+}
+
+#[tokio::test]
+async fn should_return_error_when_query_fails() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": null,
+            "errors": [{ "message": "Could not resolve to a Repository" }]
+        })))
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .graphql_paginate::<Issue>(QUERY, None, "cursor", &["repository", "issues"])
+        .await;
+
+    assert!(
+        result.is_err(),
+        "expected error result, got success somehow: {:#?}",
+        result
+    );
+}
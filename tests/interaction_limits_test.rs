@@ -0,0 +1,138 @@
+// Tests for calls to the interaction limits API of users, orgs and repos.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::interactions::{InteractionExpiry, InteractionGroup, SetInteractionLimit};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "owner";
+const REPO: &str = "repo";
+const ORG: &str = "org";
+
+async fn setup_api(
+    http_method: &str,
+    api_path: String,
+    body: Option<serde_json::Value>,
+    template: ResponseTemplate,
+) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    let mut mock = Mock::given(method(http_method)).and(path(&api_path));
+    if let Some(body) = body {
+        mock = mock.and(body_json(body));
+    }
+    mock.respond_with(template).mount(&mock_server).await;
+    setup_error_handler(
+        &mock_server,
+        &format!("{http_method} on {api_path} was not received"),
+    )
+    .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn interaction_limit(origin: &str) -> serde_json::Value {
+    json!({
+        "limit": "collaborators_only",
+        "origin": origin,
+        "expires_at": "2018-08-17T04:18:39Z"
+    })
+}
+
+#[tokio::test]
+async fn should_get_org_interaction_limits() {
+    let template = ResponseTemplate::new(200).set_body_json(interaction_limit("organization"));
+    let mock_server = setup_api(
+        "GET",
+        format!("/orgs/{ORG}/interaction-limits"),
+        None,
+        template,
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.orgs(ORG).get_interaction_limits().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let limit = result.unwrap().expect("expected a limit");
+    assert_eq!(limit.limit, InteractionGroup::CollaboratorsOnly);
+    assert_eq!(limit.origin, "organization");
+}
+
+#[tokio::test]
+async fn should_return_none_without_repo_interaction_limits() {
+    let template = ResponseTemplate::new(200).set_body_json(json!({}));
+    let mock_server = setup_api(
+        "GET",
+        format!("/repos/{OWNER}/{REPO}/interaction-limits"),
+        None,
+        template,
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).get_interaction_limits().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap(), None);
+}
+
+#[tokio::test]
+async fn should_set_user_interaction_limits() {
+    let template = ResponseTemplate::new(200).set_body_json(interaction_limit("user"));
+    let mock_server = setup_api(
+        "PUT",
+        "/user/interaction-limits".to_string(),
+        Some(json!({ "limit": "collaborators_only", "expiry": "one_week" })),
+        template,
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+    let mut limit = SetInteractionLimit::new(InteractionGroup::CollaboratorsOnly);
+    limit.expiry = Some(InteractionExpiry::OneWeek);
+
+    let result = client.current().set_interaction_limits(&limit).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().origin, "user");
+}
+
+#[tokio::test]
+async fn should_remove_org_interaction_limits() {
+    let mock_server = setup_api(
+        "DELETE",
+        format!("/orgs/{ORG}/interaction-limits"),
+        None,
+        ResponseTemplate::new(204),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.orgs(ORG).remove_interaction_limits().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
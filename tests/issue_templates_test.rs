@@ -0,0 +1,175 @@
+// Tests for IssueHandler::list_issue_templates.
+#![cfg(feature = "issue-templates")]
+
+mod mock_error;
+
+use base64::Engine;
+use mock_error::setup_error_handler;
+use octocrab::models::issues::IssueTemplateBody;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const TEMPLATE_DIR: &str = ".github/ISSUE_TEMPLATE";
+
+const BUG_REPORT: &str = r#"---
+name: Bug report
+about: Create a report to help us improve
+title: "[BUG] "
+labels: bug, triage
+assignees: ''
+---
+
+**Describe the bug**
+A clear and concise description of what the bug is.
+"#;
+
+const FEATURE_REQUEST: &str = r#"name: Feature request
+description: Suggest an idea for this project
+title: "[Feature]: "
+labels: ["enhancement"]
+body:
+  - type: textarea
+    id: problem
+    attributes:
+      label: What problem does this solve?
+    validations:
+      required: true
+"#;
+
+fn content(name: &str, contents: Option<&str>) -> serde_json::Value {
+    let file_path = format!("{TEMPLATE_DIR}/{name}");
+    let url = format!("https://api.github.com/repos/{OWNER}/{REPO}/contents/{file_path}");
+    json!({
+        "name": name,
+        "path": file_path,
+        "sha": "3d21ec53a331a6f037a91c368710b99387d012c1",
+        "size": contents.map_or(0, str::len),
+        "url": url,
+        "html_url": null,
+        "git_url": null,
+        "download_url": null,
+        "type": "file",
+        "encoding": contents.map(|_| "base64"),
+        "content": contents.map(|c| base64::prelude::BASE64_STANDARD.encode(c)),
+        "_links": { "git": null, "html": null, "self": url }
+    })
+}
+
+async fn mount_content(mock_server: &MockServer, name: &str, body: serde_json::Value) {
+    let route = if name.is_empty() {
+        format!("/repos/{OWNER}/{REPO}/contents/{TEMPLATE_DIR}")
+    } else {
+        format!("/repos/{OWNER}/{REPO}/contents/{TEMPLATE_DIR}/{name}")
+    };
+    Mock::given(method("GET"))
+        .and(path(route))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(mock_server)
+        .await;
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_list_issue_templates() {
+    let mock_server = MockServer::start().await;
+    mount_content(
+        &mock_server,
+        "",
+        json!([
+            content("bug_report.md", None),
+            content("config.yml", None),
+            content("feature_request.yml", None)
+        ]),
+    )
+    .await;
+    mount_content(
+        &mock_server,
+        "bug_report.md",
+        content("bug_report.md", Some(BUG_REPORT)),
+    )
+    .await;
+    mount_content(
+        &mock_server,
+        "feature_request.yml",
+        content("feature_request.yml", Some(FEATURE_REQUEST)),
+    )
+    .await;
+    setup_error_handler(&mock_server, "GET on issue templates was not received").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.issues(OWNER, REPO).list_issue_templates().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let templates = result.unwrap();
+    assert_eq!(templates.len(), 2);
+
+    let bug = &templates[0];
+    assert_eq!(bug.name, "Bug report");
+    assert_eq!(bug.title.as_deref(), Some("[BUG] "));
+    assert_eq!(bug.labels, vec!["bug", "triage"]);
+    assert!(bug.assignees.is_empty());
+    assert!(
+        matches!(&bug.body, IssueTemplateBody::Markdown(body) if body.contains("Describe the bug"))
+    );
+
+    let feature = &templates[1];
+    assert_eq!(feature.name, "Feature request");
+    assert_eq!(
+        feature.about.as_deref(),
+        Some("Suggest an idea for this project")
+    );
+    assert_eq!(feature.labels, vec!["enhancement"]);
+    let IssueTemplateBody::Form(elements) = &feature.body else {
+        panic!("expected an issue form, got {:?}", feature.body);
+    };
+    assert_eq!(elements[0]["validations"]["required"], json!(true));
+}
+
+#[tokio::test]
+async fn should_skip_malformed_issue_templates() {
+    let mock_server = MockServer::start().await;
+    mount_content(
+        &mock_server,
+        "",
+        json!([content("broken.md", None), content("bug_report.md", None)]),
+    )
+    .await;
+    mount_content(
+        &mock_server,
+        "broken.md",
+        content("broken.md", Some("no front matter here")),
+    )
+    .await;
+    mount_content(
+        &mock_server,
+        "bug_report.md",
+        content("bug_report.md", Some(BUG_REPORT)),
+    )
+    .await;
+    setup_error_handler(&mock_server, "GET on issue templates was not received").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.issues(OWNER, REPO).list_issue_templates().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let templates = result.unwrap();
+    assert_eq!(templates.len(), 1);
+    assert_eq!(templates[0].name, "Bug report");
+}
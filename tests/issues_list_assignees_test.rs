@@ -0,0 +1,58 @@
+// Tests for calls to the /repos/{owner}/{repo}/assignees API.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::{models::Author, Octocrab};
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+
+async fn setup_api(template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/assignees")))
+        .and(query_param("per_page", "50"))
+        .respond_with(template)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/assignees was not received"),
+    )
+    .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_return_page_with_assignees() {
+    let assignee: Author =
+        serde_json::from_str(include_str!("resources/org_members.json")).unwrap();
+    let template = ResponseTemplate::new(200).set_body_json(vec![&assignee]);
+    let mock_server = setup_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .issues(OWNER, REPO)
+        .list_assignees()
+        .per_page(50)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let items = result.unwrap().items;
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].login, assignee.login);
+}
@@ -0,0 +1,58 @@
+// Tests for calls to the /repos/{owner}/{repo}/issues/comments API.
+mod mock_error;
+
+use chrono::{TimeZone, Utc};
+use mock_error::setup_error_handler;
+use octocrab::params::{issues::Sort, Direction};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_list_comments_across_the_repo() {
+    let event: serde_json::Value = serde_json::from_str(include_str!(
+        "resources/issue_comment_created_webhook_event.json"
+    ))
+    .unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/issues/comments")))
+        .and(query_param("sort", "updated"))
+        .and(query_param("direction", "desc"))
+        .and(query_param("since", "2024-01-01T00:00:00Z"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([event["comment"]])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/issues/comments was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .issues(OWNER, REPO)
+        .list_issue_comments()
+        .sort(Sort::Updated)
+        .direction(Direction::Descending)
+        .since(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap())
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().items.len(), 1);
+}
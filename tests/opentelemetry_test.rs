@@ -0,0 +1,62 @@
+// Tests for propagating the OpenTelemetry context of requests.
+#![cfg(feature = "opentelemetry")]
+
+use octocrab::Octocrab;
+use opentelemetry::trace::{TraceContextExt, TracerProvider as _};
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::TracerProvider;
+use serde_json::json;
+use tracing::Instrument;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use tracing_subscriber::layer::SubscriberExt;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test]
+async fn should_propagate_trace_context_of_current_span() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/zen"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!("Keep it logically awesome.")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    opentelemetry::global::set_text_map_propagator(TraceContextPropagator::new());
+    let provider = TracerProvider::builder().build();
+    let subscriber = tracing_subscriber::registry()
+        .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("octocrab-test")));
+    let _guard = tracing::subscriber::set_default(subscriber);
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let span = tracing::info_span!("sync");
+    let trace_id = span.context().span().span_context().trace_id();
+    let result = client
+        .get::<String, _, ()>("/zen", None)
+        .instrument(span)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let requests = mock_server.received_requests().await.unwrap();
+    let traceparent = requests[0]
+        .headers
+        .get("traceparent")
+        .expect("request carries a traceparent header")
+        .to_str()
+        .unwrap();
+    assert!(
+        traceparent.starts_with(&format!("00-{}-", trace_id)),
+        "expected trace {}, got: {}",
+        trace_id,
+        traceparent
+    );
+}
@@ -0,0 +1,185 @@
+// Tests for labelling requests with operation names via `Octocrab::operation`.
+#![cfg(feature = "default-client")]
+
+use http::{HeaderValue, Request};
+use octocrab::{OctoBody, Octocrab};
+use serde_json::json;
+use tower::util::MapRequestLayer;
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+/// Exposes the request's operation name as a header so the mock can match it.
+fn expose_operation(mut request: Request<OctoBody>) -> Request<OctoBody> {
+    let name = request
+        .extensions()
+        .get::<&'static str>()
+        .copied()
+        .unwrap_or("unlabelled");
+    request
+        .headers_mut()
+        .insert("x-operation", HeaderValue::from_static(name));
+    request
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder()
+        .base_uri(uri)
+        .unwrap()
+        .with_post_auth_layer(MapRequestLayer::new(expose_operation))
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn should_label_requests_with_operation_name() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/zen"))
+        .and(header("x-operation", "meta.zen"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!("Keep it logically awesome.")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let operation = client.operation("meta.zen");
+    assert_eq!(operation.name(), "meta.zen");
+    let result = operation.get::<String, _, ()>("/zen", None).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_leave_plain_requests_unlabelled() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/zen"))
+        .and(header("x-operation", "unlabelled"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!("Keep it logically awesome.")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.get::<String, _, ()>("/zen", None).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_label_requests_of_typed_handlers() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/issues"))
+        .and(header("x-operation", "issues.list"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/pulls"))
+        .and(header("x-operation", "pulls.list"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let issues = client.issues("owner", "repo").list().send().await;
+    assert!(
+        issues.is_ok(),
+        "expected successful result, got error: {:#?}",
+        issues
+    );
+    let pulls = client.pulls("owner", "repo").list().send().await;
+    assert!(
+        pulls.is_ok(),
+        "expected successful result, got error: {:#?}",
+        pulls
+    );
+}
+
+#[tokio::test]
+async fn should_label_requests_of_every_handler() {
+    use octocrab::models::{CheckRunId, RunnerId};
+    use octocrab::params::repos::Reference;
+
+    let mock_server = MockServer::start().await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    // Nothing is mounted, so every request fails; only the labels matter.
+    let _ = client.issues("owner", "repo").get(1).await;
+    let _ = client
+        .issues("owner", "repo")
+        .update(1)
+        .title("title")
+        .send()
+        .await;
+    let _ = client.pulls("owner", "repo").get(1).await;
+    let _ = client.pulls("owner", "repo").merge(1).send().await;
+    let _ = client
+        .repos("owner", "repo")
+        .get_ref(&Reference::Branch("main".to_string()))
+        .await;
+    let _ = client.repos("owner", "repo").releases().get(1).await;
+    let _ = client.repos("owner", "repo").hooks().get(1u64).await;
+    let _ = client.commits("owner", "repo").get("main").await;
+    let _ = client.orgs("owner").get().await;
+    let _ = client.users("owner").profile().await;
+    let _ = client.gists().get("aa5a315d61ae9438b18d").await;
+    let _ = client.search().repositories("octocrab").send().await;
+    let _ = client
+        .checks("owner", "repo")
+        .get_check_run(CheckRunId(1))
+        .send()
+        .await;
+    let _ = client
+        .actions()
+        .get_repo_runner("owner", "repo", RunnerId(1))
+        .await;
+
+    let operations: Vec<String> = mock_server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .map(|request| {
+            request
+                .headers
+                .get("x-operation")
+                .expect("request carries an x-operation header")
+                .to_str()
+                .unwrap()
+                .to_string()
+        })
+        .collect();
+    assert_eq!(
+        operations,
+        [
+            "issues.get",
+            "issues.update",
+            "pulls.get",
+            "pulls.merge",
+            "repos.get_ref",
+            "repos.releases.get",
+            "webhooks.get",
+            "commits.get",
+            "orgs.get",
+            "users.profile",
+            "gists.get",
+            "search.repositories",
+            "checks.get_check_run",
+            "actions.get_repo_runner",
+        ]
+    );
+}
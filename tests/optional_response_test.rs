@@ -0,0 +1,126 @@
+// Tests for the `*_opt` HTTP methods, which return `None` on 404.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+async fn setup_api(http_method: &str, template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method(http_method))
+        .and(path("/thing"))
+        .respond_with(template)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("{http_method} on /thing was not received"),
+    )
+    .await;
+    mock_server
+}
+
+fn not_found() -> ResponseTemplate {
+    ResponseTemplate::new(404).set_body_json(json!({
+        "message": "Not Found",
+        "documentation_url": "https://docs.github.com/rest"
+    }))
+}
+
+#[tokio::test]
+async fn get_opt_should_return_some_on_success() {
+    let template = ResponseTemplate::new(200).set_body_json(json!({ "name": "thing" }));
+    let mock_server = setup_api("GET", template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .get_opt::<serde_json::Value, _, _>("/thing", None::<&()>)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap(), Some(json!({ "name": "thing" })));
+}
+
+#[tokio::test]
+async fn get_opt_should_return_none_on_404() {
+    let mock_server = setup_api("GET", not_found()).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .get_opt::<serde_json::Value, _, _>("/thing", None::<&()>)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap(), None);
+}
+
+#[tokio::test]
+async fn get_opt_should_map_other_errors() {
+    let template = ResponseTemplate::new(403).set_body_json(json!({
+        "message": "Forbidden",
+        "documentation_url": "https://docs.github.com/rest"
+    }));
+    let mock_server = setup_api("GET", template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .get_opt::<serde_json::Value, _, _>("/thing", None::<&()>)
+        .await;
+
+    match result {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, http::StatusCode::FORBIDDEN)
+        }
+        other => panic!("expected a GitHub error, got {:#?}", other),
+    }
+}
+
+#[tokio::test]
+async fn patch_opt_should_return_none_on_404() {
+    let mock_server = setup_api("PATCH", not_found()).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .patch_opt::<serde_json::Value, _, _>("/thing", Some(&json!({ "name": "new" })))
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap(), None);
+}
+
+#[tokio::test]
+async fn put_opt_should_return_none_on_404() {
+    let mock_server = setup_api("PUT", not_found()).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .put_opt::<serde_json::Value, _, _>("/thing", None::<&()>)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap(), None);
+}
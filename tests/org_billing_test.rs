@@ -0,0 +1,103 @@
+// Tests for calls to the /orgs/{org}/settings/billing API.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ORG: &str = "org";
+
+async fn setup_api(api_path: String, body: serde_json::Value) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(&api_path))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, &format!("GET on {api_path} was not received")).await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_get_actions_usage() {
+    let mock_server = setup_api(
+        format!("/orgs/{ORG}/settings/billing/actions"),
+        json!({
+            "total_minutes_used": 305,
+            "total_paid_minutes_used": 0,
+            "included_minutes": 3000,
+            "minutes_used_breakdown": { "UBUNTU": 205, "MACOS": 10, "WINDOWS": 90 }
+        }),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.orgs(ORG).billing().actions_usage().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let usage = result.unwrap();
+    assert_eq!(usage.total_minutes_used, 305);
+    assert_eq!(usage.included_minutes, 3000);
+    assert_eq!(usage.minutes_used_breakdown["UBUNTU"], 205);
+}
+
+#[tokio::test]
+async fn should_get_packages_usage() {
+    let mock_server = setup_api(
+        format!("/orgs/{ORG}/settings/billing/packages"),
+        json!({
+            "total_gigabytes_bandwidth_used": 50,
+            "total_paid_gigabytes_bandwidth_used": 40,
+            "included_gigabytes_bandwidth": 10
+        }),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.orgs(ORG).billing().packages_usage().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().total_paid_gigabytes_bandwidth_used, 40);
+}
+
+#[tokio::test]
+async fn should_get_shared_storage_usage() {
+    let mock_server = setup_api(
+        format!("/orgs/{ORG}/settings/billing/shared-storage"),
+        json!({
+            "days_left_in_billing_cycle": 20,
+            "estimated_paid_storage_for_month": 15.25,
+            "estimated_storage_for_month": 40
+        }),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.orgs(ORG).billing().shared_storage_usage().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let usage = result.unwrap();
+    assert_eq!(usage.days_left_in_billing_cycle, 20);
+    assert_eq!(usage.estimated_paid_storage_for_month, 15.25);
+}
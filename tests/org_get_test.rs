@@ -0,0 +1,82 @@
+// Tests for calls to the /orgs/{org} API.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ORG: &str = "github";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_get_organization_with_plan_and_settings() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/orgs/{ORG}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "login": "github",
+            "id": 1,
+            "node_id": "MDEyOk9yZ2FuaXphdGlvbjE=",
+            "url": "https://api.github.com/orgs/github",
+            "repos_url": "https://api.github.com/orgs/github/repos",
+            "events_url": "https://api.github.com/orgs/github/events",
+            "hooks_url": "https://api.github.com/orgs/github/hooks",
+            "issues_url": "https://api.github.com/orgs/github/issues",
+            "members_url": "https://api.github.com/orgs/github/members{/member}",
+            "public_members_url": "https://api.github.com/orgs/github/public_members{/member}",
+            "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+            "description": "A great organization",
+            "name": "github",
+            "company": "GitHub",
+            "blog": "https://github.com/blog",
+            "location": "San Francisco",
+            "email": "octocat@github.com",
+            "public_repos": 2,
+            "created_at": "2008-01-14T04:33:35Z",
+            "type": "Organization",
+            "total_private_repos": 100,
+            "owned_private_repos": 100,
+            "plan": {
+                "name": "Medium",
+                "space": 400,
+                "private_repos": 20,
+                "filled_seats": 4,
+                "seats": 5
+            },
+            "default_repository_permission": "read",
+            "members_can_create_repositories": true,
+            "two_factor_requirement_enabled": true
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /orgs/github was not received").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.orgs(ORG).get().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let org = result.unwrap();
+    assert_eq!(org.login, "github");
+    assert_eq!(org.company.as_deref(), Some("GitHub"));
+    assert_eq!(org.public_repos, Some(2));
+    assert_eq!(org.total_private_repos, Some(100));
+    assert_eq!(org.default_repository_permission.as_deref(), Some("read"));
+    assert_eq!(org.members_can_create_repositories, Some(true));
+    assert!(org.created_at.is_some());
+    let plan = org.plan.unwrap();
+    assert_eq!(plan.name, "Medium");
+    assert_eq!(plan.filled_seats, Some(4));
+    assert_eq!(plan.seats, Some(5));
+    assert!(org.extra.is_empty());
+}
@@ -0,0 +1,73 @@
+// Tests for calls to the /orgs/{org}/issue-types API.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::IssueTypeId;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ORG: &str = "org";
+
+async fn setup_api(template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/orgs/{ORG}/issue-types")))
+        .respond_with(template)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /orgs/{ORG}/issue-types was not received"),
+    )
+    .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_list_issue_types() {
+    let template = ResponseTemplate::new(200).set_body_json(json!([
+        {
+            "id": 410,
+            "node_id": "IT_kwDNAd3NAZo",
+            "name": "Task",
+            "description": "A specific piece of work",
+            "color": "blue",
+            "is_enabled": true,
+            "created_at": "2024-12-11T14:39:09Z",
+            "updated_at": "2024-12-11T14:39:09Z"
+        },
+        {
+            "id": 411,
+            "node_id": "IT_kwDNAd3NAZs",
+            "name": "Bug",
+            "description": "An unexpected problem or behavior",
+            "color": "red",
+            "is_enabled": true,
+            "created_at": "2024-12-11T14:39:09Z",
+            "updated_at": "2024-12-11T14:39:09Z"
+        }
+    ]));
+    let mock_server = setup_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.orgs(ORG).list_issue_types().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let issue_types = result.unwrap();
+    assert_eq!(issue_types.len(), 2);
+    assert_eq!(issue_types[1].id, IssueTypeId(411));
+    assert_eq!(issue_types[1].name, "Bug");
+}
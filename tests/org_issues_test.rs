@@ -0,0 +1,54 @@
+// Tests for calls to the /orgs/{org}/issues API.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::params::{issues::IssueFilter, State};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ORG: &str = "org";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_list_org_issues() {
+    let event: serde_json::Value =
+        serde_json::from_str(include_str!("resources/issues_opened_webhook_event.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/orgs/{ORG}/issues")))
+        .and(query_param("filter", "all"))
+        .and(query_param("state", "open"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([event["issue"]])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /orgs/{ORG}/issues was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .orgs(ORG)
+        .list_issues()
+        .filter(IssueFilter::All)
+        .state(State::Open)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let issues = result.unwrap().items;
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].number, 1);
+}
@@ -5,7 +5,7 @@ use mock_error::setup_error_handler;
 use octocrab::{models::Author, Octocrab, Page};
 use serde::{Deserialize, Serialize};
 use wiremock::{
-    matchers::{method, path},
+    matchers::{method, path, query_param, query_param_is_missing},
     Mock, MockServer, ResponseTemplate,
 };
 
@@ -136,3 +136,86 @@ async fn org_check_membership_500() {
         result
     );
 }
+
+#[tokio::test]
+async fn should_filter_members_by_2fa_and_role() {
+    let org_member: Author =
+        serde_json::from_str(include_str!("resources/org_members.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/orgs/{ORG}/members")))
+        .and(query_param("filter", "2fa_disabled"))
+        .and(query_param("role", "admin"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(vec![org_member]))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /orgs/{ORG}/members was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .orgs(ORG)
+        .list_members()
+        .filter(octocrab::params::orgs::MemberFilter::TwoFaDisabled)
+        .role(octocrab::params::orgs::Role::Admin)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().items.len(), 1);
+}
+
+#[tokio::test]
+async fn should_list_all_members_without_2fa() {
+    let org_member: Author =
+        serde_json::from_str(include_str!("resources/org_members.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/orgs/{ORG}/members")))
+        .and(query_param("filter", "2fa_disabled"))
+        .and(query_param_is_missing("page"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header(
+                    "Link",
+                    format!(
+                        "<{}/orgs/{ORG}/members?filter=2fa_disabled&per_page=100&page=2>; rel=\"next\"",
+                        mock_server.uri()
+                    ),
+                )
+                .set_body_json(vec![org_member.clone()]),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/orgs/{ORG}/members")))
+        .and(query_param("filter", "2fa_disabled"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(vec![org_member.clone()]))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /orgs/{ORG}/members was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.orgs(ORG).members_without_2fa().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let members = result.unwrap();
+    assert_eq!(members.len(), 2);
+    assert_eq!(members[0].login, org_member.login);
+}
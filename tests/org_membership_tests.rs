@@ -0,0 +1,100 @@
+// Tests for calls to the /orgs/{org}/memberships/{username} API.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::{
+    models::orgs::{MembershipInvitation, OrgMembership},
+    params::orgs::Role,
+    Octocrab,
+};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ORG: &str = "org";
+const USERNAME: &str = "mona";
+
+async fn setup_membership_api(http_method: &str, template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method(http_method))
+        .and(path(format!("/orgs/{ORG}/memberships/{USERNAME}")))
+        .respond_with(template)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("{http_method} on /orgs/{ORG}/memberships/{USERNAME} was not received"),
+    )
+    .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_update_membership_role() {
+    let membership: OrgMembership =
+        serde_json::from_str(include_str!("resources/org_membership.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path(format!("/orgs/{ORG}/memberships/{USERNAME}")))
+        .and(body_json(json!({ "role": "admin" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&membership))
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .orgs(ORG)
+        .update_membership(USERNAME, Role::Admin)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let membership = result.unwrap();
+    assert_eq!(membership.role, "admin");
+    assert_eq!(membership.user.unwrap().login, USERNAME);
+}
+
+#[tokio::test]
+#[allow(deprecated)]
+async fn should_add_or_update_membership_with_put() {
+    let membership: MembershipInvitation =
+        serde_json::from_str(include_str!("resources/org_membership.json")).unwrap();
+    let mock_server =
+        setup_membership_api("PUT", ResponseTemplate::new(200).set_body_json(&membership)).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .orgs(ORG)
+        .add_or_update_membership(USERNAME, Some(Role::Member))
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_remove_membership() {
+    let mock_server = setup_membership_api("DELETE", ResponseTemplate::new(204)).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.orgs(ORG).remove_membership(USERNAME).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
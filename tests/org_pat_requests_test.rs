@@ -0,0 +1,163 @@
+// Tests for listing and reviewing fine-grained personal access token requests.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::orgs::pat_requests::PatRequestAction;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ORG: &str = "org";
+const REQUEST_ID: u64 = 25381;
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn owner() -> serde_json::Value {
+    json!({
+        "login": "octocat",
+        "id": 1,
+        "node_id": "MDQ6VXNlcjE=",
+        "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+        "gravatar_id": "",
+        "url": "https://api.github.com/users/octocat",
+        "html_url": "https://github.com/octocat",
+        "followers_url": "https://api.github.com/users/octocat/followers",
+        "following_url": "https://api.github.com/users/octocat/following{/other_user}",
+        "gists_url": "https://api.github.com/users/octocat/gists{/gist_id}",
+        "starred_url": "https://api.github.com/users/octocat/starred{/owner}{/repo}",
+        "subscriptions_url": "https://api.github.com/users/octocat/subscriptions",
+        "organizations_url": "https://api.github.com/users/octocat/orgs",
+        "repos_url": "https://api.github.com/users/octocat/repos",
+        "events_url": "https://api.github.com/users/octocat/events{/privacy}",
+        "received_events_url": "https://api.github.com/users/octocat/received_events",
+        "type": "User",
+        "site_admin": false
+    })
+}
+
+#[tokio::test]
+async fn should_list_pat_requests() {
+    let mock_server = MockServer::start().await;
+    let route = format!("/orgs/{ORG}/personal-access-token-requests");
+    Mock::given(method("GET"))
+        .and(path(&route))
+        .and(query_param("owner", "octocat"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([{
+            "id": REQUEST_ID,
+            "reason": "I need to track issues",
+            "owner": owner(),
+            "repository_selection": "subset",
+            "repositories_url": "https://api.github.com/organizations/652551/personal-access-token-requests/25381/repositories",
+            "permissions": {
+                "organization": { "members": "read" },
+                "repository": { "metadata": "read" }
+            },
+            "created_at": "2023-05-16T08:47:09.000-07:00",
+            "token_id": 98716,
+            "token_name": "Issue tracker",
+            "token_expired": false,
+            "token_expires_at": "2023-11-16T08:47:09.000-07:00",
+            "token_last_used_at": null
+        }])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, &format!("GET on {route} was not received")).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .orgs(ORG)
+        .pat_requests()
+        .list()
+        .owner("octocat")
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let requests = result.unwrap().items;
+    assert_eq!(requests.len(), 1);
+    assert_eq!(*requests[0].id, REQUEST_ID);
+    assert_eq!(requests[0].owner.login, "octocat");
+    assert_eq!(requests[0].permissions.repository["metadata"], "read");
+    assert!(requests[0].permissions.other.is_empty());
+}
+
+#[tokio::test]
+async fn should_review_pat_request() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/orgs/{ORG}/personal-access-token-requests/{REQUEST_ID}"
+        )))
+        .and(body_json(json!({
+            "action": "deny",
+            "reason": "Use a GitHub App instead"
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .orgs(ORG)
+        .pat_requests()
+        .review(
+            REQUEST_ID,
+            PatRequestAction::Deny,
+            Some("Use a GitHub App instead"),
+        )
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_list_repos_for_pat_request() {
+    let mock_server = MockServer::start().await;
+    let route = format!("/orgs/{ORG}/personal-access-token-requests/{REQUEST_ID}/repositories");
+    Mock::given(method("GET"))
+        .and(path(&route))
+        .and(query_param("per_page", "50"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([{
+            "id": 1296269,
+            "name": "Hello-World",
+            "full_name": "octocat/Hello-World",
+            "url": "https://api.github.com/repos/octocat/Hello-World"
+        }])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, &format!("GET on {route} was not received")).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .orgs(ORG)
+        .pat_requests()
+        .list_repos_for_request(REQUEST_ID)
+        .per_page(50)
+        .page(2u32)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let repos = result.unwrap().items;
+    assert_eq!(repos.len(), 1);
+    assert_eq!(repos[0].name, "Hello-World");
+}
@@ -0,0 +1,209 @@
+// Tests for calls to the /orgs/{org}/rulesets API.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::orgs::rulesets::{
+    OrgRulesetRequest, RepositoryNameCondition, RulesetConditions, RulesetEnforcement, RulesetRule,
+    RulesetTarget,
+};
+use octocrab::Octocrab;
+use serde_json::{json, Value};
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ORG: &str = "org";
+const RULESET_ID: u64 = 21;
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn ruleset() -> Value {
+    json!({
+        "id": RULESET_ID,
+        "name": "no force pushes",
+        "target": "branch",
+        "source_type": "Organization",
+        "source": ORG,
+        "enforcement": "active",
+        "bypass_actors": [
+            { "actor_id": 234, "actor_type": "Team", "bypass_mode": "always" }
+        ],
+        "conditions": {
+            "ref_name": { "include": ["~DEFAULT_BRANCH"], "exclude": [] },
+            "repository_name": { "include": ["service-*"], "exclude": [], "protected": true }
+        },
+        "rules": [
+            { "type": "non_fast_forward" },
+            { "type": "pull_request", "parameters": { "required_approving_review_count": 1 } }
+        ],
+        "node_id": "RRS_lACkVXNlcgQB",
+        "created_at": "2023-07-15T08:43:03Z",
+        "updated_at": "2023-08-23T16:29:47Z"
+    })
+}
+
+#[tokio::test]
+async fn should_list_rulesets() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/orgs/{ORG}/rulesets")))
+        .and(query_param("per_page", "50"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([ruleset()])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /orgs/{ORG}/rulesets was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .orgs(ORG)
+        .rulesets()
+        .list()
+        .per_page(50)
+        .page(2u32)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let rulesets = result.unwrap().items;
+    assert_eq!(rulesets.len(), 1);
+    let conditions = rulesets[0].conditions.as_ref().unwrap();
+    assert_eq!(
+        conditions.repository_name.as_ref().unwrap().include,
+        ["service-*"]
+    );
+    assert_eq!(
+        rulesets[0].rules.as_ref().unwrap()[1].r#type,
+        "pull_request"
+    );
+}
+
+#[tokio::test]
+async fn should_get_ruleset() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/orgs/{ORG}/rulesets/{RULESET_ID}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(ruleset()))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /orgs/{ORG}/rulesets/{RULESET_ID} was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.orgs(ORG).rulesets().get(RULESET_ID).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let ruleset = result.unwrap();
+    assert_eq!(ruleset.enforcement, RulesetEnforcement::Active);
+    assert_eq!(ruleset.target, Some(RulesetTarget::Branch));
+}
+
+#[tokio::test]
+async fn should_create_ruleset() {
+    let mut request = OrgRulesetRequest::new("no force pushes", RulesetEnforcement::Active);
+    request.target = Some(RulesetTarget::Branch);
+    request.conditions = Some(RulesetConditions {
+        repository_name: Some(RepositoryNameCondition {
+            include: vec!["service-*".to_string()],
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    request.rules = Some(vec![RulesetRule {
+        r#type: "non_fast_forward".to_string(),
+        parameters: None,
+    }]);
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/orgs/{ORG}/rulesets")))
+        .and(body_json(json!({
+            "name": "no force pushes",
+            "enforcement": "active",
+            "target": "branch",
+            "conditions": {
+                "repository_name": { "include": ["service-*"], "exclude": [] }
+            },
+            "rules": [{ "type": "non_fast_forward" }]
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(ruleset()))
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.orgs(ORG).rulesets().create(&request).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().id.into_inner(), RULESET_ID);
+}
+
+#[tokio::test]
+async fn should_update_ruleset() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path(format!("/orgs/{ORG}/rulesets/{RULESET_ID}")))
+        .and(body_json(json!({
+            "name": "no force pushes",
+            "enforcement": "disabled"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(ruleset()))
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .orgs(ORG)
+        .rulesets()
+        .update(
+            RULESET_ID,
+            &OrgRulesetRequest::new("no force pushes", RulesetEnforcement::Disabled),
+        )
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_delete_ruleset() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!("/orgs/{ORG}/rulesets/{RULESET_ID}")))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.orgs(ORG).rulesets().delete(RULESET_ID).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
@@ -0,0 +1,178 @@
+// Tests for getting, deleting and restoring package versions.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::packages::PackageType;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ORG: &str = "org";
+const USER: &str = "user";
+const PACKAGE: &str = "app";
+const VERSION_ID: u64 = 836;
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_get_org_package_version() {
+    let mock_server = MockServer::start().await;
+    let route = format!("/orgs/{ORG}/packages/container/{PACKAGE}/versions/{VERSION_ID}");
+    Mock::given(method("GET"))
+        .and(path(&route))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": VERSION_ID,
+            "name": "sha256:b3d3e366b55f9a54599220198b3db5da8f53592acbbb7dc7e4e9878762fc5344",
+            "url": "https://api.github.com/orgs/org/packages/container/app/versions/836",
+            "package_html_url": "https://github.com/orgs/org/packages/container/package/app",
+            "created_at": "2020-05-19T22:19:11Z",
+            "updated_at": "2020-05-19T22:19:11Z",
+            "html_url": "https://github.com/orgs/org/packages/container/app/836",
+            "metadata": {
+                "package_type": "container",
+                "container": {
+                    "tags": ["latest"]
+                }
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, &format!("GET on {route} was not received")).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .orgs(ORG)
+        .packages()
+        .get_package_version(PackageType::Container, PACKAGE, VERSION_ID.into())
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let version = result.unwrap();
+    assert_eq!(version.id, VERSION_ID.into());
+    let metadata = version.metadata.unwrap();
+    assert_eq!(metadata.package_type, PackageType::Container);
+    assert_eq!(metadata.container.unwrap().tags, ["latest"]);
+}
+
+#[tokio::test]
+async fn should_delete_org_package_version() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!(
+            "/orgs/{ORG}/packages/npm/{PACKAGE}/versions/{VERSION_ID}"
+        )))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .orgs(ORG)
+        .packages()
+        .delete_package_version(PackageType::Npm, PACKAGE, VERSION_ID.into())
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_restore_user_package_version() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/users/{USER}/packages/npm/{PACKAGE}/versions/{VERSION_ID}/restore"
+        )))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .users(USER)
+        .packages()
+        .restore_package_version(PackageType::Npm, PACKAGE, VERSION_ID.into())
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_encode_package_name() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!(
+            "/orgs/{ORG}/packages/container/team%2Fapp/versions/{VERSION_ID}"
+        )))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .orgs(ORG)
+        .packages()
+        .delete_package_version(PackageType::Container, "team/app", VERSION_ID.into())
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_look_up_login_of_user_by_id() {
+    let user_id: u64 = 583231;
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/user/{user_id}")))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_raw(include_str!("resources/user_data.json"), "application/json"),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/users/octocat/packages/npm/{PACKAGE}/versions/{VERSION_ID}/restore"
+        )))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .users_by_id(user_id)
+        .packages()
+        .restore_package_version(PackageType::Npm, PACKAGE, VERSION_ID.into())
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
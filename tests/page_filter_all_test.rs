@@ -0,0 +1,45 @@
+// Tests for filtering every page of a paginated response.
+use octocrab::{Octocrab, Page};
+use wiremock::{
+    matchers::{method, path, query_param, query_param_is_missing},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_filter_items_across_pages() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/numbers"))
+        .and(query_param_is_missing("page"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header(
+                    "Link",
+                    format!("<{}/numbers?page=2>; rel=\"next\"", mock_server.uri()),
+                )
+                .set_body_json([1, 2, 3, 4]),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/numbers"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json([5, 6, 7, 8]))
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let first_page: Page<u32> = client.get("/numbers", None::<&()>).await.unwrap();
+    let result = first_page.filter_all(&client, |n| n % 2 == 0).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap(), vec![2, 4, 6, 8]);
+}
@@ -0,0 +1,55 @@
+// Tests for resuming pagination from a previously saved page URL.
+use octocrab::{Octocrab, Page};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_resume_pagination_from_saved_url() {
+    let mock_server = MockServer::start().await;
+    let uri = mock_server.uri();
+    Mock::given(method("GET"))
+        .and(path("/numbers"))
+        .and(query_param("page", "2"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header(
+                    "Link",
+                    format!(
+                        "<{uri}/numbers?page=3>; rel=\"next\", <{uri}/numbers?page=1>; rel=\"prev\""
+                    ),
+                )
+                .set_body_json(json!([3, 4])),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/numbers"))
+        .and(query_param("page", "3"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([5])))
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&uri);
+    let saved: http::Uri = format!("{uri}/numbers?page=2").parse().unwrap();
+
+    let result = client.page_from_url::<u32>(saved).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let page: Page<u32> = result.unwrap();
+    assert_eq!(page.items, vec![3, 4]);
+    assert_eq!(
+        page.prev,
+        Some(format!("{uri}/numbers?page=1").parse().unwrap())
+    );
+    assert_eq!(client.all_pages(page).await.unwrap(), vec![3, 4, 5]);
+}
@@ -0,0 +1,80 @@
+// Tests for configuring the default client's connection pool.
+#![cfg(feature = "default-client")]
+
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::time::Duration;
+
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test]
+async fn should_send_requests_with_pool_limits() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/zen"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!("Keep it logically awesome.")))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .set_pool_max_idle_per_host(1)
+        .set_pool_idle_timeout(Duration::from_millis(10))
+        .build()
+        .unwrap();
+
+    for _ in 0..2 {
+        let result = client.get::<String, _, ()>("/zen", None).await;
+        assert!(
+            result.is_ok(),
+            "expected successful result, got error: {:#?}",
+            result
+        );
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+}
+
+#[tokio::test]
+async fn should_close_connections_idle_for_longer_than_timeout() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let uri = format!("http://{}", listener.local_addr().unwrap());
+    // Answers one request, then waits for the client to close the connection.
+    let server = tokio::task::spawn_blocking(move || {
+        let (mut socket, _) = listener.accept().unwrap();
+        let mut buf = [0; 4096];
+        let _ = socket.read(&mut buf).unwrap();
+        socket
+            .write_all(b"HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 2\r\n\r\n\"\"")
+            .unwrap();
+        socket
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+        socket.read(&mut buf)
+    });
+    let client = Octocrab::builder()
+        .base_uri(uri)
+        .unwrap()
+        .set_pool_idle_timeout(Duration::from_millis(100))
+        .build()
+        .unwrap();
+
+    let result = client.get::<String, _, ()>("/zen", None).await;
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+
+    let read = server.await.unwrap();
+    assert!(
+        matches!(read, Ok(0)),
+        "expected the idle connection to be closed, got: {:#?}",
+        read
+    );
+}
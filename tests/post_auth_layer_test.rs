@@ -0,0 +1,64 @@
+// Tests for layers inserted with `OctocrabBuilder::with_post_auth_layer`.
+#![cfg(feature = "default-client")]
+
+use http::{header::AUTHORIZATION, HeaderValue, Request};
+use octocrab::{OctoBody, Octocrab};
+use serde_json::json;
+use tower::util::MapRequestLayer;
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+/// Signs a request with its absolute URI and `Authorization` header, which
+/// are only both present once the request is about to be dispatched.
+fn sign(mut request: Request<OctoBody>) -> Request<OctoBody> {
+    let authorization = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("unauthenticated")
+        .to_owned();
+    let signature = format!(
+        "{} {} {}",
+        request.method(),
+        request.uri().path(),
+        authorization
+    );
+    let absolute = request.uri().scheme().is_some();
+    let headers = request.headers_mut();
+    headers.insert("x-signature", HeaderValue::from_str(&signature).unwrap());
+    headers.insert(
+        "x-absolute-uri",
+        HeaderValue::from_static(if absolute { "true" } else { "false" }),
+    );
+    request
+}
+
+#[tokio::test]
+async fn should_apply_layer_after_auth() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/zen"))
+        .and(header("x-signature", "GET /zen Bearer secret-token"))
+        .and(header("x-absolute-uri", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!("Keep it logically awesome.")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .personal_token("secret-token".to_string())
+        .with_post_auth_layer(MapRequestLayer::new(sign))
+        .build()
+        .unwrap();
+
+    let result = client.get::<String, _, ()>("/zen", None).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
@@ -0,0 +1,87 @@
+// Tests for operating on pull requests through the issues API.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::Label;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "octocat";
+const REPO: &str = "Hello-World";
+const PULL_NUMBER: u64 = 101;
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_add_labels_to_pull_request_via_issues_api() {
+    let labels: Vec<Label> =
+        serde_json::from_str(include_str!("resources/issues_remove_label.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/issues/{PULL_NUMBER}/labels"
+        )))
+        .and(body_json(json!({ "labels": ["bug"] })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&labels))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("POST on /repos/{OWNER}/{REPO}/issues/{PULL_NUMBER}/labels was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .as_issue(PULL_NUMBER)
+        .add_labels(&["bug".to_string()])
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap(), labels);
+}
+
+#[tokio::test]
+async fn should_list_pull_request_labels_via_issues_api() {
+    let labels: Vec<Label> =
+        serde_json::from_str(include_str!("resources/issues_remove_label.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/issues/{PULL_NUMBER}/labels"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&labels))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/issues/{PULL_NUMBER}/labels was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .as_issue(PULL_NUMBER)
+        .list_labels()
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().items, labels);
+}
@@ -0,0 +1,96 @@
+// Tests for enabling and disabling auto-merge through the GraphQL API.
+use octocrab::{params::pulls::MergeMethod, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const PR_NODE_ID: &str = "PR_kwDOAHz1OX4uYAah";
+
+async fn setup_graphql_api(variables: serde_json::Value, template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_partial_json(json!({ "variables": variables })))
+        .respond_with(template)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_enable_auto_merge() {
+    let template = ResponseTemplate::new(200).set_body_json(json!({
+        "data": { "enablePullRequestAutoMerge": { "clientMutationId": null } }
+    }));
+    let mock_server = setup_graphql_api(
+        json!({ "pullRequestId": PR_NODE_ID, "mergeMethod": "SQUASH" }),
+        template,
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .enable_auto_merge(PR_NODE_ID, MergeMethod::Squash)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_disable_auto_merge() {
+    let template = ResponseTemplate::new(200).set_body_json(json!({
+        "data": { "disablePullRequestAutoMerge": { "clientMutationId": null } }
+    }));
+    let mock_server = setup_graphql_api(json!({ "pullRequestId": PR_NODE_ID }), template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .disable_auto_merge(PR_NODE_ID)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_fail_when_auto_merge_is_not_allowed() {
+    let template = ResponseTemplate::new(200).set_body_json(json!({
+        "data": { "enablePullRequestAutoMerge": null },
+        "errors": [{
+            "type": "UNPROCESSABLE",
+            "message": "Pull request Auto merge is not allowed for this repository"
+        }]
+    }));
+    let mock_server = setup_graphql_api(json!({ "pullRequestId": PR_NODE_ID }), template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .enable_auto_merge(PR_NODE_ID, MergeMethod::Merge)
+        .await;
+
+    assert!(
+        result.is_err(),
+        "expected error result, got success somehow: {:#?}",
+        result
+    );
+}
@@ -0,0 +1,103 @@
+// Tests for polling the mergeability of a pull request.
+#![cfg(feature = "tokio")]
+
+mod mock_error;
+
+use std::time::Duration;
+
+use mock_error::setup_error_handler;
+use octocrab::models::pulls::MergeableState;
+use octocrab::Octocrab;
+use serde_json::{json, Value};
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "owner";
+const REPO: &str = "repo";
+const PULL_NUMBER: u64 = 2;
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn pull_request(mergeable: Option<bool>, mergeable_state: &str) -> Value {
+    let event: Value = serde_json::from_str(include_str!(
+        "resources/pull_request_opened_webhook_event.json"
+    ))
+    .unwrap();
+    let mut pull_request = event["pull_request"].clone();
+    pull_request["mergeable"] = json!(mergeable);
+    pull_request["mergeable_state"] = json!(mergeable_state);
+    pull_request
+}
+
+async fn setup_api(responses: Vec<Value>) -> MockServer {
+    let mock_server = MockServer::start().await;
+    let route = format!("/repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}");
+    for body in responses {
+        Mock::given(method("GET"))
+            .and(path(&route))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+    setup_error_handler(&mock_server, &format!("GET on {route} was not received")).await;
+    mock_server
+}
+
+#[tokio::test]
+async fn should_poll_until_mergeable_is_known() {
+    let mock_server = setup_api(vec![
+        pull_request(None, "unknown"),
+        pull_request(Some(true), "clean"),
+    ])
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .mergeable(PULL_NUMBER)
+        .interval(Duration::from_millis(10))
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let state = result.unwrap();
+    assert_eq!(state.mergeable, Some(true));
+    assert_eq!(state.mergeable_state, Some(MergeableState::Clean));
+}
+
+#[tokio::test]
+async fn should_stop_after_max_attempts() {
+    let mock_server = setup_api(vec![
+        pull_request(None, "unknown"),
+        pull_request(None, "unknown"),
+    ])
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .mergeable(PULL_NUMBER)
+        .max_attempts(2)
+        .interval(Duration::from_millis(10))
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let state = result.unwrap();
+    assert_eq!(state.mergeable, None);
+    assert_eq!(state.mergeable_state, Some(MergeableState::Unknown));
+}
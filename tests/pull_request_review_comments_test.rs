@@ -0,0 +1,65 @@
+// Tests for creating review comments on the diff of a pull request.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::pulls::Side;
+use octocrab::Octocrab;
+use serde_json::{json, Value};
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "XAMPPRocky";
+const REPO: &str = "octocrab";
+const PULL_NUMBER: u64 = 42;
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn review_comment() -> Value {
+    serde_json::from_str(include_str!("resources/pull_request_review_comment.json")).unwrap()
+}
+
+#[tokio::test]
+async fn should_create_review_comment() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}/comments"
+        )))
+        .and(body_json(json!({
+            "body": "Great stuff!",
+            "commit_id": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+            "path": "file1.txt",
+            "line": 2,
+            "side": "RIGHT"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(review_comment()))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("POST on /repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}/comments was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .create_review_comment(PULL_NUMBER)
+        .body("Great stuff!")
+        .commit_id("6dcb09b5b57875f334f61aebed695e2e4193db5e")
+        .path("file1.txt")
+        .line(2u64)
+        .side(Side::Right)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
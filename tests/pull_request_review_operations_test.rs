@@ -1,4 +1,4 @@
-use wiremock::matchers::{method, path};
+use wiremock::matchers::{body_partial_json, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 use octocrab::models::pulls::{Review, ReviewAction, ReviewComment};
@@ -138,3 +138,38 @@ async fn should_work_with_specific_review() {
         .await;
     assert_eq!(result.unwrap(), pr_comment_response);
 }
+
+#[tokio::test]
+async fn should_dismiss_review() {
+    let review_response: Review =
+        serde_json::from_str(include_str!("resources/get_pull_request_review.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}/reviews/{REVIEW_ID}/dismissals"
+        )))
+        .and(body_partial_json(
+            serde_json::json!({ "message": "stale review" }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&review_response))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("PUT on /repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}/reviews/{REVIEW_ID}/dismissals was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .dismiss_review(PULL_NUMBER, REVIEW_ID, "stale review")
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap(), review_response);
+}
@@ -0,0 +1,165 @@
+// Tests for suggesting pull request reviewers from CODEOWNERS.
+mod mock_error;
+
+use base64::Engine;
+use mock_error::setup_error_handler;
+use octocrab::Octocrab;
+use serde_json::{json, Value};
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "octo-org";
+const REPO: &str = "octo-repo";
+const PULL_NUMBER: u64 = 101;
+
+const CODEOWNERS: &str = "\
+*       @global-owner
+*.rs    @rust-owner
+/docs/  @docs-team @rust-owner
+";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn pull_request() -> Value {
+    let event: Value = serde_json::from_str(include_str!(
+        "resources/pull_request_opened_webhook_event.json"
+    ))
+    .unwrap();
+    event["pull_request"].clone()
+}
+
+fn codeowners_content(location: &str) -> Value {
+    json!({
+        "type": "file",
+        "encoding": "base64",
+        "size": CODEOWNERS.len(),
+        "name": "CODEOWNERS",
+        "path": location,
+        "content": base64::prelude::BASE64_STANDARD.encode(CODEOWNERS),
+        "sha": "3d21ec53a331a6f037a91c368710b99387d012c1",
+        "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/contents/{location}"),
+        "git_url": null,
+        "html_url": null,
+        "download_url": null,
+        "_links": {
+            "git": null,
+            "html": null,
+            "self": format!("https://api.github.com/repos/{OWNER}/{REPO}/contents/{location}")
+        }
+    })
+}
+
+fn diff_entry(filename: &str) -> Value {
+    json!({
+        "sha": "bbcd538c8e72b8c175046e27cc8f907076331401",
+        "filename": filename,
+        "status": "modified",
+        "additions": 1,
+        "deletions": 1,
+        "changes": 2,
+        "blob_url": null,
+        "raw_url": null,
+        "contents_url": format!("https://api.github.com/repos/{OWNER}/{REPO}/contents/{filename}")
+    })
+}
+
+async fn setup_api(codeowners_location: Option<&str>) -> MockServer {
+    let mock_server = MockServer::start().await;
+    let pull_request = pull_request();
+    let base_sha = pull_request["base"]["sha"].as_str().unwrap().to_string();
+
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&pull_request))
+        .mount(&mock_server)
+        .await;
+    if let Some(location) = codeowners_location {
+        Mock::given(method("GET"))
+            .and(path(format!("/repos/{OWNER}/{REPO}/contents/{location}")))
+            .and(query_param("ref", base_sha.as_str()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(codeowners_content(location)))
+            .mount(&mock_server)
+            .await;
+    }
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}/files"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            diff_entry("src/lib.rs"),
+            diff_entry("docs/guide.md"),
+            diff_entry("README.md")
+        ])))
+        .mount(&mock_server)
+        .await;
+    mock_server
+}
+
+async fn not_found(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "message": "Not Found",
+            "documentation_url": "https://docs.github.com/rest"
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn should_suggest_owners_of_changed_files() {
+    let mock_server = setup_api(Some("CODEOWNERS")).await;
+    not_found(&mock_server).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .suggested_reviewers(PULL_NUMBER)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(
+        result.unwrap(),
+        vec!["@rust-owner", "@docs-team", "@global-owner"]
+    );
+}
+
+#[tokio::test]
+async fn should_suggest_nobody_without_codeowners() {
+    let mock_server = setup_api(None).await;
+    not_found(&mock_server).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .suggested_reviewers(PULL_NUMBER)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert!(result.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn should_fail_when_pull_request_is_missing() {
+    let mock_server = MockServer::start().await;
+    setup_error_handler(&mock_server, "pull request lookup was not expected").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .suggested_reviewers(PULL_NUMBER)
+        .await;
+
+    assert!(result.is_err(), "expected an error, got: {:#?}", result);
+}
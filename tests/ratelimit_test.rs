@@ -0,0 +1,81 @@
+// Tests for calls to the /rate_limit API.
+mod mock_error;
+
+use std::time::Duration;
+
+use mock_error::setup_error_handler;
+use octocrab::models::RateLimitResource;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn rate(limit: usize, remaining: usize) -> serde_json::Value {
+    json!({ "limit": limit, "used": limit - remaining, "remaining": remaining, "reset": 1691591363 })
+}
+
+async fn setup_ratelimit_api(expected_calls: u64) -> MockServer {
+    let mock_server = MockServer::start().await;
+    let body = json!({
+        "resources": {
+            "core": rate(5000, 4999),
+            "search": rate(30, 18),
+            "graphql": rate(5000, 4993)
+        },
+        "rate": rate(5000, 4999)
+    });
+
+    Mock::given(method("GET"))
+        .and(path("/rate_limit"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .expect(expected_calls)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /rate_limit was not received").await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_return_rate_for_resource() {
+    let mock_server = setup_ratelimit_api(2).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.ratelimit().resource(RateLimitResource::Search).await;
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let search = result.unwrap().expect("search rate should be present");
+    assert_eq!(search.limit, 30);
+    assert_eq!(search.remaining, 18);
+
+    let scim = client.ratelimit().resource(RateLimitResource::Scim).await;
+    assert!(scim.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn should_reuse_cached_rate_limit() {
+    let mock_server = setup_ratelimit_api(1).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    for _ in 0..3 {
+        let result = client
+            .ratelimit()
+            .cache_for(Duration::from_secs(60))
+            .get()
+            .await;
+        assert!(
+            result.is_ok(),
+            "expected successful result, got error: {:#?}",
+            result
+        );
+        assert_eq!(result.unwrap().resources.core.remaining, 4999);
+    }
+}
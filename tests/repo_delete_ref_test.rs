@@ -1,7 +1,8 @@
 mod mock_error;
 
 use mock_error::setup_error_handler;
-use octocrab::{params::repos::Reference, Octocrab};
+use octocrab::{params::repos::Reference, Error, Octocrab};
+use serde_json::json;
 use wiremock::{
     matchers::{method, path},
     Mock, MockServer, ResponseTemplate,
@@ -54,3 +55,30 @@ async fn should_delete_reference() {
         result
     );
 }
+
+#[tokio::test]
+async fn should_explain_unprocessable_reference() {
+    let template = ResponseTemplate::new(422).set_body_json(json!({
+        "message": "Reference does not exist",
+        "documentation_url": "https://docs.github.com/rest/git/refs#delete-a-reference"
+    }));
+    let mock_server = setup_delete_ref_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let repos = client.repos(OWNER.to_owned(), REPO.to_owned());
+
+    let result = repos
+        .delete_ref(&Reference::Branch(BRANCH.to_owned()))
+        .await;
+
+    match result.unwrap_err() {
+        Error::Reference {
+            reference, source, ..
+        } => {
+            assert_eq!(reference, "refs/heads/foo");
+            assert_eq!(source.status_code, 422);
+            assert_eq!(source.message, "Reference does not exist");
+        }
+        other => panic!("Unexpected error: {:?}", other),
+    }
+}
@@ -0,0 +1,101 @@
+// Tests for calls to the /repos/{owner}/{repo}/keys API.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::{repos::DeployKey, DeployKeyId};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "owner";
+const REPO: &str = "repo";
+
+async fn setup_api(http_method: &str, api_path: String, template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method(http_method))
+        .and(path(&api_path))
+        .respond_with(template)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("{http_method} on {api_path} was not received"),
+    )
+    .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_list_deploy_keys() {
+    let keys: Vec<DeployKey> =
+        serde_json::from_str(include_str!("resources/repo_deploy_keys.json")).unwrap();
+    let template = ResponseTemplate::new(200).set_body_json(&keys);
+    let mock_server = setup_api("GET", format!("/repos/{OWNER}/{REPO}/keys"), template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).deploy_keys().list().send().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let page = result.unwrap();
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.items[0].id, DeployKeyId(1));
+    assert!(page.items[0].read_only);
+}
+
+#[tokio::test]
+async fn should_create_deploy_key() {
+    let keys: Vec<DeployKey> =
+        serde_json::from_str(include_str!("resources/repo_deploy_keys.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/keys")))
+        .and(body_json(json!({
+            "title": "octocat@octomac",
+            "key": "ssh-rsa AAA...",
+            "read_only": true,
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(&keys[0]))
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .deploy_keys()
+        .create("octocat@octomac", "ssh-rsa AAA...", true)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().title, "octocat@octomac");
+}
+
+#[tokio::test]
+async fn should_delete_deploy_key() {
+    let template = ResponseTemplate::new(204);
+    let mock_server = setup_api("DELETE", format!("/repos/{OWNER}/{REPO}/keys/1"), template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).deploy_keys().delete(1u64).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
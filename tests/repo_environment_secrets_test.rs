@@ -0,0 +1,182 @@
+// Tests for calls to the /repos/{owner}/{repo}/environments/{environment}/secrets API.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::{
+    models::{
+        repos::secrets::{CreateRepositorySecretResponse, RepositorySecrets},
+        PublicKey,
+    },
+    Octocrab,
+};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "owner";
+const REPO: &str = "repo";
+const ENVIRONMENT: &str = "production";
+
+async fn setup_api(
+    http_method: &str,
+    template: ResponseTemplate,
+    secrets_path: &str,
+) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method(http_method))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/environments/{ENVIRONMENT}/secrets{secrets_path}"
+        )))
+        .respond_with(template)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!(
+            "{http_method} on /repos/{OWNER}/{REPO}/environments/{ENVIRONMENT}/secrets{secrets_path} was not received"
+        ),
+    )
+    .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_list_environment_secrets() {
+    let secrets: RepositorySecrets =
+        serde_json::from_str(include_str!("resources/repo_secrets.json")).unwrap();
+    let template = ResponseTemplate::new(200).set_body_json(&secrets);
+    let mock_server = setup_api("GET", template, "").await;
+    let result = setup_octocrab(&mock_server.uri())
+        .repos(OWNER, REPO)
+        .environments()
+        .secrets(ENVIRONMENT)
+        .list()
+        .await;
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap(), secrets);
+}
+
+#[tokio::test]
+async fn should_return_environment_public_key() {
+    let public_key: PublicKey =
+        serde_json::from_str(include_str!("resources/repo_public_key.json")).unwrap();
+    let template = ResponseTemplate::new(200).set_body_json(&public_key);
+    let mock_server = setup_api("GET", template, "/public-key").await;
+    let result = setup_octocrab(&mock_server.uri())
+        .repos(OWNER, REPO)
+        .environments()
+        .secrets(ENVIRONMENT)
+        .get_public_key()
+        .await;
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().key_id, "012345678912345678");
+}
+
+#[tokio::test]
+async fn should_create_environment_secret() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/environments/{ENVIRONMENT}/secrets/GH_TOKEN"
+        )))
+        .and(body_json(json!({
+            "encrypted_value": "some-b64-string",
+            "key_id": "123456"
+        })))
+        .respond_with(ResponseTemplate::new(201))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "PUT on environment secret was not received").await;
+    let result = setup_octocrab(&mock_server.uri())
+        .repos(OWNER, REPO)
+        .environments()
+        .secrets(ENVIRONMENT)
+        .create_or_update("GH_TOKEN", "some-b64-string", "123456")
+        .await;
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap(), CreateRepositorySecretResponse::Created);
+}
+
+#[tokio::test]
+async fn should_update_environment_secret() {
+    let template = ResponseTemplate::new(204);
+    let mock_server = setup_api("PUT", template, "/GH_TOKEN").await;
+    let result = setup_octocrab(&mock_server.uri())
+        .repos(OWNER, REPO)
+        .environments()
+        .secrets(ENVIRONMENT)
+        .create_or_update("GH_TOKEN", "some-b64-string", "123456")
+        .await;
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap(), CreateRepositorySecretResponse::Updated);
+}
+
+#[tokio::test]
+async fn should_delete_environment_secret() {
+    let template = ResponseTemplate::new(204);
+    let mock_server = setup_api("DELETE", template, "/GH_TOKEN").await;
+    let result = setup_octocrab(&mock_server.uri())
+        .repos(OWNER, REPO)
+        .environments()
+        .secrets(ENVIRONMENT)
+        .delete("GH_TOKEN")
+        .await;
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_encode_environment_name() {
+    let secrets: RepositorySecrets =
+        serde_json::from_str(include_str!("resources/repo_secrets.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/environments/staging%2Feu%20west/secrets"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&secrets))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /repos/owner/repo/environments/staging%2Feu%20west/secrets was not received",
+    )
+    .await;
+    let result = setup_octocrab(&mock_server.uri())
+        .repos(OWNER, REPO)
+        .environments()
+        .secrets("staging/eu west")
+        .list()
+        .await;
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
@@ -0,0 +1,196 @@
+// Tests for calls to the /repos/{owner}/{repo}/hooks API.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::hooks::{ContentType, Hook};
+use octocrab::models::webhook_events::WebhookEventType;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "owner";
+const REPO: &str = "repo";
+const HOOK_ID: u64 = 12345678;
+
+async fn setup_api(http_method: &str, api_path: String, template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method(http_method))
+        .and(path(&api_path))
+        .respond_with(template)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("{http_method} on {api_path} was not received"),
+    )
+    .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_list_hooks() {
+    let hook: Hook = serde_json::from_str(include_str!("resources/repo_hook.json")).unwrap();
+    let template = ResponseTemplate::new(200).set_body_json(vec![&hook]);
+    let mock_server = setup_api("GET", format!("/repos/{OWNER}/{REPO}/hooks"), template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).hooks().list().send().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let page = result.unwrap();
+    assert_eq!(page.items, vec![hook]);
+}
+
+#[tokio::test]
+async fn should_list_hooks_paginated() {
+    let hook: Hook = serde_json::from_str(include_str!("resources/repo_hook.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/hooks")))
+        .and(query_param("per_page", "50"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(vec![&hook]))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .hooks()
+        .list()
+        .per_page(50)
+        .page(2u32)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_create_hook() {
+    let hook: Hook = serde_json::from_str(include_str!("resources/repo_hook.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/hooks")))
+        .and(body_json(json!({
+            "name": "web",
+            "config": {
+                "url": "https://example.com/webhook",
+                "content_type": "json",
+                "secret": "shh",
+            },
+            "events": ["push", "pull_request"],
+            "active": true,
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(&hook))
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .hooks()
+        .create("https://example.com/webhook")
+        .content_type(ContentType::Json)
+        .secret("shh")
+        .events(vec![WebhookEventType::Push, WebhookEventType::PullRequest])
+        .active(true)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().id, HOOK_ID);
+}
+
+#[tokio::test]
+async fn should_update_hook_config_in_a_single_request() {
+    let hook: Hook = serde_json::from_str(include_str!("resources/repo_hook.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PATCH"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/hooks/{HOOK_ID}")))
+        .and(body_json(json!({
+            "config": { "url": "https://example.com/new-webhook" },
+            "add_events": ["push"]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&hook))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .hooks()
+        .update(HOOK_ID)
+        .url("https://example.com/new-webhook")
+        .add_events(vec![WebhookEventType::Push])
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_delete_hook() {
+    let template = ResponseTemplate::new(204);
+    let mock_server = setup_api(
+        "DELETE",
+        format!("/repos/{OWNER}/{REPO}/hooks/{HOOK_ID}"),
+        template,
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).hooks().delete(HOOK_ID).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_ping_hook() {
+    let template = ResponseTemplate::new(204);
+    let mock_server = setup_api(
+        "POST",
+        format!("/repos/{OWNER}/{REPO}/hooks/{HOOK_ID}/pings"),
+        template,
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).hooks().ping(HOOK_ID).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
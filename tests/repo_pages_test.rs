@@ -0,0 +1,164 @@
+// Tests for calls to the /repos/{owner}/{repo}/pages API.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "owner";
+const REPO: &str = "repo";
+
+async fn setup_api(http_method: &str, pages_path: &str, template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method(http_method))
+        .and(path(format!("/repos/{OWNER}/{REPO}/pages{pages_path}")))
+        .respond_with(template)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("{http_method} on /repos/{OWNER}/{REPO}/pages{pages_path} was not received"),
+    )
+    .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn page_build() -> serde_json::Value {
+    json!({
+        "url": "https://api.github.com/repos/owner/repo/pages/builds/5472601",
+        "status": "built",
+        "error": { "message": null },
+        "pusher": {
+            "login": "octocat",
+            "id": 1,
+            "node_id": "MDQ6VXNlcjE=",
+            "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+            "gravatar_id": "",
+            "url": "https://api.github.com/users/octocat",
+            "html_url": "https://github.com/octocat",
+            "followers_url": "https://api.github.com/users/octocat/followers",
+            "following_url": "https://api.github.com/users/octocat/following{/other_user}",
+            "gists_url": "https://api.github.com/users/octocat/gists{/gist_id}",
+            "starred_url": "https://api.github.com/users/octocat/starred{/owner}{/repo}",
+            "subscriptions_url": "https://api.github.com/users/octocat/subscriptions",
+            "organizations_url": "https://api.github.com/users/octocat/orgs",
+            "repos_url": "https://api.github.com/users/octocat/repos",
+            "events_url": "https://api.github.com/users/octocat/events{/privacy}",
+            "received_events_url": "https://api.github.com/users/octocat/received_events",
+            "type": "User",
+            "site_admin": false
+        },
+        "commit": "351391cdcb88ffae71ec3028c91f375a8036a26b",
+        "duration": 2104,
+        "created_at": "2014-02-10T19:00:49Z",
+        "updated_at": "2014-02-10T19:00:51Z"
+    })
+}
+
+#[tokio::test]
+async fn should_get_pages() {
+    let template = ResponseTemplate::new(200).set_body_json(json!({
+        "url": "https://api.github.com/repos/owner/repo/pages",
+        "status": "built",
+        "cname": "developer.octocat.com",
+        "custom_404": false,
+        "html_url": "https://developer.octocat.com",
+        "build_type": "legacy",
+        "source": { "branch": "main", "path": "/" },
+        "public": true,
+        "https_enforced": true
+    }));
+    let mock_server = setup_api("GET", "", template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).pages().get().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let pages = result.unwrap();
+    assert_eq!(pages.status.as_deref(), Some("built"));
+    assert_eq!(pages.cname.as_deref(), Some("developer.octocat.com"));
+    assert_eq!(pages.source.unwrap().branch, "main");
+}
+
+#[tokio::test]
+async fn should_list_builds() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/pages/builds")))
+        .and(query_param("per_page", "1"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([page_build()])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/pages/builds?per_page=1&page=2 was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .pages()
+        .list_builds()
+        .per_page(1)
+        .page(2u32)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let builds = result.unwrap().items;
+    assert_eq!(builds.len(), 1);
+    assert_eq!(builds[0].duration, 2104);
+}
+
+#[tokio::test]
+async fn should_get_latest_build() {
+    let template = ResponseTemplate::new(200).set_body_json(page_build());
+    let mock_server = setup_api("GET", "/builds/latest", template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).pages().latest_build().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().status, "built");
+}
+
+#[tokio::test]
+async fn should_request_build() {
+    let template = ResponseTemplate::new(201).set_body_json(json!({
+        "url": "https://api.github.com/repos/owner/repo/pages/builds/latest",
+        "status": "queued"
+    }));
+    let mock_server = setup_api("POST", "/builds", template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).pages().request_build().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().status, "queued");
+}
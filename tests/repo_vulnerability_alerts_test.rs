@@ -0,0 +1,141 @@
+// Tests for calls to the /repos/{owner}/{repo}/vulnerability-alerts and
+// /repos/{owner}/{repo}/automated-security-fixes APIs.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "owner";
+const REPO: &str = "repo";
+
+async fn setup_api(http_method: &str, endpoint: &str, template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method(http_method))
+        .and(path(format!("/repos/{OWNER}/{REPO}/{endpoint}")))
+        .respond_with(template)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("{http_method} on /repos/{OWNER}/{REPO}/{endpoint} was not received"),
+    )
+    .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_report_vulnerability_alerts_enabled() {
+    let mock_server = setup_api("GET", "vulnerability-alerts", ResponseTemplate::new(204)).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .vulnerability_alerts()
+        .is_enabled()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert!(result.unwrap());
+}
+
+#[tokio::test]
+async fn should_report_vulnerability_alerts_disabled() {
+    let template = ResponseTemplate::new(404).set_body_json(json!({
+        "message": "Not Found",
+        "documentation_url": "https://docs.github.com/rest"
+    }));
+    let mock_server = setup_api("GET", "vulnerability-alerts", template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .vulnerability_alerts()
+        .is_enabled()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert!(!result.unwrap());
+}
+
+#[tokio::test]
+async fn should_enable_vulnerability_alerts() {
+    let mock_server = setup_api("PUT", "vulnerability-alerts", ResponseTemplate::new(204)).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .vulnerability_alerts()
+        .enable()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_disable_automated_security_fixes() {
+    let mock_server = setup_api(
+        "DELETE",
+        "automated-security-fixes",
+        ResponseTemplate::new(204),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .automated_security_fixes()
+        .disable()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_get_automated_security_fixes() {
+    let template = ResponseTemplate::new(200).set_body_json(json!({
+        "enabled": true,
+        "paused": false
+    }));
+    let mock_server = setup_api("GET", "automated-security-fixes", template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .automated_security_fixes()
+        .get()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let settings = result.unwrap().unwrap();
+    assert!(settings.enabled);
+    assert!(!settings.paused);
+}
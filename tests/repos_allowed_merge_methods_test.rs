@@ -0,0 +1,59 @@
+// Tests for deriving allowed merge methods from GET /repos/{owner}/{repo}.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::Repository;
+use octocrab::params::pulls::MergeMethod;
+use octocrab::Octocrab;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "owner";
+const REPO: &str = "repo";
+
+async fn setup_api(template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}")))
+        .respond_with(template)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO} was not received"),
+    )
+    .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_return_allowed_merge_methods() {
+    let repositories: Vec<Repository> =
+        serde_json::from_str(include_str!("resources/user_repositories.json")).unwrap();
+    let mut repository = repositories.into_iter().next().unwrap();
+    repository.allow_merge_commit = Some(false);
+    repository.allow_squash_merge = Some(true);
+    repository.allow_rebase_merge = None;
+    let template = ResponseTemplate::new(200).set_body_json(&repository);
+    let mock_server = setup_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).allowed_merge_methods().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(
+        result.unwrap(),
+        vec![MergeMethod::Squash, MergeMethod::Rebase]
+    );
+}
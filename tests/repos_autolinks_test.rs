@@ -0,0 +1,122 @@
+// Tests for managing the autolinks of a repository.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::AutolinkId;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "owner";
+const REPO: &str = "repo";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn autolink() -> serde_json::Value {
+    json!({
+        "id": 1,
+        "key_prefix": "JIRA-",
+        "url_template": "https://jira.example.com/browse/JIRA-<num>",
+        "is_alphanumeric": false
+    })
+}
+
+#[tokio::test]
+async fn should_list_and_get_autolinks() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/autolinks")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([autolink()])))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/autolinks/1")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(autolink()))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/autolinks was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+    let repo = client.repos(OWNER, REPO);
+
+    let result = repo.autolinks().list().await;
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let autolinks = result.unwrap().items;
+    assert_eq!(autolinks.len(), 1);
+    assert_eq!(autolinks[0].key_prefix, "JIRA-");
+
+    let result = repo.autolinks().get(1u64).await;
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let autolink = result.unwrap();
+    assert_eq!(autolink.id, AutolinkId(1));
+    assert!(!autolink.is_alphanumeric);
+}
+
+#[tokio::test]
+async fn should_create_autolink() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/autolinks")))
+        .and(body_json(json!({
+            "key_prefix": "JIRA-",
+            "url_template": "https://jira.example.com/browse/JIRA-<num>",
+            "is_alphanumeric": false
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(autolink()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .autolinks()
+        .create("JIRA-", "https://jira.example.com/browse/JIRA-<num>", false)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(
+        result.unwrap().url_template,
+        "https://jira.example.com/browse/JIRA-<num>"
+    );
+}
+
+#[tokio::test]
+async fn should_delete_autolink() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/autolinks/1")))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).autolinks().delete(1u64).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
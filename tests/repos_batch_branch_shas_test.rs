@@ -0,0 +1,97 @@
+// Tests for fetching several branch heads with a single GraphQL query.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "owner";
+const REPO: &str = "repo";
+const MAIN_SHA: &str = "aa218f56b14c9653891f9e74264a383fa43fefbd";
+const RELEASE_SHA: &str = "6dcb09b5b57875f334f61aebed695e2e4193db5e";
+
+async fn setup_api(template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_partial_json(json!({
+            "variables": {
+                "owner": OWNER,
+                "name": REPO,
+                "b0": "refs/heads/main",
+                "b1": "refs/heads/release",
+                "b2": "refs/heads/missing",
+            }
+        })))
+        .respond_with(template)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "POST on /graphql was not received").await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_return_shas_of_existing_branches() {
+    let template = ResponseTemplate::new(200).set_body_json(json!({
+        "data": {
+            "repository": {
+                "b0": { "target": { "oid": MAIN_SHA } },
+                "b1": { "target": { "oid": RELEASE_SHA } },
+                "b2": null
+            }
+        }
+    }));
+    let mock_server = setup_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .batch_branch_shas(&["main", "release", "missing"])
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let shas = result.unwrap();
+    assert_eq!(shas.len(), 2);
+    assert_eq!(shas["main"], MAIN_SHA);
+    assert_eq!(shas["release"], RELEASE_SHA);
+}
+
+#[tokio::test]
+async fn should_fail_on_graphql_errors() {
+    let template = ResponseTemplate::new(200).set_body_json(json!({
+        "data": { "repository": null },
+        "errors": [
+            {
+                "type": "NOT_FOUND",
+                "path": ["repository"],
+                "message": "Could not resolve to a Repository with the name 'owner/repo'."
+            }
+        ]
+    }));
+    let mock_server = setup_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .batch_branch_shas(&["main", "release", "missing"])
+        .await;
+
+    assert!(
+        result.is_err(),
+        "expected error result, got success somehow: {:#?}",
+        result
+    );
+}
@@ -0,0 +1,79 @@
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const BRANCH: &str = "main";
+const SHA: &str = "aa218f56b14c9653891f9e74264a383fa43fefbd";
+
+async fn setup_get_ref_api(template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/git/ref/heads/{BRANCH}"
+        )))
+        .respond_with(template)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/git/ref/heads/{BRANCH} was not received"),
+    )
+    .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_return_branch_head_sha() {
+    let template = ResponseTemplate::new(200).set_body_json(json!({
+        "ref": format!("refs/heads/{BRANCH}"),
+        "node_id": "MDM6UmVmcmVmcy9oZWFkcy9mZWF0dXJlQQ==",
+        "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/git/refs/heads/{BRANCH}"),
+        "object": {
+            "type": "commit",
+            "sha": SHA,
+            "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/git/commits/{SHA}")
+        }
+    }));
+    let mock_server = setup_get_ref_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).branch_sha(BRANCH).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap(), SHA);
+}
+
+#[tokio::test]
+async fn should_fail_for_missing_branch() {
+    let template = ResponseTemplate::new(404).set_body_json(json!({
+        "message": "Not Found",
+        "documentation_url": "https://docs.github.com/rest/git/refs#get-a-reference"
+    }));
+    let mock_server = setup_get_ref_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).branch_sha(BRANCH).await;
+
+    assert!(
+        result.is_err(),
+        "expected error result, got success somehow: {:#?}",
+        result
+    );
+}
@@ -0,0 +1,64 @@
+// Tests for calls to the /repos/{owner}/{repo}/community/profile API.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::{models::RepositoryMetrics, Octocrab};
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "octocat";
+const REPO: &str = "Hello-World";
+
+async fn setup_api(template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/community/profile")))
+        .respond_with(template)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/community/profile was not received"),
+    )
+    .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_return_community_profile_metrics() {
+    let metrics: RepositoryMetrics =
+        serde_json::from_str(include_str!("resources/repos_community_profile.json")).unwrap();
+    let template = ResponseTemplate::new(200).set_body_json(&metrics);
+    let mock_server = setup_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .get_community_profile_metrics()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let RepositoryMetrics {
+        health_percentage,
+        files,
+        ..
+    } = result.unwrap();
+    assert_eq!(health_percentage, 100);
+    assert!(files["code_of_conduct"].is_some());
+    assert!(files["pull_request_template"].is_none());
+    assert_eq!(
+        files["license"].as_ref().unwrap().key.as_deref(),
+        Some("mit")
+    );
+}
@@ -0,0 +1,130 @@
+// Tests for listing the statuses of a deployment.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::repos::deployments::DeploymentState;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "owner";
+const REPO: &str = "repo";
+const DEPLOYMENT_ID: u64 = 42;
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn deployment_status(id: u64, state: &str) -> serde_json::Value {
+    json!({
+        "url": format!("https://api.github.com/repos/owner/repo/deployments/42/statuses/{id}"),
+        "id": id,
+        "node_id": "MDE2OkRlcGxveW1lbnRTdGF0dXMx",
+        "state": state,
+        "creator": null,
+        "description": "Deployment finished successfully.",
+        "environment": "production",
+        "target_url": "https://example.com/deployment/42/output",
+        "created_at": "2012-07-20T01:19:13Z",
+        "updated_at": "2012-07-20T01:19:13Z",
+        "deployment_url": "https://api.github.com/repos/owner/repo/deployments/42",
+        "repository_url": "https://api.github.com/repos/owner/repo",
+        "environment_url": "https://test-branch.lab.acme.com",
+        "log_url": "https://example.com/deployment/42/output"
+    })
+}
+
+#[tokio::test]
+async fn should_list_deployment_statuses() {
+    let mock_server = MockServer::start().await;
+    let route = format!("/repos/{OWNER}/{REPO}/deployments/{DEPLOYMENT_ID}/statuses");
+    Mock::given(method("GET"))
+        .and(path(&route))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            deployment_status(2, "in_progress"),
+            deployment_status(1, "queued"),
+        ])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, &format!("GET on {route} was not received")).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .deployments()
+        .list_statuses(DEPLOYMENT_ID)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let statuses = result.unwrap().items;
+    assert_eq!(statuses.len(), 2);
+    assert_eq!(statuses[0].state, DeploymentState::InProgress);
+    assert_eq!(statuses[1].state, DeploymentState::Queued);
+    assert_eq!(statuses[0].environment.as_deref(), Some("production"));
+}
+
+#[tokio::test]
+async fn should_get_latest_deployment_status() {
+    let mock_server = MockServer::start().await;
+    let route = format!("/repos/{OWNER}/{REPO}/deployments/{DEPLOYMENT_ID}/statuses");
+    Mock::given(method("GET"))
+        .and(path(&route))
+        .and(query_param("per_page", "1"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!([deployment_status(2, "success")])),
+        )
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, &format!("GET on {route} was not received")).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .deployments()
+        .latest_status(DEPLOYMENT_ID)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let status = result.unwrap().unwrap();
+    assert_eq!(status.state, DeploymentState::Success);
+    assert_eq!(
+        status.log_url.as_deref(),
+        Some("https://example.com/deployment/42/output")
+    );
+}
+
+#[tokio::test]
+async fn should_return_none_without_deployment_statuses() {
+    let mock_server = MockServer::start().await;
+    let route = format!("/repos/{OWNER}/{REPO}/deployments/{DEPLOYMENT_ID}/statuses");
+    Mock::given(method("GET"))
+        .and(path(&route))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, &format!("GET on {route} was not received")).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .deployments()
+        .latest_status(DEPLOYMENT_ID)
+        .await;
+
+    assert!(
+        matches!(result, Ok(None)),
+        "expected no status, got: {:#?}",
+        result
+    );
+}
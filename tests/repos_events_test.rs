@@ -64,6 +64,7 @@ async fn should_return_page_with_events_and_etag() {
         Etagged {
             etag: Some(etag),
             value: Some(mut page),
+            ..
         } => {
             assert_eq!(page.take_items(), page_response.items);
             assert_eq!(etag, EntityTag::strong(expected_etag.replace('\"', "")));
@@ -89,6 +90,7 @@ async fn should_return_no_page_with_events_and_etag_when_response_is_304() {
         Etagged {
             etag: Some(etag),
             value: None,
+            ..
         } => {
             assert_eq!(etag, EntityTag::strong(expected_etag.replace('\"', "")));
         }
@@ -115,6 +117,7 @@ async fn should_return_no_etag_if_response_contains_none() {
         Etagged {
             etag: None,
             value: Some(mut page),
+            ..
         } => {
             assert_eq!(page.take_items(), page_response.items);
         }
@@ -0,0 +1,84 @@
+// Tests for calls to the /repos/{owner}/{repo}/forks API.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::Repository;
+use octocrab::params::repos::forks::Sort;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "owner";
+const REPO: &str = "repo";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn repositories() -> Vec<Repository> {
+    serde_json::from_str(include_str!("resources/user_repositories.json")).unwrap()
+}
+
+#[tokio::test]
+async fn should_list_forks_sorted_by_stargazers() {
+    let forks = repositories();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/forks")))
+        .and(query_param("sort", "stargazers"))
+        .and(query_param("per_page", "50"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&forks))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/forks was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .list_forks()
+        .sort(Sort::Stargazers)
+        .per_page(50)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().items, forks);
+}
+
+#[tokio::test]
+async fn should_create_fork_in_organization() {
+    let fork = repositories().into_iter().next().unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/forks")))
+        .and(body_json(json!({ "organization": "octo-org" })))
+        .respond_with(ResponseTemplate::new(202).set_body_json(&fork))
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .create_fork()
+        .organization("octo-org")
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().id, fork.id);
+}
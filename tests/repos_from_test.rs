@@ -0,0 +1,61 @@
+// Tests for creating a `RepoHandler` from a `Repository` model.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::Repository;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_use_full_name_of_repository() {
+    let repositories: Vec<Repository> =
+        serde_json::from_str(include_str!("resources/user_repositories.json")).unwrap();
+    let repository = &repositories[0];
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/iamjpotts/actix-examples"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(repository))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /repos/iamjpotts/actix-examples was not received",
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos_from(repository).unwrap().get().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().id, repository.id);
+}
+
+#[tokio::test]
+async fn should_fail_without_owner_or_full_name() {
+    let repository: Repository = serde_json::from_value(json!({
+        "id": 1,
+        "name": "repo",
+        "url": "https://api.github.com/repos/owner/repo"
+    }))
+    .unwrap();
+    let client = setup_octocrab("http://localhost");
+
+    let result = client.repos_from(&repository);
+
+    assert!(
+        result.is_err(),
+        "expected error result, got success somehow"
+    );
+}
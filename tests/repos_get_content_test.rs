@@ -0,0 +1,126 @@
+// Tests for fetching repository contents, including symlinks and submodules.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::repos::ContentsItem;
+use octocrab::Octocrab;
+use serde_json::{json, Value};
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "owner";
+const REPO: &str = "repo";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+async fn setup_api(route: &str, body: Value) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(route))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, &format!("GET on {route} was not received")).await;
+    mock_server
+}
+
+fn entry(kind: &str, name: &str) -> Value {
+    let url = format!("https://api.github.com/repos/{OWNER}/{REPO}/contents/{name}");
+    json!({
+        "type": kind,
+        "size": 0,
+        "name": name,
+        "path": name,
+        "sha": "6ca3721222109997540bd6d9ccd396902e0ad2f9",
+        "url": url,
+        "git_url": null,
+        "html_url": null,
+        "download_url": null,
+        "_links": { "self": url, "git": null, "html": null }
+    })
+}
+
+#[tokio::test]
+async fn should_distinguish_content_items() {
+    let mut symlink = entry("symlink", "link");
+    symlink["target"] = json!("docs/README.md");
+    let mut file = entry("file", "README.md");
+    file["git_url"] = json!(format!(
+        "https://api.github.com/repos/{OWNER}/{REPO}/git/blobs/6ca3721222109997540bd6d9ccd396902e0ad2f9"
+    ));
+    // Listings report submodules as files whose `git_url` is a tree in the
+    // submodule's repository.
+    let mut submodule = entry("file", "vendor");
+    submodule["git_url"] = json!(
+        "https://api.github.com/repos/jquery/qunit/git/trees/6ca3721222109997540bd6d9ccd396902e0ad2f9"
+    );
+    let route = format!("/repos/{OWNER}/{REPO}/contents/");
+    let mock_server = setup_api(
+        &route,
+        json!([file, entry("dir", "src"), symlink, submodule,]),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).get_content().send().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let items: Vec<_> = result
+        .unwrap()
+        .take_items()
+        .iter()
+        .map(|content| content.item())
+        .collect();
+    assert_eq!(
+        items,
+        vec![
+            ContentsItem::File,
+            ContentsItem::Dir,
+            ContentsItem::Symlink {
+                target: "docs/README.md".to_string()
+            },
+            ContentsItem::Submodule {
+                submodule_git_url: None
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn should_get_single_submodule() {
+    let mut submodule = entry("submodule", "vendor");
+    submodule["submodule_git_url"] = json!("git://github.com/jquery/qunit.git");
+    let route = format!("/repos/{OWNER}/{REPO}/contents/vendor");
+    let mock_server = setup_api(&route, submodule).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .get_content()
+        .path("vendor")
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let items = result.unwrap().take_items();
+    assert_eq!(items.len(), 1);
+    assert_eq!(
+        items[0].item(),
+        ContentsItem::Submodule {
+            submodule_git_url: Some("git://github.com/jquery/qunit.git".to_string())
+        }
+    );
+    assert!(items[0].content.is_none());
+}
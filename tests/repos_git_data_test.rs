@@ -0,0 +1,464 @@
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::repos::git::{
+    BlobEncoding, CreateTreeEntry, FileChange, FileMode, TagObjectType, TreeEntryType,
+};
+use octocrab::params::repos::Reference;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path, query_param, query_param_is_missing},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const BLOB_SHA: &str = "3a0f86fb8db8eea7ccbb9a95f325ddbedfb25e15";
+const TREE_SHA: &str = "cd8274d15fa3ae2ab983129fb037999f264ba9a7";
+const BASE_TREE_SHA: &str = "9fb037999f264ba9a7fc6274d15fa3ae2ab98312";
+const COMMIT_SHA: &str = "7638417db6d59f3c431d3e1f261cc637155684cd";
+const HEAD_SHA: &str = "7d1b31e74ee336d15cbd21741bc88a537ed063a0";
+
+async fn setup_api(
+    http_method: &str,
+    api_path: String,
+    body: serde_json::Value,
+    template: ResponseTemplate,
+) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method(http_method))
+        .and(path(&api_path))
+        .and(body_json(body))
+        .respond_with(template)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("{http_method} on {api_path} was not received"),
+    )
+    .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_create_blob() {
+    let template = ResponseTemplate::new(201).set_body_json(json!({
+        "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/git/blobs/{BLOB_SHA}"),
+        "sha": BLOB_SHA
+    }));
+    let mock_server = setup_api(
+        "POST",
+        format!("/repos/{OWNER}/{REPO}/git/blobs"),
+        json!({ "content": "Hello, world!", "encoding": "utf-8" }),
+        template,
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .git()
+        .create_blob("Hello, world!", BlobEncoding::Utf8)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().sha, BLOB_SHA);
+}
+
+#[tokio::test]
+async fn should_create_tree() {
+    let template = ResponseTemplate::new(201).set_body_json(json!({
+        "sha": TREE_SHA,
+        "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/git/trees/{TREE_SHA}"),
+        "tree": [
+            {
+                "path": "README.md",
+                "mode": "100644",
+                "type": "blob",
+                "size": 7,
+                "sha": BLOB_SHA,
+                "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/git/blobs/{BLOB_SHA}")
+            }
+        ],
+        "truncated": false
+    }));
+    let mock_server = setup_api(
+        "POST",
+        format!("/repos/{OWNER}/{REPO}/git/trees"),
+        json!({
+            "base_tree": BASE_TREE_SHA,
+            "tree": [
+                { "path": "README.md", "mode": "100644", "type": "blob", "content": "# Hello" }
+            ]
+        }),
+        template,
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .git()
+        .create_tree(
+            Some(BASE_TREE_SHA.to_string()),
+            vec![CreateTreeEntry::content("README.md", "# Hello")],
+        )
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let tree = result.unwrap();
+    assert_eq!(tree.sha, TREE_SHA);
+    assert!(!tree.truncated);
+    assert_eq!(tree.tree[0].mode, FileMode::File);
+    assert_eq!(tree.tree[0].r#type, TreeEntryType::Blob);
+}
+
+#[tokio::test]
+async fn should_update_ref() {
+    let template = ResponseTemplate::new(200).set_body_json(json!({
+        "ref": "refs/heads/main",
+        "node_id": "MDM6UmVmcmVmcy9oZWFkcy9mZWF0dXJlQQ==",
+        "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/git/refs/heads/main"),
+        "object": {
+            "type": "commit",
+            "sha": COMMIT_SHA,
+            "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/git/commits/{COMMIT_SHA}")
+        }
+    }));
+    let mock_server = setup_api(
+        "PATCH",
+        format!("/repos/{OWNER}/{REPO}/git/refs/heads/main"),
+        json!({ "sha": COMMIT_SHA, "force": true }),
+        template,
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .git()
+        .update_ref(&Reference::Branch("main".to_string()), COMMIT_SHA, true)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+fn tree_response(sha: &str, entries: serde_json::Value, truncated: bool) -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "sha": sha,
+        "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/git/trees/{sha}"),
+        "tree": entries,
+        "truncated": truncated
+    }))
+}
+
+fn tree_entry(path: &str, entry_type: &str, sha: &str) -> serde_json::Value {
+    let mode = if entry_type == "tree" {
+        "040000"
+    } else {
+        "100644"
+    };
+    json!({ "path": path, "mode": mode, "type": entry_type, "sha": sha })
+}
+
+#[tokio::test]
+async fn should_get_tree_recursively() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/git/trees/{TREE_SHA}")))
+        .and(query_param("recursive", "1"))
+        .respond_with(tree_response(
+            TREE_SHA,
+            json!([
+                tree_entry("src", "tree", BASE_TREE_SHA),
+                tree_entry("src/lib.rs", "blob", BLOB_SHA),
+            ]),
+            false,
+        ))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/git/trees/{TREE_SHA} was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .git()
+        .get_tree(TREE_SHA)
+        .recursive(true)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let tree = result.unwrap();
+    assert_eq!(tree.tree.len(), 2);
+    assert_eq!(tree.tree[1].path, "src/lib.rs");
+}
+
+#[tokio::test]
+async fn should_complete_truncated_tree() {
+    let mock_server = MockServer::start().await;
+    let root_path = format!("/repos/{OWNER}/{REPO}/git/trees/{TREE_SHA}");
+    Mock::given(method("GET"))
+        .and(path(&root_path))
+        .and(query_param("recursive", "1"))
+        .respond_with(tree_response(
+            TREE_SHA,
+            json!([tree_entry("README.md", "blob", BLOB_SHA)]),
+            true,
+        ))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(&root_path))
+        .and(query_param_is_missing("recursive"))
+        .respond_with(tree_response(
+            TREE_SHA,
+            json!([
+                tree_entry("README.md", "blob", BLOB_SHA),
+                tree_entry("src", "tree", BASE_TREE_SHA),
+            ]),
+            false,
+        ))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/git/trees/{BASE_TREE_SHA}"
+        )))
+        .and(query_param("recursive", "1"))
+        .respond_with(tree_response(
+            BASE_TREE_SHA,
+            json!([
+                tree_entry("lib.rs", "blob", BLOB_SHA),
+                tree_entry("nested", "tree", COMMIT_SHA),
+                tree_entry("nested/mod.rs", "blob", BLOB_SHA),
+            ]),
+            false,
+        ))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on a git tree was not received").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .git()
+        .get_tree(TREE_SHA)
+        .send_complete()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let tree = result.unwrap();
+    assert!(!tree.truncated);
+    let paths: Vec<_> = tree.tree.iter().map(|entry| entry.path.as_str()).collect();
+    assert_eq!(
+        paths,
+        vec![
+            "README.md",
+            "src",
+            "src/lib.rs",
+            "src/nested",
+            "src/nested/mod.rs"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn should_create_tag_and_ref() {
+    const TAG_SHA: &str = "940bd336248efae0f9ee5bc7b2d5c985887b16ac";
+    let tag_body = json!({
+        "tag": "v1.0.0",
+        "message": "Release 1.0.0",
+        "object": COMMIT_SHA,
+        "type": "commit"
+    });
+    let mock_server = setup_api(
+        "POST",
+        format!("/repos/{OWNER}/{REPO}/git/tags"),
+        tag_body,
+        ResponseTemplate::new(201).set_body_json(json!({
+            "node_id": "MDM6VGFnOTQwYmQzMzYyNDhlZmFlMGY5ZWU1YmM3YjJkNWM5ODU4ODdiMTZhYw==",
+            "tag": "v1.0.0",
+            "sha": TAG_SHA,
+            "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/git/tags/{TAG_SHA}"),
+            "message": "Release 1.0.0",
+            "tagger": {
+                "name": "Monalisa Octocat",
+                "email": "octocat@github.com",
+                "date": "2014-11-07T22:01:45Z"
+            },
+            "object": {
+                "type": "commit",
+                "sha": COMMIT_SHA,
+                "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/git/commits/{COMMIT_SHA}")
+            }
+        })),
+    )
+    .await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/git/refs")))
+        .and(body_json(
+            json!({ "ref": "refs/tags/v1.0.0", "sha": TAG_SHA }),
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "ref": "refs/tags/v1.0.0",
+            "node_id": "MDM6UmVmcmVmcy90YWdzL3YxLjAuMA==",
+            "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/git/refs/tags/v1.0.0"),
+            "object": {
+                "type": "tag",
+                "sha": TAG_SHA,
+                "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/git/tags/{TAG_SHA}")
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .git()
+        .create_tag_and_ref(
+            "v1.0.0",
+            "Release 1.0.0",
+            COMMIT_SHA,
+            TagObjectType::Commit,
+            None,
+        )
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let (tag, _reference) = result.unwrap();
+    assert_eq!(tag.sha, TAG_SHA);
+    assert_eq!(tag.tagger.unwrap().name, "Monalisa Octocat");
+    assert_eq!(tag.object.unwrap().r#type, TagObjectType::Commit);
+}
+
+#[tokio::test]
+async fn should_commit_files() {
+    let mock_server = MockServer::start().await;
+    let commit_url =
+        |sha: &str| format!("https://api.github.com/repos/{OWNER}/{REPO}/git/commits/{sha}");
+
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/git/ref/heads/main")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "ref": "refs/heads/main",
+            "node_id": "MDM6UmVmcmVmcy9oZWFkcy9tYWlu",
+            "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/git/refs/heads/main"),
+            "object": { "type": "commit", "sha": HEAD_SHA, "url": commit_url(HEAD_SHA) }
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/git/trees/{HEAD_SHA}")))
+        .respond_with(tree_response(
+            BASE_TREE_SHA,
+            json!([tree_entry("README.md", "blob", BLOB_SHA)]),
+            false,
+        ))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/git/trees")))
+        .and(body_json(json!({
+            "base_tree": BASE_TREE_SHA,
+            "tree": [
+                { "path": "README.md", "mode": "100644", "type": "blob", "content": "# Hello" },
+                { "path": "old.txt", "mode": "100644", "type": "blob", "sha": null }
+            ]
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "sha": TREE_SHA,
+            "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/git/trees/{TREE_SHA}"),
+            "tree": [],
+            "truncated": false
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/git/commits")))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "sha": COMMIT_SHA,
+            "node_id": "MDY6Q29tbWl0NzYzODQxN2RiNmQ1OWYzYzQzMWQzZTFmMjYxY2M2MzcxNTU2ODRjZA==",
+            "url": commit_url(COMMIT_SHA),
+            "html_url": format!("https://github.com/{OWNER}/{REPO}/commit/{COMMIT_SHA}"),
+            "author": { "name": "Octocat", "email": "octocat@github.com", "date": "2024-01-01T00:00:00Z" },
+            "committer": { "name": "Octocat", "email": "octocat@github.com", "date": "2024-01-01T00:00:00Z" },
+            "message": "Update files",
+            "tree": {
+                "sha": TREE_SHA,
+                "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/git/trees/{TREE_SHA}")
+            },
+            "parents": [{ "sha": HEAD_SHA, "url": commit_url(HEAD_SHA) }],
+            "verification": { "verified": false, "reason": "unsigned", "signature": null, "payload": null }
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/git/refs/heads/main")))
+        .and(body_json(json!({ "sha": COMMIT_SHA, "force": false })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "ref": "refs/heads/main",
+            "node_id": "MDM6UmVmcmVmcy9oZWFkcy9tYWlu",
+            "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/git/refs/heads/main"),
+            "object": { "type": "commit", "sha": COMMIT_SHA, "url": commit_url(COMMIT_SHA) }
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "commit_files made an unexpected request").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .commit_files(
+            "main",
+            "Update files",
+            vec![
+                FileChange::upsert("README.md", "# Hello"),
+                FileChange::delete("old.txt"),
+            ],
+        )
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap(), COMMIT_SHA);
+}
@@ -0,0 +1,138 @@
+// Tests for `repos().merge_readiness()`.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::StatusState;
+use octocrab::Octocrab;
+use serde_json::{json, Value};
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "XAMPPRocky";
+const REPO: &str = "octocrab";
+const REF: &str = "main";
+
+fn combined_status(state: &str, total_count: u64) -> Value {
+    json!({
+        "state": state,
+        "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+        "total_count": total_count,
+        "statuses": []
+    })
+}
+
+async fn setup_api(status: Value, check_runs: Value) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/commits/{REF}/status")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(status))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/commits/{REF}/check-runs"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(check_runs))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/commits/{REF} was not received"),
+    )
+    .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn check_runs() -> Value {
+    serde_json::from_str(include_str!("resources/commit_check_runs.json")).unwrap()
+}
+
+#[tokio::test]
+async fn should_pass_when_statuses_and_checks_pass() {
+    let mock_server = setup_api(combined_status("success", 1), check_runs()).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).merge_readiness(REF).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let readiness = result.unwrap();
+    assert_eq!(readiness.combined_status, StatusState::Success);
+    assert_eq!(readiness.check_runs.len(), 2);
+    assert!(readiness.all_passing);
+}
+
+#[tokio::test]
+async fn should_ignore_pending_status_without_any_statuses() {
+    let mock_server = setup_api(combined_status("pending", 0), check_runs()).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let readiness = client
+        .repos(OWNER, REPO)
+        .merge_readiness(REF)
+        .await
+        .unwrap();
+
+    assert_eq!(readiness.combined_status, StatusState::Pending);
+    assert!(readiness.all_passing);
+}
+
+#[tokio::test]
+async fn should_fail_when_a_check_run_is_incomplete() {
+    let mut runs = check_runs();
+    runs["check_runs"][1]["conclusion"] = Value::Null;
+    let mock_server = setup_api(combined_status("success", 1), runs).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let readiness = client
+        .repos(OWNER, REPO)
+        .merge_readiness(REF)
+        .await
+        .unwrap();
+
+    assert!(!readiness.all_passing);
+}
+
+#[tokio::test]
+async fn should_fail_when_statuses_fail() {
+    let mock_server = setup_api(combined_status("failure", 1), check_runs()).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let readiness = client
+        .repos(OWNER, REPO)
+        .merge_readiness(REF)
+        .await
+        .unwrap();
+
+    assert_eq!(readiness.combined_status, StatusState::Failure);
+    assert!(!readiness.all_passing);
+}
+
+#[tokio::test]
+async fn should_only_count_the_latest_run_of_each_check() {
+    let mut runs = check_runs();
+    let mut stale = runs["check_runs"][0].clone();
+    stale["id"] = json!(1);
+    stale["conclusion"] = json!("failure");
+    runs["check_runs"].as_array_mut().unwrap().push(stale);
+    let mock_server = setup_api(combined_status("success", 1), runs).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let readiness = client
+        .repos(OWNER, REPO)
+        .merge_readiness(REF)
+        .await
+        .unwrap();
+
+    assert_eq!(readiness.check_runs.len(), 3);
+    assert!(readiness.all_passing);
+}
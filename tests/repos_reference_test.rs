@@ -0,0 +1,127 @@
+// Tests for passing typed references to the content, status and git refs
+// endpoints.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::params::repos::Reference;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "owner";
+const REPO: &str = "repo";
+const SHA: &str = "6dcb09b5b57875f334f61aebed695e2e4193db5e";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn references() -> Vec<(Reference, &'static str)> {
+    vec![
+        (
+            Reference::Branch("feature/a#b".to_string()),
+            "heads/feature/a#b",
+        ),
+        (Reference::Tag("v1.0".to_string()), "tags/v1.0"),
+        (Reference::Commit(SHA.to_string()), SHA),
+    ]
+}
+
+#[tokio::test]
+async fn should_pass_references_to_get_content() {
+    for (reference, expected) in references() {
+        let mock_server = MockServer::start().await;
+        let route = format!("/repos/{OWNER}/{REPO}/contents/README.md");
+        Mock::given(method("GET"))
+            .and(path(&route))
+            .and(query_param("ref", expected))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        setup_error_handler(&mock_server, &format!("GET on {route} was not received")).await;
+        let client = setup_octocrab(&mock_server.uri());
+
+        let result = client
+            .repos(OWNER, REPO)
+            .get_content()
+            .path("README.md")
+            .r#ref(&reference)
+            .send()
+            .await;
+
+        assert!(
+            result.is_ok(),
+            "expected successful result for {reference:?}, got error: {:#?}",
+            result
+        );
+    }
+}
+
+#[tokio::test]
+async fn should_encode_references_for_combined_status() {
+    for (reference, _) in references() {
+        let mock_server = MockServer::start().await;
+        let route = format!(
+            "/repos/{OWNER}/{REPO}/commits/{}/status",
+            reference.ref_url_path()
+        );
+        Mock::given(method("GET"))
+            .and(path(&route))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "state": "success",
+                "sha": SHA,
+                "total_count": 0,
+                "statuses": []
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        setup_error_handler(&mock_server, &format!("GET on {route} was not received")).await;
+        let client = setup_octocrab(&mock_server.uri());
+
+        let result = client
+            .repos(OWNER, REPO)
+            .combined_status_for_ref(&reference)
+            .await;
+
+        assert!(
+            result.is_ok(),
+            "expected successful result for {reference:?}, got error: {:#?}",
+            result
+        );
+    }
+}
+
+#[test]
+fn should_percent_encode_ref_url_paths() {
+    assert_eq!(
+        Reference::Branch("feature/a#b".to_string()).ref_url_path(),
+        "heads/feature/a%23b"
+    );
+    assert_eq!(
+        Reference::Tag("v1.0".to_string()).full_ref(),
+        "refs/tags/v1.0"
+    );
+    assert_eq!(Reference::Commit(SHA.to_string()).full_ref(), SHA);
+}
+
+#[tokio::test]
+async fn should_reject_commits_for_git_refs() {
+    let mock_server = MockServer::start().await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .get_ref(&Reference::Commit(SHA.to_string()))
+        .await;
+
+    assert!(
+        matches!(result, Err(octocrab::Error::Other { .. })),
+        "expected an error, got: {:#?}",
+        result
+    );
+}
@@ -82,3 +82,47 @@ async fn should_fail_when_no_releases_found() {
         other => panic!("Unexpected error: {:?}", other),
     }
 }
+
+#[tokio::test]
+async fn should_deserialize_assets_with_varying_uploaders() {
+    let mocked_response: Value = serde_json::from_str(include_str!(
+        "resources/repos_releases_get_latest_with_assets.json"
+    ))
+    .unwrap();
+    let template = ResponseTemplate::new(200).set_body_json(&mocked_response);
+    let mock_server = setup_get_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client.repos(OWNER, REPO).releases().get_latest().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+
+    let release = result.unwrap();
+    assert_eq!(release.assets.len(), 3);
+
+    let linux = release
+        .asset_by_name("octocrab-x86_64-unknown-linux-gnu.tar.gz")
+        .unwrap();
+    assert_eq!(
+        linux
+            .uploader
+            .as_ref()
+            .map(|uploader| uploader.r#type.as_str()),
+        Some("Bot")
+    );
+
+    let windows = release
+        .asset_matching(|name| name.contains("windows") && name.ends_with(".zip"))
+        .unwrap();
+    assert!(windows.uploader.is_none());
+
+    let checksums = release.asset_by_name("checksums.txt").unwrap();
+    let uploader = checksums.uploader.as_ref().unwrap();
+    assert_eq!(uploader.node_id, None);
+    assert!(!uploader.site_admin);
+
+    assert!(release.asset_by_name("missing.tar.gz").is_none());
+}
@@ -0,0 +1,197 @@
+// Tests for ReleasesHandler::latest with and without prereleases.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::{params::repos::releases::Prereleases, Error, Octocrab};
+use serde_json::{json, Value};
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "XAMPPRocky";
+const REPO: &str = "octocrab";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn release(template: &Value, tag: &str, draft: bool, prerelease: bool, published: &str) -> Value {
+    let mut release = template.clone();
+    release["tag_name"] = json!(tag);
+    release["draft"] = json!(draft);
+    release["prerelease"] = json!(prerelease);
+    release["published_at"] = json!(published);
+    release
+}
+
+#[tokio::test]
+async fn should_return_newest_release_including_prereleases() {
+    let releases: Vec<Value> =
+        serde_json::from_str(include_str!("resources/repos_releases_list.json")).unwrap();
+    let template = &releases[0];
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/releases")))
+        .and(query_param("per_page", "100"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            release(template, "v0.39.0", true, false, "2024-05-01T00:00:00Z"),
+            release(template, "v0.38.0", false, false, "2024-04-09T10:50:08Z"),
+            release(
+                template,
+                "v0.39.0-rc.1",
+                false,
+                true,
+                "2024-04-20T00:00:00Z"
+            ),
+        ])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/releases was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .releases()
+        .latest(Prereleases::Include)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().unwrap().tag_name, "v0.39.0-rc.1");
+}
+
+#[tokio::test]
+async fn should_return_none_without_stable_release() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/releases/latest")))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "message": "Not Found",
+            "documentation_url": "https://docs.github.com/rest/releases/releases#get-the-latest-release"
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/releases")))
+        .and(query_param("per_page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/releases/latest was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .releases()
+        .latest(Prereleases::Exclude)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert!(result.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn should_fail_for_missing_repository() {
+    let not_found = ResponseTemplate::new(404).set_body_json(json!({
+        "message": "Not Found",
+        "documentation_url": "https://docs.github.com/rest/releases/releases#list-releases"
+    }));
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/releases/latest")))
+        .respond_with(not_found.clone())
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/releases")))
+        .respond_with(not_found)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .releases()
+        .latest(Prereleases::Exclude)
+        .await;
+
+    match result.unwrap_err() {
+        Error::GitHub { source, .. } => assert_eq!(source.status_code, 404),
+        other => panic!("Unexpected error: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn should_skip_pages_of_drafts_when_including_prereleases() {
+    let releases: Vec<Value> =
+        serde_json::from_str(include_str!("resources/repos_releases_list.json")).unwrap();
+    let template = &releases[0];
+    let mock_server = MockServer::start().await;
+    let route = format!("/repos/{OWNER}/{REPO}/releases");
+    Mock::given(method("GET"))
+        .and(path(&route))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([release(
+            template,
+            "v0.38.0",
+            false,
+            false,
+            "2024-04-09T10:50:08Z"
+        )])))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(&route))
+        .and(query_param("per_page", "100"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header(
+                    "Link",
+                    format!(
+                        r#"<{}{route}?per_page=100&page=2>; rel="next""#,
+                        mock_server.uri()
+                    )
+                    .as_str(),
+                )
+                .set_body_json(json!([release(
+                    template,
+                    "v0.39.0",
+                    true,
+                    false,
+                    "2024-05-01T00:00:00Z"
+                )])),
+        )
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, &format!("GET on {route} was not received")).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .releases()
+        .latest(Prereleases::Include)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().unwrap().tag_name, "v0.38.0");
+}
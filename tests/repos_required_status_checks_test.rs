@@ -0,0 +1,181 @@
+// Tests for combining required status checks with a commit's combined status
+// and check runs.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "owner";
+const REPO: &str = "repo";
+const BRANCH: &str = "main";
+const SHA: &str = "6dcb09b5b57875f334f61aebed695e2e4193db5e";
+
+fn status(context: &str, state: &str) -> serde_json::Value {
+    json!({
+        "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/statuses/{SHA}"),
+        "avatar_url": "https://github.com/images/error/hubot_happy.gif",
+        "id": 1,
+        "node_id": "MDY6U3RhdHVzMQ==",
+        "state": state,
+        "description": "Build has completed",
+        "target_url": "https://ci.example.com/1000/output",
+        "context": context,
+        "created_at": "2012-07-20T01:19:13Z",
+        "updated_at": "2012-07-20T01:19:13Z"
+    })
+}
+
+fn required_status_checks(contexts: &[&str], checks: serde_json::Value) -> serde_json::Value {
+    json!({
+        "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/branches/{BRANCH}/protection/required_status_checks"),
+        "strict": true,
+        "contexts": contexts,
+        "checks": checks,
+        "contexts_url": format!("https://api.github.com/repos/{OWNER}/{REPO}/branches/{BRANCH}/protection/required_status_checks/contexts")
+    })
+}
+
+async fn setup_api(required: serde_json::Value) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/branches/{BRANCH}/protection/required_status_checks"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(required))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/commits/{SHA}/status")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "state": "failure",
+            "sha": SHA,
+            "total_count": 4,
+            "statuses": [
+                status("ci/build", "success"),
+                status("ci/test", "failure"),
+                status("ci/lint", "pending"),
+                status("ci/optional", "error")
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+    let mut check_runs: serde_json::Value =
+        serde_json::from_str(include_str!("resources/commit_check_runs.json")).unwrap();
+    check_runs["check_runs"][0]["name"] = json!("security/scan");
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/commits/{SHA}/check-runs"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(check_runs))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on required status checks, combined status or check runs was not received",
+    )
+    .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_group_required_contexts_by_state() {
+    let mock_server = setup_api(required_status_checks(
+        &["ci/build", "ci/test", "ci/lint", "security/scan"],
+        json!([
+            { "context": "ci/build", "app_id": null },
+            { "context": "ci/test", "app_id": null },
+            { "context": "ci/lint", "app_id": null },
+            { "context": "security/scan", "app_id": 15368 }
+        ]),
+    ))
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .required_status_checks_status(BRANCH, SHA.to_string())
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let status = result.unwrap();
+    assert_eq!(status.passing, vec!["ci/build", "security/scan"]);
+    assert_eq!(status.failing, vec!["ci/test"]);
+    assert_eq!(status.pending, vec!["ci/lint"]);
+    assert!(!status.all_passing());
+}
+
+#[tokio::test]
+async fn should_prefer_required_checks_over_contexts() {
+    let mock_server = setup_api(required_status_checks(
+        &["ci/build", "ci/test"],
+        json!([
+            { "context": "ci/build", "app_id": null },
+            { "context": "ci/lint", "app_id": null }
+        ]),
+    ))
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let status = client
+        .repos(OWNER, REPO)
+        .required_status_checks_status(BRANCH, SHA.to_string())
+        .await
+        .unwrap();
+
+    assert_eq!(status.passing, vec!["ci/build"]);
+    assert!(status.failing.is_empty());
+    assert_eq!(status.pending, vec!["ci/lint"]);
+}
+
+#[tokio::test]
+async fn should_fall_back_to_contexts_without_required_checks() {
+    let mock_server = setup_api(required_status_checks(&["ci/build", "ci/test"], json!([]))).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let status = client
+        .repos(OWNER, REPO)
+        .required_status_checks_status(BRANCH, SHA.to_string())
+        .await
+        .unwrap();
+
+    assert_eq!(status.passing, vec!["ci/build"]);
+    assert_eq!(status.failing, vec!["ci/test"]);
+    assert!(status.pending.is_empty());
+}
+
+#[tokio::test]
+async fn should_only_count_runs_from_the_required_app() {
+    let mock_server = setup_api(required_status_checks(
+        &["ci/build", "security/scan"],
+        json!([
+            { "context": "ci/build", "app_id": 42 },
+            { "context": "security/scan", "app_id": 42 }
+        ]),
+    ))
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let status = client
+        .repos(OWNER, REPO)
+        .required_status_checks_status(BRANCH, SHA.to_string())
+        .await
+        .unwrap();
+
+    assert!(status.passing.is_empty());
+    assert!(status.failing.is_empty());
+    assert_eq!(status.pending, vec!["ci/build", "security/scan"]);
+}
@@ -0,0 +1,76 @@
+// Tests for calls to the /repos/{owner}/{repo}/dependency-graph/sbom API.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "owner";
+const REPO: &str = "repo";
+
+async fn setup_api(template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/dependency-graph/sbom")))
+        .respond_with(template)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/dependency-graph/sbom was not received"),
+    )
+    .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_return_sbom() {
+    let template = ResponseTemplate::new(200).set_body_json(json!({
+        "sbom": {
+            "SPDXID": "SPDXRef-DOCUMENT",
+            "spdxVersion": "SPDX-2.3",
+            "creationInfo": {
+                "created": "2021-09-01T00:00:00Z",
+                "creators": ["Tool: GitHub.com-Dependency-Graph"]
+            },
+            "name": "github/owner/repo",
+            "dataLicense": "CC0-1.0",
+            "documentNamespace": "https://github.com/owner/repo/dependency_graph/sbom-123",
+            "packages": [
+                {
+                    "SPDXID": "SPDXRef-npm-lodash-4.17.21",
+                    "name": "npm:lodash",
+                    "versionInfo": "4.17.21"
+                },
+                {
+                    "SPDXID": "SPDXRef-npm-react-18.2.0",
+                    "name": "npm:react",
+                    "versionInfo": "18.2.0"
+                }
+            ]
+        }
+    }));
+    let mock_server = setup_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).sbom().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let sbom = result.unwrap();
+    assert_eq!(sbom.spdx_version(), Some("SPDX-2.3"));
+    assert_eq!(sbom.name(), Some("github/owner/repo"));
+    assert_eq!(sbom.package_count(), 2);
+}
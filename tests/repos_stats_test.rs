@@ -0,0 +1,130 @@
+// Tests for calls to the /repos/{owner}/{repo}/stats API.
+mod mock_error;
+
+use std::time::Duration;
+
+use mock_error::setup_error_handler;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "owner";
+const REPO: &str = "repo";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+async fn setup_api(statistic: &str, pending_responses: u64, body: serde_json::Value) -> MockServer {
+    let mock_server = MockServer::start().await;
+    let api_path = format!("/repos/{OWNER}/{REPO}/stats/{statistic}");
+
+    if pending_responses > 0 {
+        Mock::given(method("GET"))
+            .and(path(&api_path))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({})))
+            .up_to_n_times(pending_responses)
+            .expect(pending_responses)
+            .mount(&mock_server)
+            .await;
+    }
+    Mock::given(method("GET"))
+        .and(path(&api_path))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, &format!("GET on {api_path} was not received")).await;
+    mock_server
+}
+
+#[tokio::test]
+async fn should_retry_contributors_until_computed() {
+    let mock_server = setup_api(
+        "contributors",
+        2,
+        json!([{
+            "author": null,
+            "total": 135,
+            "weeks": [{ "w": 1367712000, "a": 6898, "d": 77, "c": 10 }]
+        }]),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .stats()
+        .backoff(Duration::from_millis(1))
+        .contributors()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let contributors = result.unwrap();
+    assert_eq!(contributors[0].total, 135);
+    assert_eq!(contributors[0].weeks[0].a, 6898);
+}
+
+#[tokio::test]
+async fn should_fail_when_still_computing_after_max_attempts() {
+    let mock_server = setup_api("contributors", 3, json!([])).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .stats()
+        .max_attempts(3)
+        .backoff(Duration::from_millis(1))
+        .contributors()
+        .await;
+
+    assert!(
+        matches!(result, Err(octocrab::Error::Other { .. })),
+        "expected an error, got: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_get_code_frequency() {
+    let mock_server = setup_api("code_frequency", 0, json!([[1302998400, 1124, -435]])).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).stats().code_frequency().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let week = result.unwrap()[0];
+    assert_eq!(week.week, 1302998400);
+    assert_eq!(week.additions, 1124);
+    assert_eq!(week.deletions, -435);
+}
+
+#[tokio::test]
+async fn should_get_participation() {
+    let mock_server = setup_api(
+        "participation",
+        0,
+        json!({ "all": [11, 21, 15], "owner": [3, 2, 3] }),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).stats().participation().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().all, vec![11, 21, 15]);
+}
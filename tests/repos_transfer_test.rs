@@ -0,0 +1,82 @@
+// Tests for calls to the POST /repos/{owner}/{repo}/transfer API.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::Repository;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "owner";
+const REPO: &str = "repo";
+
+async fn setup_api(body: serde_json::Value, template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/transfer")))
+        .and(body_json(body))
+        .respond_with(template)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("POST on /repos/{OWNER}/{REPO}/transfer was not received"),
+    )
+    .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn accepted_repository() -> (Repository, ResponseTemplate) {
+    let repositories: Vec<Repository> =
+        serde_json::from_str(include_str!("resources/user_repositories.json")).unwrap();
+    let repository = repositories.into_iter().next().unwrap();
+    let template = ResponseTemplate::new(202).set_body_json(&repository);
+    (repository, template)
+}
+
+#[tokio::test]
+async fn should_transfer_repository() {
+    let (repository, template) = accepted_repository();
+    let mock_server = setup_api(json!({ "new_owner": "new-org" }), template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.repos(OWNER, REPO).transfer("new-org", None).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().id, repository.id);
+}
+
+#[tokio::test]
+async fn should_transfer_repository_with_teams() {
+    let (repository, template) = accepted_repository();
+    let mock_server = setup_api(
+        json!({ "new_owner": "new-org", "team_ids": [12, 345] }),
+        template,
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .transfer("new-org", Some(vec![12.into(), 345.into()]))
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().id, repository.id);
+}
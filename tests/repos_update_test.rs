@@ -0,0 +1,68 @@
+// Tests for calls to the PATCH /repos/{owner}/{repo} API.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::Repository;
+use octocrab::params::repos::Visibility;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "owner";
+const REPO: &str = "repo";
+
+async fn setup_api(template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PATCH"))
+        .and(path(format!("/repos/{OWNER}/{REPO}")))
+        .and(body_json(json!({
+            "description": "Settings as code",
+            "visibility": "private",
+            "allow_squash_merge": false,
+            "delete_branch_on_merge": true,
+        })))
+        .respond_with(template)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("PATCH on /repos/{OWNER}/{REPO} was not received"),
+    )
+    .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_update_repository() {
+    let repositories: Vec<Repository> =
+        serde_json::from_str(include_str!("resources/user_repositories.json")).unwrap();
+    let repository = repositories.into_iter().next().unwrap();
+    let template = ResponseTemplate::new(200).set_body_json(&repository);
+    let mock_server = setup_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .update()
+        .description("Settings as code")
+        .visibility(Visibility::Private)
+        .allow_squash_merge(false)
+        .delete_branch_on_merge(true)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().id, repository.id);
+}
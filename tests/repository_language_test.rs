@@ -0,0 +1,29 @@
+// Tests for deserializing `Repository::language`.
+use octocrab::models::Repository;
+use serde_json::{json, Value};
+
+fn repository_with_language(language: Value) -> Repository {
+    let mut repositories: Vec<Value> =
+        serde_json::from_str(include_str!("resources/user_repositories.json")).unwrap();
+    let mut repository = repositories.remove(0);
+    repository["language"] = language;
+    serde_json::from_value(repository).unwrap()
+}
+
+#[test]
+fn should_deserialize_language_name() {
+    let repository = repository_with_language(json!("Rust"));
+    assert_eq!(repository.language.as_deref(), Some("Rust"));
+}
+
+#[test]
+fn should_deserialize_null_language() {
+    let repository = repository_with_language(Value::Null);
+    assert_eq!(repository.language, None);
+}
+
+#[test]
+fn should_deserialize_language_breakdown() {
+    let repository = repository_with_language(json!({ "Shell": 1024, "Rust": 98304 }));
+    assert_eq!(repository.language.as_deref(), Some("Rust"));
+}
@@ -0,0 +1,114 @@
+// Tests for opting specific responses into retries with `RetryConfig::with_predicate`.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::service::middleware::retry::RetryConfig;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "owner";
+const REPO: &str = "repo";
+
+async fn setup_api(message: &str) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/license")))
+        .respond_with(ResponseTemplate::new(422).set_body_json(json!({
+            "message": message,
+            "documentation_url": "https://docs.github.com/rest"
+        })))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/license")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "name": "LICENSE",
+            "path": "LICENSE",
+            "sha": "c0a49a3b1bfd8b2d1e4f4b8d5dc5d5c8a3cfc5c3",
+            "size": 1072,
+            "url": "https://api.github.com/repos/owner/repo/contents/LICENSE?ref=main",
+            "html_url": "https://github.com/owner/repo/blob/main/LICENSE",
+            "git_url": "https://api.github.com/repos/owner/repo/git/blobs/c0a49a3b1bfd8b2d1e4f4b8d5dc5d5c8a3cfc5c3",
+            "download_url": "https://raw.githubusercontent.com/owner/repo/main/LICENSE",
+            "type": "file",
+            "content": "",
+            "encoding": "base64",
+            "_links": {
+                "self": "https://api.github.com/repos/owner/repo/contents/LICENSE?ref=main",
+                "git": "https://api.github.com/repos/owner/repo/git/blobs/c0a49a3b1bfd8b2d1e4f4b8d5dc5d5c8a3cfc5c3",
+                "html": "https://github.com/owner/repo/blob/main/LICENSE"
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/license was not received"),
+    )
+    .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str, retry_config: RetryConfig) -> Octocrab {
+    Octocrab::builder()
+        .add_retry_config(retry_config)
+        .base_uri(uri)
+        .unwrap()
+        .build()
+        .unwrap()
+}
+
+fn submitted_too_quickly() -> RetryConfig {
+    RetryConfig::with_predicate(1, |_, _, error| {
+        error.is_some_and(|error| error.message == "was submitted too quickly")
+    })
+}
+
+#[tokio::test]
+async fn should_retry_responses_matching_predicate() {
+    let mock_server = setup_api("was submitted too quickly").await;
+    let client = setup_octocrab(&mock_server.uri(), submitted_too_quickly());
+
+    let result = client.repos(OWNER, REPO).license().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_not_retry_responses_not_matching_predicate() {
+    let mock_server = setup_api("Validation Failed").await;
+    let client = setup_octocrab(&mock_server.uri(), submitted_too_quickly());
+
+    let result = client.repos(OWNER, REPO).license().await;
+
+    match result {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.message, "Validation Failed");
+        }
+        other => panic!("expected a GitHub error, got {:#?}", other),
+    }
+}
+
+#[tokio::test]
+async fn should_not_retry_without_predicate() {
+    let mock_server = setup_api("was submitted too quickly").await;
+    let client = setup_octocrab(&mock_server.uri(), RetryConfig::Simple(1));
+
+    let result = client.repos(OWNER, REPO).license().await;
+
+    assert!(
+        result.is_err(),
+        "expected error result, got success somehow: {:#?}",
+        result
+    );
+}
@@ -0,0 +1,71 @@
+// Tests for calls to the /search/code API.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{header, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_return_text_matches() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search/code"))
+        .and(query_param("q", "addClass repo:jquery/jquery"))
+        .and(header("Accept", "application/vnd.github.text-match+json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "total_count": 1,
+            "incomplete_results": false,
+            "items": [{
+                "name": "classes.js",
+                "path": "src/attributes/classes.js",
+                "sha": "d7212f9dee2dcc18f084d7df8f417b80846ded5a",
+                "url": "https://api.github.com/repositories/167174/contents/src/attributes/classes.js?ref=825ac3773694e0cd23ee74895fd5aeb535b27da4",
+                "git_url": "https://api.github.com/repositories/167174/git/blobs/d7212f9dee2dcc18f084d7df8f417b80846ded5a",
+                "html_url": "https://github.com/jquery/jquery/blob/825ac3773694e0cd23ee74895fd5aeb535b27da4/src/attributes/classes.js",
+                "repository": {
+                    "id": 167174,
+                    "name": "jquery",
+                    "full_name": "jquery/jquery",
+                    "url": "https://api.github.com/repos/jquery/jquery"
+                },
+                "text_matches": [{
+                    "object_url": "https://api.github.com/repositories/167174/contents/src/attributes/classes.js",
+                    "object_type": "FileContent",
+                    "property": "content",
+                    "fragment": "jQuery.fn.extend( {\n\taddClass: function( value ) {",
+                    "matches": [{ "text": "addClass", "indices": [21, 29] }]
+                }]
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /search/code was not received").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .search()
+        .code("addClass repo:jquery/jquery")
+        .text_matches(true)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let code = &result.unwrap().items[0];
+    assert_eq!(code.text_matches.len(), 1);
+    let text_match = &code.text_matches[0];
+    assert_eq!(text_match.property, "content");
+    assert_eq!(text_match.matches[0].text, "addClass");
+    assert_eq!(text_match.matches[0].indices, vec![21, 29]);
+}
@@ -0,0 +1,58 @@
+// Tests for calls to the /search/topics API.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{header, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_search_topics_with_preview_header() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search/topics"))
+        .and(query_param("q", "ruby is:featured"))
+        .and(header("Accept", "application/vnd.github.mercy-preview+json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "total_count": 1,
+            "incomplete_results": false,
+            "items": [{
+                "name": "ruby",
+                "display_name": "Ruby",
+                "short_description": "Ruby is a scripting language designed for simplified object-oriented programming.",
+                "description": "Ruby was developed by Yukihiro \"Matz\" Matsumoto in 1995.",
+                "created_by": "Yukihiro Matsumoto",
+                "released": "December 21, 1995",
+                "created_at": "2016-11-28T22:03:59Z",
+                "updated_at": "2017-10-30T18:16:32Z",
+                "featured": true,
+                "curated": true,
+                "score": 1.0
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /search/topics was not received").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.search().topics("ruby is:featured").send().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let topics = result.unwrap().items;
+    assert_eq!(topics.len(), 1);
+    assert_eq!(topics[0].name, "ruby");
+    assert_eq!(topics[0].display_name.as_deref(), Some("Ruby"));
+    assert!(topics[0].featured);
+    assert!(topics[0].curated);
+}
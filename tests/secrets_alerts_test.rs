@@ -1,11 +1,13 @@
 use wiremock::{
-    matchers::{method, path},
+    matchers::{body_partial_json, method, path, query_param},
     Mock, MockServer, ResponseTemplate,
 };
 
 use mock_error::setup_error_handler;
-use octocrab::models::repos::secret_scanning_alert::SecretScanningAlert;
 use octocrab::models::repos::secret_scanning_alert::SecretsScanningAlertLocation;
+use octocrab::models::repos::secret_scanning_alert::{
+    SecretScanningAlert, UpdateSecretScanningAlert,
+};
 use octocrab::Octocrab;
 
 mod mock_error;
@@ -129,3 +131,93 @@ async fn check_secrets_alert_locations_list_200() {
 
     assert_eq!(items.len(), 13);
 }
+
+#[tokio::test]
+async fn should_join_list_filters_with_commas() {
+    let s = include_str!("resources/check_secrets_alerts.json");
+    let alerts: Vec<SecretScanningAlert> = serde_json::from_str(s).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/secret-scanning/alerts"
+        )))
+        .and(query_param("state", "resolved"))
+        .and(query_param("secret_type", "github_personal_access_token"))
+        .and(query_param("resolution", "false_positive,revoked"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&alerts))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/secret-scanning/alerts was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .secrets_scanning()
+        .state("resolved")
+        .secret_type("github_personal_access_token")
+        .resolution(vec!["false_positive".to_owned(), "revoked".to_owned()])
+        .get_alerts()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().items.len(), alerts.len());
+}
+
+#[tokio::test]
+async fn should_get_and_resolve_an_alert() {
+    let s = include_str!("resources/check_secrets_alerts.json");
+    let alerts: Vec<SecretScanningAlert> = serde_json::from_str(s).unwrap();
+    let route = format!("/repos/{OWNER}/{REPO}/secret-scanning/alerts/{ALERT_NUMBER}");
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(&route))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&alerts[0]))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path(&route))
+        .and(body_partial_json(serde_json::json!({
+            "state": "resolved",
+            "resolution": "revoked",
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&alerts[0]))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, &format!("GET on {route} was not received")).await;
+    let client = setup_octocrab(&mock_server.uri());
+    let repo = client.repos(OWNER, REPO);
+
+    let result = repo.secrets_scanning().get_alert(ALERT_NUMBER).await;
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().number, alerts[0].number);
+
+    let result = repo
+        .secrets_scanning()
+        .update_alert(
+            ALERT_NUMBER,
+            Some(&UpdateSecretScanningAlert {
+                state: "resolved",
+                resolution: Some("revoked"),
+                resolution_comment: None,
+            }),
+        )
+        .await;
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
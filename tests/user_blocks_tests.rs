@@ -106,7 +106,7 @@ async fn should_respond_user_blocked() {
 #[tokio::test]
 async fn should_respond_user_unblocked() {
     /* status 204 for unblocked */
-    let template = ResponseTemplate::new(200);
+    let template = ResponseTemplate::new(204);
     let mock_server = setup_blocks_mock(
         "DELETE",
         format!("/user/blocks/{}", NOT_BLOCKED).as_str(),
@@ -115,5 +115,5 @@ async fn should_respond_user_unblocked() {
     .await;
     let client = setup_octocrab(&mock_server.uri());
     let result = client.users("some-user").unblock_user(NOT_BLOCKED).await;
-    assert!(result.is_err());
+    assert!(result.is_ok());
 }
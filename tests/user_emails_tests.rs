@@ -1,6 +1,7 @@
 use http::StatusCode;
+use serde_json::json;
 use wiremock::{
-    matchers::{method, path},
+    matchers::{body_json, method, path},
     Mock, MockServer, ResponseTemplate,
 };
 
@@ -54,7 +55,7 @@ async fn should_respond_to_primary_email_visibility() {
     );
     let response = result.unwrap();
     let visibility = response.first().unwrap().visibility;
-    assert_eq!(visibility, EmailVisibilityState::Private);
+    assert_eq!(visibility, Some(EmailVisibilityState::Private));
 }
 
 #[tokio::test]
@@ -78,7 +79,7 @@ async fn should_respond_to_email_list() {
     );
     let response = result.unwrap();
     let visibility = response.items.first().unwrap().visibility;
-    assert_eq!(visibility, EmailVisibilityState::Private);
+    assert_eq!(visibility, Some(EmailVisibilityState::Private));
 }
 
 #[tokio::test]
@@ -102,7 +103,7 @@ async fn should_respond_to_public_email_list() {
     );
     let response = result.unwrap();
     let visibility = response.items.first().unwrap().visibility;
-    assert_eq!(visibility, EmailVisibilityState::Private);
+    assert_eq!(visibility, Some(EmailVisibilityState::Private));
 }
 
 #[tokio::test]
@@ -140,3 +141,154 @@ async fn should_respond_to_emails_delete() {
         result
     );
 }
+
+#[tokio::test]
+async fn should_send_primary_email_visibility_as_plain_string() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PATCH"))
+        .and(path("/user/email/visibility"))
+        .and(body_json(json!({ "visibility": "public" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .users("some_other_user")
+        .set_primary_email_visibility(EmailVisibilityState::Public)
+        .await;
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let result = client
+        .current()
+        .set_primary_email_visibility(EmailVisibilityState::Public)
+        .await;
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_list_current_user_emails() {
+    let template = ResponseTemplate::new(200).set_body_json(json!([
+        {
+            "email": "octocat@github.com",
+            "primary": true,
+            "verified": true,
+            "visibility": "public"
+        },
+        {
+            "email": "octocat@example.com",
+            "primary": false,
+            "verified": false,
+            "visibility": null
+        }
+    ]));
+    let mock_server = setup_emails_mock("GET", "/user/emails", template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.current().list_emails().per_page(100).send().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let emails = result.unwrap().items;
+    assert_eq!(emails.len(), 2);
+    assert_eq!(emails[0].visibility, Some(EmailVisibilityState::Public));
+    assert!(!emails[1].primary);
+    assert_eq!(emails[1].visibility, None);
+}
+
+#[tokio::test]
+async fn should_add_and_delete_current_user_emails() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/user/emails"))
+        .and(body_json(json!({ "emails": ["octocat@example.com"] })))
+        .respond_with(
+            ResponseTemplate::new(StatusCode::CREATED).set_body_json(json!([{
+                "email": "octocat@example.com",
+                "primary": false,
+                "verified": false,
+                "visibility": null
+            }])),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/user/emails"))
+        .and(body_json(json!({ "emails": ["octocat@example.com"] })))
+        .respond_with(ResponseTemplate::new(StatusCode::NO_CONTENT))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let added = client.current().add_emails(&["octocat@example.com"]).await;
+    assert!(
+        added.is_ok(),
+        "expected successful result, got error: {:#?}",
+        added
+    );
+    assert_eq!(added.unwrap()[0].email, "octocat@example.com");
+
+    let deleted = client
+        .current()
+        .delete_emails(&["octocat@example.com"])
+        .await;
+    assert!(
+        deleted.is_ok(),
+        "expected successful result, got error: {:#?}",
+        deleted
+    );
+}
+
+#[tokio::test]
+async fn should_accept_ok_status_when_adding_and_deleting_emails() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/user/emails"))
+        .respond_with(ResponseTemplate::new(StatusCode::OK).set_body_json(json!([{
+            "email": "octocat@example.com",
+            "primary": false,
+            "verified": false,
+            "visibility": null
+        }])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/user/emails"))
+        .respond_with(ResponseTemplate::new(StatusCode::OK))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let added = client.current().add_emails(&["octocat@example.com"]).await;
+    assert!(
+        added.is_ok(),
+        "expected successful result, got error: {:#?}",
+        added
+    );
+    assert_eq!(added.unwrap()[0].email, "octocat@example.com");
+
+    let deleted = client
+        .current()
+        .delete_emails(&["octocat@example.com"])
+        .await;
+    assert!(
+        deleted.is_ok(),
+        "expected successful result, got error: {:#?}",
+        deleted
+    );
+}
@@ -0,0 +1,90 @@
+// Tests for calls to the /users/{username}/events and /users/{username}/received_events APIs.
+mod mock_error;
+
+use std::time::Duration;
+
+use mock_error::setup_error_handler;
+use octocrab::etag::EntityTag;
+use octocrab::Octocrab;
+use serde_json::{json, Value};
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const USERNAME: &str = "octocat";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn event() -> Value {
+    serde_json::from_str(include_str!("resources/create_event.json")).unwrap()
+}
+
+async fn setup_api(route: &str, template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(route))
+        .respond_with(template)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, &format!("GET on {route} was not received")).await;
+    mock_server
+}
+
+#[tokio::test]
+async fn should_list_user_events_with_etag_and_poll_interval() {
+    let template = ResponseTemplate::new(200)
+        .set_body_json(json!([event()]))
+        .insert_header("etag", "\"1234\"")
+        .insert_header("x-poll-interval", "60");
+    let mock_server = setup_api(&format!("/users/{USERNAME}/events"), template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.users(USERNAME).events().send().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let etagged = result.unwrap();
+    assert_eq!(etagged.etag, Some(EntityTag::strong("1234".to_string())));
+    assert_eq!(etagged.poll_interval, Some(Duration::from_secs(60)));
+    assert_eq!(etagged.value.unwrap().items.len(), 1);
+}
+
+#[tokio::test]
+async fn should_not_return_received_events_when_not_modified() {
+    let route = format!("/users/{USERNAME}/received_events");
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(&route))
+        .and(header("if-none-match", "\"1234\""))
+        .respond_with(
+            ResponseTemplate::new(304)
+                .insert_header("etag", "\"1234\"")
+                .insert_header("x-poll-interval", "90"),
+        )
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, &format!("GET on {route} was not received")).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .users(USERNAME)
+        .received_events()
+        .etag(Some(EntityTag::strong("1234".to_string())))
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let etagged = result.unwrap();
+    assert!(etagged.value.is_none());
+    assert_eq!(etagged.poll_interval, Some(Duration::from_secs(90)));
+}
@@ -0,0 +1,127 @@
+// Tests for following users and listing followers.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::Octocrab;
+use serde_json::Value;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const USERNAME: &str = "octocat";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+async fn setup_api(verb: &str, route: &str, template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method(verb))
+        .and(path(route))
+        .respond_with(template)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, &format!("{verb} on {route} was not received")).await;
+    mock_server
+}
+
+fn users() -> Value {
+    serde_json::from_str(include_str!("resources/user_blocks.json")).unwrap()
+}
+
+#[tokio::test]
+async fn should_list_followers() {
+    let route = format!("/users/{USERNAME}/followers");
+    let template = ResponseTemplate::new(200).set_body_json(users());
+    let mock_server = setup_api("GET", &route, template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.users(USERNAME).followers().send().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().items[0].login, "octocat");
+}
+
+#[tokio::test]
+async fn should_list_following() {
+    let route = format!("/users/{USERNAME}/following");
+    let template = ResponseTemplate::new(200).set_body_json(users());
+    let mock_server = setup_api("GET", &route, template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.users(USERNAME).following().send().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().items[0].login, "octocat");
+}
+
+#[tokio::test]
+async fn should_check_whether_authenticated_user_is_following() {
+    let route = format!("/user/following/{USERNAME}");
+    let mock_server = setup_api("GET", &route, ResponseTemplate::new(204)).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.current().is_following(USERNAME).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert!(result.unwrap());
+}
+
+#[tokio::test]
+async fn should_report_not_following_on_404() {
+    let route = format!("/user/following/{USERNAME}");
+    let mock_server = setup_api("GET", &route, ResponseTemplate::new(404)).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.current().is_following(USERNAME).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert!(!result.unwrap());
+}
+
+#[tokio::test]
+async fn should_follow_user() {
+    let route = format!("/user/following/{USERNAME}");
+    let mock_server = setup_api("PUT", &route, ResponseTemplate::new(204)).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.current().follow(USERNAME).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_unfollow_user() {
+    let route = format!("/user/following/{USERNAME}");
+    let mock_server = setup_api("DELETE", &route, ResponseTemplate::new(204)).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.current().unfollow(USERNAME).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
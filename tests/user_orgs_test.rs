@@ -0,0 +1,91 @@
+// Tests for calls to the /users/{login}/orgs and /user/orgs APIs.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::Octocrab;
+use serde_json::{json, Value};
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn organization(login: &str, id: u64) -> Value {
+    let url = format!("https://api.github.com/orgs/{login}");
+    json!({
+        "login": login,
+        "id": id,
+        "node_id": "MDEyOk9yZ2FuaXphdGlvbjE=",
+        "url": url,
+        "repos_url": format!("{url}/repos"),
+        "events_url": format!("{url}/events"),
+        "hooks_url": format!("{url}/hooks"),
+        "issues_url": format!("{url}/issues"),
+        "members_url": format!("{url}/members{{/member}}"),
+        "public_members_url": format!("{url}/public_members{{/member}}"),
+        "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+        "description": "A great organization"
+    })
+}
+
+#[tokio::test]
+async fn should_list_user_orgs() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/octocat/orgs"))
+        .and(query_param("per_page", "50"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([organization("github", 1)])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /users/octocat/orgs was not received").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .users("octocat")
+        .list_orgs()
+        .per_page(50)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let orgs = result.unwrap().items;
+    assert_eq!(orgs.len(), 1);
+    assert_eq!(orgs[0].login, "github");
+}
+
+#[tokio::test]
+async fn should_list_authenticated_user_orgs() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/user/orgs"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            organization("github", 1),
+            organization("private-org", 2)
+        ])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /user/orgs was not received").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.current().list_orgs().send().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let logins: Vec<_> = result
+        .unwrap()
+        .items
+        .into_iter()
+        .map(|org| org.login)
+        .collect();
+    assert_eq!(logins, ["github", "private-org"]);
+}
@@ -95,3 +95,59 @@ async fn should_fail_when_not_found() {
         other => panic!("Unexpected error: {:?}", other),
     }
 }
+
+#[tokio::test]
+async fn should_keep_unmodelled_fields_when_lenient() {
+    let template = ResponseTemplate::new(200).set_body_raw(
+        include_str!("resources/user_repositories.json"),
+        "application/json",
+    );
+    let mock_server = setup_api(template).await;
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .lenient_deserialization(true)
+        .build()
+        .unwrap();
+    let result = client.users("some-user").repos().send().await;
+
+    let item = &result.unwrap().items[0];
+
+    assert_eq!(
+        Some(&json!(false)),
+        item.extra.get("web_commit_signoff_required")
+    );
+    assert!(!item.extra.contains_key("name"));
+    assert!(item.owner.is_some());
+}
+
+#[tokio::test]
+async fn should_ignore_unmodelled_fields_by_default() {
+    let template = ResponseTemplate::new(200).set_body_raw(
+        include_str!("resources/user_repositories.json"),
+        "application/json",
+    );
+    let mock_server = setup_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client.users("some-user").repos().send().await;
+
+    assert!(result.unwrap().items[0].extra.is_empty());
+}
+
+#[tokio::test]
+async fn should_split_owner_and_name() {
+    let mocked_response: Vec<Repository> =
+        serde_json::from_str(include_str!("resources/user_repositories.json")).unwrap();
+    let template = ResponseTemplate::new(200).set_body_json(&mocked_response);
+    let mock_server = setup_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client.users("some-user").repos().send().await;
+
+    let item = &result.unwrap().items[0];
+
+    assert_eq!(Some("iamjpotts"), item.owner_login());
+    assert_eq!(
+        Some(("iamjpotts", "actix-examples")),
+        item.full_name_parts()
+    );
+}
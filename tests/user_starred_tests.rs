@@ -0,0 +1,87 @@
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::Repository;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{header, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const USER: &str = "octocat";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn repositories() -> Vec<Repository> {
+    serde_json::from_str(include_str!("resources/user_repositories.json")).unwrap()
+}
+
+#[tokio::test]
+async fn should_list_starred_repos() {
+    let repositories = repositories();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/users/{USER}/starred")))
+        .and(query_param("sort", "updated"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&repositories))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /users/{USER}/starred was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .users(USER)
+        .starred()
+        .sort(octocrab::params::users::starred::Sort::Updated)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().items, repositories);
+}
+
+#[tokio::test]
+async fn should_list_starred_repos_with_timestamps() {
+    let repository = repositories().remove(0);
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/users/{USER}/starred")))
+        .and(header("Accept", "application/vnd.github.star+json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([{
+            "starred_at": "2011-01-16T19:06:43Z",
+            "repo": repository
+        }])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /users/{USER}/starred was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.users(USER).starred().send_with_timestamps().await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let starred = &result.unwrap().items[0];
+    assert_eq!(starred.repo, repository);
+    assert_eq!(
+        starred.starred_at.unwrap().to_rfc3339(),
+        "2011-01-16T19:06:43+00:00"
+    );
+}
@@ -0,0 +1,113 @@
+// Tests for calls to the /repos/{owner}/{repo}/actions/runs/{run_id}/jobs API.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::workflows::{Conclusion, Status};
+use octocrab::params::workflows::Filter;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const RUN_ID: u64 = 29679449;
+
+fn job(
+    id: u64,
+    status: &str,
+    conclusion: Option<&str>,
+    steps: serde_json::Value,
+) -> serde_json::Value {
+    json!({
+        "id": id,
+        "run_id": RUN_ID,
+        "workflow_name": "CI",
+        "head_branch": "main",
+        "run_url": format!("https://api.github.com/repos/{OWNER}/{REPO}/actions/runs/{RUN_ID}"),
+        "run_attempt": 1,
+        "node_id": "MDg6Q2hlY2tSdW4zOTk0NDQ0OTY=",
+        "head_sha": "f83a356604ae3c5d03e1b46ef4d1ca77d64a90b0",
+        "url": format!("https://api.github.com/repos/{OWNER}/{REPO}/actions/jobs/{id}"),
+        "html_url": format!("https://github.com/{OWNER}/{REPO}/runs/{id}"),
+        "status": status,
+        "conclusion": conclusion,
+        "created_at": "2020-01-20T17:42:40Z",
+        "started_at": "2020-01-20T17:42:40Z",
+        "completed_at": null,
+        "name": "build",
+        "steps": steps,
+        "check_run_url": format!("https://api.github.com/repos/{OWNER}/{REPO}/check-runs/{id}"),
+        "labels": ["ubuntu-latest"],
+        "runner_id": null,
+        "runner_name": null,
+        "runner_group_id": null,
+        "runner_group_name": null
+    })
+}
+
+fn step(number: i64, name: &str, conclusion: &str) -> serde_json::Value {
+    json!({
+        "name": name,
+        "status": "completed",
+        "conclusion": conclusion,
+        "number": number,
+        "started_at": "2020-01-20T09:42:40.000-08:00",
+        "completed_at": "2020-01-20T09:42:41.000-08:00"
+    })
+}
+
+#[tokio::test]
+async fn should_list_jobs_for_run() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/actions/runs/{RUN_ID}/jobs"
+        )))
+        .and(query_param("filter", "latest"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "total_count": 2,
+            "jobs": [
+                job(399444496, "completed", Some("failure"), json!([
+                    step(1, "Set up job", "success"),
+                    step(2, "Run tests", "failure"),
+                    step(3, "Upload coverage", "skipped")
+                ])),
+                job(399444497, "waiting", None, json!([]))
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/actions/runs/{RUN_ID}/jobs was not received"),
+    )
+    .await;
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let result = client
+        .workflows(OWNER, REPO)
+        .list_jobs(RUN_ID.into())
+        .filter(Filter::Latest)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let jobs = result.unwrap().items;
+    assert_eq!(jobs.len(), 2);
+    assert_eq!(jobs[0].status, Status::Completed);
+    assert_eq!(jobs[0].conclusion, Some(Conclusion::Failure));
+    assert_eq!(jobs[0].failed_step().unwrap().name, "Run tests");
+    assert_eq!(jobs[1].status, Status::Waiting);
+    assert!(jobs[1].failed_step().is_none());
+}